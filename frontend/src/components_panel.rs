@@ -0,0 +1,99 @@
+use crate::{platform, theme::*, STORE};
+use shared::{ComponentInfo, ComponentStatus};
+use zoon::*;
+
+pub static COMPONENTS_PANEL_OPEN: Lazy<Mutable<bool>> = Lazy::new(|| false.into());
+
+/// Call after every change of loaded components
+pub async fn refresh_components() {
+    let components = platform::components().await;
+    STORE.components.lock_mut().replace_cloned(components);
+}
+
+pub struct ComponentsPanel {
+    components: MutableVec<ComponentInfo>,
+}
+
+impl ComponentsPanel {
+    pub fn new(components: MutableVec<ComponentInfo>) -> impl Element {
+        Self { components }.root()
+    }
+
+    fn root(&self) -> impl Element {
+        Column::new()
+            .s(Padding::new().x(20).bottom(15))
+            .s(Gap::new().y(5))
+            .item_signal(self.components.signal_vec_cloned().is_empty().map_true(|| {
+                El::new().child("No components loaded, add them with `FW.add_decoders([..])` etc.")
+            }))
+            .items_signal_vec(self.components.signal_vec_cloned().map(Self::component_row))
+    }
+
+    fn component_row(component: ComponentInfo) -> impl Element {
+        let ComponentInfo {
+            kind,
+            index,
+            name,
+            path,
+            status,
+        } = component;
+        let (status_label, status_color) = match &status {
+            ComponentStatus::Enabled => ("Enabled".to_owned(), COLOR_LIGHT_BLUE),
+            ComponentStatus::Disabled => ("Disabled".to_owned(), COLOR_LAVENDER),
+            ComponentStatus::Failed { reason } => (format!("Failed: {reason}"), COLOR_FIREBRICK),
+        };
+        let is_enabled = matches!(status, ComponentStatus::Enabled);
+        Row::new()
+            .s(Gap::new().x(10))
+            .item(El::new().s(Width::exact(140)).child(kind.as_static_str()))
+            .item(
+                El::new()
+                    .s(Width::exact(200))
+                    .s(Font::new().weight(FontWeight::Bold))
+                    .update_raw_el(|raw_el| raw_el.attr("title", &path))
+                    .child(name),
+            )
+            .item(
+                El::new()
+                    .s(Width::growable().min(100))
+                    .s(Font::new().color(status_color))
+                    .child(status_label),
+            )
+            .item(component_button("Up", move || async move {
+                if let Some(new_index) = index.checked_sub(1) {
+                    platform::move_component(kind, index, new_index).await;
+                }
+            }))
+            .item(component_button("Down", move || {
+                platform::move_component(kind, index, index + 1)
+            }))
+            .item(component_button(
+                if is_enabled { "Disable" } else { "Enable" },
+                move || platform::set_component_enabled(kind, index, !is_enabled),
+            ))
+            .item(component_button("Remove", move || {
+                platform::remove_component(kind, index)
+            }))
+    }
+}
+
+fn component_button<FUT: Future + 'static>(
+    label: &'static str,
+    action: impl Fn() -> FUT + 'static,
+) -> impl Element {
+    let (hovered, hovered_signal) = Mutable::new_and_signal(false);
+    Button::new()
+        .s(Padding::new().x(15).y(5))
+        .s(Background::new()
+            .color_signal(hovered_signal.map_bool(|| COLOR_MEDIUM_SLATE_BLUE, || COLOR_SLATE_BLUE)))
+        .s(RoundedCorners::all(15))
+        .label(label)
+        .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
+        .on_press(move || {
+            let action = action();
+            Task::start(async move {
+                action.await;
+                refresh_components().await;
+            })
+        })
+}
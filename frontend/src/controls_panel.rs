@@ -1,9 +1,8 @@
-use crate::{theme::*, Filename, Layout};
-use std::cell::Cell;
+use crate::{find_waveform, header_panel, theme::*, Layout, LoadedWaveform, SelectedVar};
+use shared::WaveformId;
 use std::mem;
 use std::ops::Not;
 use std::rc::Rc;
-use std::sync::Arc;
 use wellen::GetItem;
 use zoon::*;
 
@@ -16,6 +15,7 @@ struct VarForUI {
     name: Rc<String>,
     var_type: wellen::VarType,
     var_direction: wellen::VarDirection,
+    waveform_id: WaveformId,
     var_ref: wellen::VarRef,
     signal_type: wellen::SignalType,
 }
@@ -24,6 +24,7 @@ struct VarForUI {
 struct ScopeForUI {
     level: usize,
     name: Rc<String>,
+    waveform_id: WaveformId,
     scope_ref: wellen::ScopeRef,
     has_children: bool,
     expanded: Mutable<bool>,
@@ -33,44 +34,41 @@ struct ScopeForUI {
 
 #[derive(Clone)]
 pub struct ControlsPanel {
-    selected_scope_ref: Mutable<Option<wellen::ScopeRef>>,
-    hierarchy: Mutable<Option<Arc<wellen::Hierarchy>>>,
-    selected_var_refs: MutableVec<wellen::VarRef>,
+    // Scopes of different waveforms may have the same `ScopeRef`
+    selected_scope: Mutable<Option<(WaveformId, wellen::ScopeRef)>>,
+    waveforms: MutableVec<LoadedWaveform>,
+    selected_vars: MutableVec<SelectedVar>,
     layout: Mutable<Layout>,
-    loaded_filename: Mutable<Option<Filename>>,
 }
 
 impl ControlsPanel {
     pub fn new(
-        hierarchy: Mutable<Option<Arc<wellen::Hierarchy>>>,
-        selected_var_refs: MutableVec<wellen::VarRef>,
+        waveforms: MutableVec<LoadedWaveform>,
+        selected_vars: MutableVec<SelectedVar>,
         layout: Mutable<Layout>,
-        loaded_filename: Mutable<Option<Filename>>,
     ) -> impl Element {
         Self {
-            selected_scope_ref: <_>::default(),
-            hierarchy,
-            selected_var_refs,
+            selected_scope: <_>::default(),
+            waveforms,
+            selected_vars,
             layout,
-            loaded_filename,
         }
         .root()
     }
 
     fn triggers(&self) -> Vec<TaskHandle> {
         vec![Task::start_droppable(clone!((self => s) async move {
-            let was_some = Cell::new(false);
-            s.hierarchy
-                .signal_ref(Option::is_some)
-                .dedupe()
-                .for_each_sync(clone!((s) move |is_some| {
-                    if is_some {
-                        return was_some.set(true);
-                    }
-                    if was_some.get() {
-                        s.selected_scope_ref.set(None);
-                        s.selected_var_refs.lock_mut().clear();
-                        s.loaded_filename.set(None);
+            s.waveforms
+                .signal_vec_cloned()
+                .map(|waveform| waveform.id)
+                .to_signal_cloned()
+                .for_each_sync(clone!((s) move |waveform_ids| {
+                    let mut selected_scope = s.selected_scope.lock_mut();
+                    if let Some((waveform_id, _)) = *selected_scope {
+                        // The waveform has been unloaded
+                        if not(waveform_ids.contains(&waveform_id)) {
+                            *selected_scope = None;
+                        }
                     }
                 })).await
         }))]
@@ -79,12 +77,13 @@ impl ControlsPanel {
     fn root(&self) -> impl Element {
         let triggers = self.triggers();
         let layout = self.layout.clone();
-        let layout_and_hierarchy_signal = map_ref! {
+        let is_tree_with_waveforms = map_ref! {
             let layout = layout.signal(),
-            let hierarchy = self.hierarchy.signal_cloned() => {
-                (*layout, hierarchy.clone())
+            let has_waveforms = self.waveforms.signal_vec_cloned().is_empty().map(not) => {
+                matches!(layout, Layout::Tree) && *has_waveforms
             }
-        };
+        }
+        .dedupe();
         Column::new()
             .after_remove(move |_| drop(triggers))
             .s(Width::with_signal_self(
@@ -108,21 +107,50 @@ impl ControlsPanel {
             .s(Padding::all(20))
             .s(Gap::new().y(40))
             .s(Align::new().top())
+            .s(Scrollbars::y_and_clip_x())
+            .items_signal_vec(
+                self.waveforms
+                    .signal_vec_cloned()
+                    .map(clone!((self => s) move |waveform| s.waveform_panel(waveform))),
+            )
             .item_signal(
-                self.hierarchy
-                    .signal_cloned()
-                    .map_some(clone!((self => s) move |hierarchy| s.scopes_panel(hierarchy))),
+                is_tree_with_waveforms.map_true(clone!((self => s) move || s.vars_panel(None))),
             )
-            .item_signal(layout_and_hierarchy_signal.map(
-                clone!((self => s) move |(layout, hierarchy)| {
-                    hierarchy.and_then(clone!((s) move |hierarchy| {
-                        matches!(layout, Layout::Tree).then(move || s.vars_panel(hierarchy))
-                    }))
-                }),
-            ))
     }
 
-    fn scopes_panel(&self, hierarchy: Arc<wellen::Hierarchy>) -> impl Element {
+    fn waveform_panel(&self, waveform: LoadedWaveform) -> impl Element {
+        Column::new()
+            .s(Height::fill())
+            .s(Width::fill())
+            .s(Gap::new().y(20))
+            .item(self.waveform_header(&waveform))
+            .item(self.scopes_panel(waveform))
+    }
+
+    fn waveform_header(&self, waveform: &LoadedWaveform) -> impl Element {
+        let (hovered, hovered_signal) = Mutable::new_and_signal(false);
+        let waveform_id = waveform.id;
+        Row::new()
+            .s(Gap::new().x(15))
+            .item(
+                El::new()
+                    .s(Font::new().no_wrap().color(COLOR_LIGHT_BLUE))
+                    .child(&waveform.filename),
+            )
+            .item(
+                Button::new()
+                    .s(Padding::new().x(15).y(5))
+                    .s(Background::new().color_signal(
+                        hovered_signal.map_bool(|| COLOR_MEDIUM_SLATE_BLUE, || COLOR_SLATE_BLUE),
+                    ))
+                    .s(RoundedCorners::all(15))
+                    .label("Unload")
+                    .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
+                    .on_press(move || Task::start(header_panel::unload_waveform(waveform_id))),
+            )
+    }
+
+    fn scopes_panel(&self, waveform: LoadedWaveform) -> impl Element {
         Column::new()
             .s(Height::fill())
             .s(Scrollbars::y_and_clip_x())
@@ -134,11 +162,13 @@ impl ControlsPanel {
                     .map(|layout| matches!(layout, Layout::Tree))
                     .map_true(|| El::new().child("Scopes")),
             )
-            .item(self.scopes_list(hierarchy))
+            .item(self.scopes_list(waveform))
     }
 
-    fn scopes_list(&self, hierarchy: Arc<wellen::Hierarchy>) -> impl Element {
+    fn scopes_list(&self, waveform: LoadedWaveform) -> impl Element {
         let layout = self.layout.clone();
+        let waveform_id = waveform.id;
+        let hierarchy = waveform.hierarchy;
         let mut scopes_for_ui = Vec::new();
         let mut max_level_index: usize = 0;
         for scope_ref in hierarchy.scopes() {
@@ -158,6 +188,7 @@ impl ControlsPanel {
                 scopes_for_ui.push(ScopeForUI {
                     level,
                     name: Rc::new(scope.name(&hierarchy).to_owned()),
+                    waveform_id,
                     scope_ref,
                     has_children,
                     expanded: expanded.clone(),
@@ -222,7 +253,7 @@ impl ControlsPanel {
                                                 .map(clone!((s) move |scope_for_ui| s.scope_button_row(scope_for_ui)))
                                         )
                                 }))
-                                .item(s.vars_panel(hierarchy.clone()))
+                                .item(s.vars_panel(Some(waveform_id)))
                         ).unify()
                 }
             }))
@@ -231,10 +262,11 @@ impl ControlsPanel {
     fn scope_button_row(&self, scope_for_ui: ScopeForUI) -> impl Element {
         let layout = self.layout.clone();
         let (button_hovered, button_hovered_signal) = Mutable::new_and_signal(false);
-        let selected_scope_ref = self.selected_scope_ref.clone();
-        let is_selected = selected_scope_ref
+        let scope = (scope_for_ui.waveform_id, scope_for_ui.scope_ref);
+        let is_selected = self
+            .selected_scope
             .signal()
-            .map(move |selected_scope_ref| selected_scope_ref == Some(scope_for_ui.scope_ref));
+            .map(move |selected_scope| selected_scope == Some(scope));
         let background_color = map_ref! {
             let is_selected = is_selected,
             let is_hovered = button_hovered_signal => match (*is_selected, *is_hovered) {
@@ -315,7 +347,7 @@ impl ControlsPanel {
             let layout = layout.signal(),
             let expanded = expanded.signal() => (*layout, *expanded)
         };
-        let selected_scope_ref: Mutable<Option<wellen::ScopeRef>> = self.selected_scope_ref.clone();
+        let selected_scope = self.selected_scope.clone();
         let (hovered, hovered_signal) = Mutable::new_and_signal(false);
         Button::new()
             .s(Padding::new()
@@ -358,7 +390,7 @@ impl ControlsPanel {
                     scope_for_ui.expanded.update(not)
                 }
                 Layout::Columns => {
-                    selected_scope_ref.set_neq(None);
+                    selected_scope.set_neq(None);
                     if scope_for_ui.expanded.get() {
                         scope_for_ui.selected_scope_in_level.set(None);
                     } else {
@@ -379,16 +411,17 @@ impl ControlsPanel {
             .s(Padding::new().x(15).y(5))
             .on_hovered_change(move |is_hovered| button_hovered.set_neq(is_hovered))
             .on_press(
-                clone!((self.selected_scope_ref => selected_scope_ref, scope_for_ui) move || {
-                    selected_scope_ref.set_neq(Some(scope_for_ui.scope_ref));
+                clone!((self.selected_scope => selected_scope, scope_for_ui) move || {
+                    selected_scope.set_neq(Some((scope_for_ui.waveform_id, scope_for_ui.scope_ref)));
                     scope_for_ui.selected_scope_in_level.set_neq(None);
                 }),
             )
             .label(scope_for_ui.name)
     }
 
-    fn vars_panel(&self, hierarchy: Arc<wellen::Hierarchy>) -> impl Element {
-        let selected_scope_ref = self.selected_scope_ref.clone();
+    /// Vars of the selected scope, only when it's in the waveform with `waveform_id` if set
+    fn vars_panel(&self, waveform_id: Option<WaveformId>) -> impl Element {
+        let waveforms = self.waveforms.clone();
         Column::new()
             .s(Align::new().top())
             .s(Gap::new().y(20))
@@ -401,8 +434,15 @@ impl ControlsPanel {
                     .map(|layout| matches!(layout, Layout::Tree))
                     .map_true(|| El::new().child("Variables")),
             )
-            .item_signal(selected_scope_ref.signal().map_some(
-                clone!((self => s) move |scope_ref| s.vars_list(scope_ref, hierarchy.clone())),
+            .item_signal(self.selected_scope.signal().map(
+                clone!((self => s) move |selected_scope| {
+                    let (selected_waveform_id, scope_ref) = selected_scope?;
+                    if waveform_id.is_some() && waveform_id != Some(selected_waveform_id) {
+                        None?
+                    }
+                    let waveform = find_waveform(&waveforms, selected_waveform_id)?;
+                    Some(s.vars_list(scope_ref, waveform))
+                }),
             ))
     }
 
@@ -410,8 +450,10 @@ impl ControlsPanel {
     fn vars_list(
         &self,
         selected_scope_ref: wellen::ScopeRef,
-        hierarchy: Arc<wellen::Hierarchy>,
+        waveform: LoadedWaveform,
     ) -> impl Element {
+        let waveform_id = waveform.id;
+        let hierarchy = waveform.hierarchy;
         let vars_for_ui = hierarchy
             .get(selected_scope_ref)
             .vars(&hierarchy)
@@ -421,6 +463,7 @@ impl ControlsPanel {
                     name: Rc::new(var.name(&hierarchy).to_owned()),
                     var_type: var.var_type(),
                     var_direction: var.direction(),
+                    waveform_id,
                     var_ref,
                     signal_type: var.signal_tpe(),
                 }
@@ -483,7 +526,7 @@ impl ControlsPanel {
 
     fn var_button(&self, var_for_ui: VarForUI) -> impl Element {
         let (hovered, hovered_signal) = Mutable::new_and_signal(false);
-        let selected_var_ref = self.selected_var_refs.clone();
+        let selected_vars = self.selected_vars.clone();
         El::new().child(
             Button::new()
                 .s(Padding::new().x(15).y(5))
@@ -492,7 +535,12 @@ impl ControlsPanel {
                 ))
                 .s(RoundedCorners::all(15))
                 .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
-                .on_press(move || selected_var_ref.lock_mut().push(var_for_ui.var_ref))
+                .on_press(move || {
+                    selected_vars.lock_mut().push(SelectedVar {
+                        waveform_id: var_for_ui.waveform_id,
+                        var_ref: var_for_ui.var_ref,
+                    })
+                })
                 .label(var_for_ui.name),
         )
     }
@@ -0,0 +1,277 @@
+use crate::{
+    find_waveform, platform, script_bridge, theme::*, waveform_panel::format_time, LoadedWaveform,
+    SelectedVar, TimeMarkers, STORE,
+};
+use shared::{VarDiff, WaveformId};
+use zoon::*;
+
+pub static DIFF_PANEL_OPEN: Lazy<Mutable<bool>> = Lazy::new(|| false.into());
+
+/// Diffs the waveforms and keeps the results to be shown in the diff panel
+pub async fn diff_waveforms(waveform_id_a: WaveformId, waveform_id_b: WaveformId) -> Vec<VarDiff> {
+    let var_diffs = platform::diff_waveforms(waveform_id_a, waveform_id_b).await;
+    STORE.var_diffs.lock_mut().replace_cloned(var_diffs.clone());
+    STORE
+        .diffed_waveforms
+        .set(Some((waveform_id_a, waveform_id_b)));
+    STORE.selected_var_diff.set(None);
+    var_diffs
+}
+
+/// Results are outdated once one of the diffed waveforms is reloaded or unloaded
+pub fn clear_waveform_diff(waveform_id: WaveformId) {
+    let Some((waveform_id_a, waveform_id_b)) = STORE.diffed_waveforms.get() else {
+        return;
+    };
+    if waveform_id == waveform_id_a || waveform_id == waveform_id_b {
+        STORE.diffed_waveforms.set(None);
+        STORE.var_diffs.lock_mut().clear();
+        STORE.selected_var_diff.set(None);
+    }
+}
+
+#[derive(Clone)]
+pub struct DiffPanel {
+    waveforms: MutableVec<LoadedWaveform>,
+    selected_vars: MutableVec<SelectedVar>,
+    time_markers: TimeMarkers,
+    var_diffs: MutableVec<VarDiff>,
+    diffed_waveforms: Mutable<Option<(WaveformId, WaveformId)>>,
+    // Its mismatches are highlighted on the timeline
+    selected_var_diff: Mutable<Option<VarDiff>>,
+    // E.g. a golden simulation run
+    waveform_id_a: Mutable<Option<WaveformId>>,
+    // E.g. a failing simulation run
+    waveform_id_b: Mutable<Option<WaveformId>>,
+    is_diffing: Mutable<bool>,
+}
+
+impl DiffPanel {
+    pub fn new(
+        waveforms: MutableVec<LoadedWaveform>,
+        selected_vars: MutableVec<SelectedVar>,
+        time_markers: TimeMarkers,
+        var_diffs: MutableVec<VarDiff>,
+        diffed_waveforms: Mutable<Option<(WaveformId, WaveformId)>>,
+        selected_var_diff: Mutable<Option<VarDiff>>,
+    ) -> impl Element {
+        let (waveform_id_a, waveform_id_b) = diffed_waveforms.get().unzip();
+        Self {
+            waveforms,
+            selected_vars,
+            time_markers,
+            var_diffs,
+            diffed_waveforms,
+            selected_var_diff,
+            waveform_id_a: Mutable::new(waveform_id_a),
+            waveform_id_b: Mutable::new(waveform_id_b),
+            is_diffing: <_>::default(),
+        }
+        .root()
+    }
+
+    fn triggers(&self) -> Vec<TaskHandle> {
+        vec![Task::start_droppable(clone!((self => s) async move {
+            s.waveforms
+                .signal_vec_cloned()
+                .map(|waveform| waveform.id)
+                .to_signal_cloned()
+                .for_each_sync(clone!((s) move |waveform_ids| {
+                    // The first two loaded waveforms are diffed by default
+                    let mut waveform_id_a = s.waveform_id_a.lock_mut();
+                    if waveform_id_a.filter(|id| waveform_ids.contains(id)).is_none() {
+                        *waveform_id_a = waveform_ids.first().copied();
+                    }
+                    let mut waveform_id_b = s.waveform_id_b.lock_mut();
+                    if waveform_id_b.filter(|id| waveform_ids.contains(id)).is_none() {
+                        *waveform_id_b = waveform_ids
+                            .iter()
+                            .find(|id| Some(**id) != *waveform_id_a)
+                            .copied();
+                    }
+                })).await
+        }))]
+    }
+
+    fn root(&self) -> impl Element {
+        let triggers = self.triggers();
+        Column::new()
+            .after_remove(move |_| drop(triggers))
+            .s(Padding::new().x(20).bottom(15))
+            .s(Gap::new().y(10))
+            .item(
+                Row::new()
+                    .s(Gap::new().x(20))
+                    .item(self.waveform_picker("Golden", self.waveform_id_a.clone()))
+                    .item(self.waveform_picker("Compared", self.waveform_id_b.clone()))
+                    .item(self.diff_button())
+                    .item(self.diff_summary()),
+            )
+            .item(self.var_diffs())
+    }
+
+    fn waveform_picker(
+        &self,
+        label: &'static str,
+        picked_waveform_id: Mutable<Option<WaveformId>>,
+    ) -> impl Element {
+        Row::new()
+            .s(Gap::new().x(10))
+            .item(label)
+            .items_signal_vec(self.waveforms.signal_vec_cloned().map(move |waveform| {
+            let (hovered, hovered_signal) = Mutable::new_and_signal(false);
+            let background_color = map_ref! {
+                let hovered = hovered_signal,
+                let is_picked = picked_waveform_id.signal_ref(move |id| *id == Some(waveform.id)) =>
+                if *hovered || *is_picked { COLOR_MEDIUM_SLATE_BLUE } else { COLOR_SLATE_BLUE }
+            };
+            Button::new()
+                .s(Padding::new().x(15).y(5))
+                .s(Background::new().color_signal(background_color))
+                .s(RoundedCorners::all(15))
+                .label(El::new().s(Font::new().no_wrap()).child(waveform.filename))
+                .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
+                .on_press(clone!((picked_waveform_id) move || {
+                    picked_waveform_id.set_neq(Some(waveform.id))
+                }))
+        }))
+    }
+
+    fn diff_button(&self) -> impl Element {
+        let (hovered, hovered_signal) = Mutable::new_and_signal(false);
+        let waveform_id_a = self.waveform_id_a.clone();
+        let waveform_id_b = self.waveform_id_b.clone();
+        let is_diffing = self.is_diffing.clone();
+        Button::new()
+            .s(Padding::new().x(20).y(5))
+            .s(Background::new().color_signal(
+                hovered_signal.map_bool(|| COLOR_MEDIUM_SLATE_BLUE, || COLOR_SLATE_BLUE),
+            ))
+            .s(RoundedCorners::all(15))
+            .label_signal(
+                is_diffing
+                    .signal()
+                    .map_bool(|| "Diffing..", || "Diff")
+                    .map(|label| El::new().s(Font::new().no_wrap()).child(label)),
+            )
+            .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
+            .on_press(move || {
+                let (Some(waveform_id_a), Some(waveform_id_b)) =
+                    (waveform_id_a.get(), waveform_id_b.get())
+                else {
+                    return;
+                };
+                if waveform_id_a == waveform_id_b || is_diffing.get() {
+                    return;
+                }
+                is_diffing.set(true);
+                Task::start(clone!((is_diffing) async move {
+                    diff_waveforms(waveform_id_a, waveform_id_b).await;
+                    is_diffing.set(false);
+                }));
+            })
+    }
+
+    fn diff_summary(&self) -> impl Element {
+        let summary = map_ref! {
+            let diffed_waveforms = self.diffed_waveforms.signal(),
+            let var_diff_count = self.var_diffs.signal_vec_cloned().len(),
+            let waveform_count = self.waveforms.signal_vec_cloned().len() => {
+                if *waveform_count < 2 {
+                    "Load at least two waveforms to diff them".to_owned()
+                } else if diffed_waveforms.is_none() {
+                    String::new()
+                } else if *var_diff_count == 0 {
+                    "No differences".to_owned()
+                } else {
+                    format!("{var_diff_count} vars differ")
+                }
+            }
+        };
+        El::new()
+            .s(Align::new().center_y())
+            .s(Font::new().color(COLOR_LIGHT_BLUE).no_wrap())
+            .child_signal(summary)
+    }
+
+    fn var_diffs(&self) -> impl Element {
+        Column::new()
+            .s(Height::default().max(250))
+            .s(Scrollbars::y_and_clip_x())
+            .s(Font::new().family([FontFamily::new("Courier New"), FontFamily::Monospace]))
+            .items_signal_vec(
+                self.var_diffs
+                    .signal_vec_cloned()
+                    .map(clone!((self => s) move |var_diff| s.var_diff_row(var_diff))),
+            )
+    }
+
+    fn var_diff_row(&self, var_diff: VarDiff) -> impl Element {
+        let (hovered, hovered_signal) = Mutable::new_and_signal(false);
+        let full_var_name = var_diff.full_var_name.clone();
+        let background_color = map_ref! {
+            let hovered = hovered_signal,
+            let is_selected = self.selected_var_diff.signal_ref(move |selected_var_diff| {
+                selected_var_diff.as_ref().map(|var_diff| &var_diff.full_var_name) == Some(&full_var_name)
+            }) =>
+            if *hovered || *is_selected { COLOR_MEDIUM_SLATE_BLUE } else { COLOR_DARK_SLATE_BLUE }
+        };
+        let mismatch_count = match var_diff.mismatch_count {
+            1 => "1 mismatch".to_owned(),
+            mismatch_count => format!("{mismatch_count} mismatches"),
+        };
+        Button::new()
+            .s(Padding::new().x(10).y(2))
+            .s(Background::new().color_signal(background_color))
+            .label(
+                Row::new()
+                    .s(Gap::new().x(10))
+                    .item(
+                        El::new()
+                            .s(Width::exact(150))
+                            .child(format_time(var_diff.first_mismatch_time, &self.waveforms)),
+                    )
+                    .item(
+                        El::new()
+                            .s(Width::exact(150))
+                            .s(Font::new().color(COLOR_LIGHT_BLUE))
+                            .child(mismatch_count),
+                    )
+                    .item(var_diff.full_var_name.clone()),
+            )
+            .update_raw_el(|raw_el| raw_el.attr("title", "Click to jump to the first mismatch"))
+            .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
+            .on_press(clone!((self => s) move || s.jump_to_var_diff(var_diff.clone())))
+    }
+
+    /// Selects the var of both waveforms and moves the cursor to the first mismatch
+    fn jump_to_var_diff(&self, var_diff: VarDiff) {
+        let Some((waveform_id_a, waveform_id_b)) = self.diffed_waveforms.get() else {
+            return;
+        };
+        for waveform_id in [waveform_id_a, waveform_id_b] {
+            let Some(waveform) = find_waveform(&self.waveforms, waveform_id) else {
+                continue;
+            };
+            let Some(var_ref) =
+                script_bridge::lookup_var_ref(&waveform.hierarchy, &var_diff.full_var_name)
+            else {
+                continue;
+            };
+            let selected_var = SelectedVar {
+                waveform_id,
+                var_ref,
+            };
+            let mut selected_vars = self.selected_vars.lock_mut();
+            if not(selected_vars.contains(&selected_var)) {
+                selected_vars.push_cloned(selected_var);
+            }
+        }
+        let time = var_diff.first_mismatch_time;
+        self.time_markers.cursor.set(Some(time));
+        if let Some(controller) = STORE.pixi_canvas_controller.get_cloned().get_cloned() {
+            controller.scroll_to_time(time as f64);
+        }
+        self.selected_var_diff.set(Some(var_diff));
+    }
+}
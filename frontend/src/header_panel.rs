@@ -1,27 +1,298 @@
+use crate::components_panel::{self, COMPONENTS_PANEL_OPEN};
+use crate::diff_panel::{self, DIFF_PANEL_OPEN};
+use crate::log_panel::LOG_PANEL_OPEN;
 use crate::term::TERM_OPEN;
-use crate::{platform, theme::*, Filename, Layout, Mode};
-use std::sync::Arc;
+use crate::{
+    find_waveform, platform, script_bridge, theme::*, FilePath, Filename, Layout, LoadedWaveform,
+    Mode, SelectedVar, WaveformVarName, STORE,
+};
+use shared::{WaveformId, WaveformLoadingStatus};
+use std::collections::BTreeSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 use zoon::*;
 
+static IS_RELOADING: AtomicBool = AtomicBool::new(false);
+static PENDING_RELOADS: Lazy<Mutex<BTreeSet<WaveformId>>> = lazy::default();
+// Removed by the reloading, their signals were dropped with the previous backend waveform
+static RELOADED_SELECTED_VARS: Lazy<Mutex<Vec<SelectedVar>>> = lazy::default();
+
+/// Adds the waveform to the already loaded ones
+pub async fn load_waveform_from_path(path: FilePath) -> Option<Filename> {
+    let waveform_info = platform::load_waveform_from_path(path).await;
+    refresh_recent_files().await;
+    add_loaded_waveform(waveform_info?).await
+}
+
+async fn add_loaded_waveform(waveform_info: shared::WaveformInfo) -> Option<Filename> {
+    let hierarchy = platform::get_hierarchy(waveform_info.id).await?;
+    STORE.waveforms.lock_mut().push_cloned(LoadedWaveform {
+        id: waveform_info.id,
+        filename: waveform_info.filename.clone(),
+        hierarchy: Arc::new(hierarchy),
+    });
+    Some(waveform_info.filename)
+}
+
+/// Removes the waveform together with its selected vars,
+/// markers and the cursor are cleared with the last waveform
+/// and converted to the time units of the new first waveform otherwise
+pub fn remove_waveform(waveform_id: WaveformId) {
+    let previous_timescale = first_waveform_timescale();
+    diff_panel::clear_waveform_diff(waveform_id);
+    STORE
+        .selected_vars
+        .lock_mut()
+        .retain(|selected_var| selected_var.waveform_id != waveform_id);
+    STORE
+        .changed_waveforms
+        .lock_mut()
+        .retain(|changed_waveform_id| *changed_waveform_id != waveform_id);
+    remove_waveform_vars(&STORE.var_decoders, waveform_id);
+    remove_waveform_vars(&STORE.var_formats, waveform_id);
+    remove_waveform_vars(&STORE.var_render_modes, waveform_id);
+    STORE
+        .waveforms
+        .lock_mut()
+        .retain(|waveform| waveform.id != waveform_id);
+    match previous_timescale {
+        Some(previous_timescale) if !STORE.waveforms.lock_ref().is_empty() => {
+            convert_time_markers(previous_timescale)
+        }
+        _ => {
+            STORE.time_markers.cursor.set(None);
+            STORE.time_markers.markers.lock_mut().clear();
+        }
+    }
+}
+
+fn remove_waveform_vars<V: Clone>(
+    var_settings: &MutableBTreeMap<WaveformVarName, V>,
+    waveform_id: WaveformId,
+) {
+    let mut var_settings = var_settings.lock_mut();
+    let removed_var_names = var_settings
+        .keys()
+        .filter(|(var_waveform_id, _)| *var_waveform_id == waveform_id)
+        .cloned()
+        .collect::<Vec<_>>();
+    for waveform_var_name in removed_var_names {
+        var_settings.remove(&waveform_var_name);
+    }
+}
+
+fn first_waveform_timescale() -> Option<Option<wellen::Timescale>> {
+    STORE
+        .waveforms
+        .lock_ref()
+        .first()
+        .map(|waveform| waveform.hierarchy.timescale())
+}
+
+/// Times are in the time units of the first waveform,
+/// they are converted when its timescale changes
+fn convert_time_markers(previous_timescale: Option<wellen::Timescale>) {
+    let Some(timescale) = first_waveform_timescale() else {
+        return;
+    };
+    if timescale == previous_timescale {
+        return;
+    }
+    let time_axis = |timescale| shared::TimeAxis {
+        timescale,
+        last_time: 0,
+    };
+    let convert = |time| time_axis(timescale).time_from(time, time_axis(previous_timescale));
+    let cursor = STORE.time_markers.cursor.get();
+    STORE.time_markers.cursor.set(cursor.map(convert));
+    let mut markers = STORE.time_markers.markers.lock_mut();
+    let converted_markers = markers
+        .iter()
+        .map(|marker| shared::Marker {
+            name: marker.name.clone(),
+            time: convert(marker.time),
+        })
+        .collect();
+    markers.replace_cloned(converted_markers);
+}
+
+/// The backend waveform is unloaded too, its loading is cancelled if it's still running
+pub async fn unload_waveform(waveform_id: WaveformId) {
+    remove_waveform(waveform_id);
+    platform::unload_waveform(waveform_id).await;
+    // The shared time axis may be shorter or in other time units now
+    if let Some(controller) = STORE.pixi_canvas_controller.get_cloned().get_cloned() {
+        controller.redraw_all_rows().await
+    }
+}
+
+/// Reloads the waveform in the live tail mode, otherwise offers the reload to the user.
+///
+/// The live tail reloads the whole file on every change, wellen can't parse only appended values.
+pub fn on_waveform_file_change(waveform_id: WaveformId) {
+    if find_waveform(&STORE.waveforms, waveform_id).is_none() {
+        return;
+    }
+    if STORE.live_tail.get() {
+        Task::start(reload_waveform(waveform_id));
+        return;
+    }
+    let mut changed_waveforms = STORE.changed_waveforms.lock_mut();
+    if !changed_waveforms.contains(&waveform_id) {
+        changed_waveforms.push(waveform_id);
+    }
+}
+
+/// Selected vars are matched by their full names in the reloaded waveform.
+/// Var formats, render modes, zoom and viewport are kept.
+///
+/// Reloads requested while another one is running are done after it,
+/// a simulation may append to the file faster than it's reloaded.
+pub async fn reload_waveform(waveform_id: WaveformId) {
+    PENDING_RELOADS.lock().unwrap_throw().insert(waveform_id);
+    if IS_RELOADING.swap(true, Ordering::Relaxed) {
+        return;
+    }
+    loop {
+        let Some(waveform_id) = PENDING_RELOADS.lock().unwrap_throw().pop_first() else {
+            break;
+        };
+        reload_waveform_with_selected_vars(waveform_id).await;
+    }
+    IS_RELOADING.store(false, Ordering::Relaxed);
+}
+
+async fn reload_waveform_with_selected_vars(waveform_id: WaveformId) {
+    STORE
+        .changed_waveforms
+        .lock_mut()
+        .retain(|changed_waveform_id| *changed_waveform_id != waveform_id);
+    let Some(waveform) = find_waveform(&STORE.waveforms, waveform_id) else {
+        return;
+    };
+    // Vars of other waveforms are kept as they are
+    let selected_vars_with_names = STORE
+        .selected_vars
+        .lock_ref()
+        .iter()
+        .map(|selected_var| {
+            let full_var_name = (selected_var.waveform_id == waveform_id)
+                .then(|| waveform.full_var_name(selected_var.var_ref));
+            (*selected_var, full_var_name)
+        })
+        .collect::<Vec<_>>();
+
+    // The previous waveform stays loaded on fail, e.g. when the file is being written
+    if let Err(error) = platform::reload_waveform(waveform_id).await {
+        return eprintln!("Failed to reload the waveform: {error}");
+    }
+    diff_panel::clear_waveform_diff(waveform_id);
+    STORE.selected_vars.lock_mut().retain(|selected_var| {
+        let is_reloaded = selected_var.waveform_id == waveform_id;
+        if is_reloaded {
+            RELOADED_SELECTED_VARS
+                .lock()
+                .unwrap_throw()
+                .push(*selected_var);
+        }
+        !is_reloaded
+    });
+    let Some(hierarchy) = platform::get_hierarchy(waveform_id).await.map(Arc::new) else {
+        return;
+    };
+    let selected_vars =
+        selected_vars_with_names
+            .into_iter()
+            .filter_map(|(selected_var, full_var_name)| match full_var_name {
+                Some(full_var_name) => script_bridge::lookup_var_ref(&hierarchy, &full_var_name)
+                    .map(|var_ref| SelectedVar {
+                        waveform_id,
+                        var_ref,
+                    }),
+                None => Some(selected_var),
+            })
+            .collect::<Vec<_>>();
+    let previous_timescale = first_waveform_timescale();
+    {
+        let mut waveforms = STORE.waveforms.lock_mut();
+        // The waveform may have been unloaded during the reloading
+        let Some(index) = waveforms
+            .iter()
+            .position(|waveform| waveform.id == waveform_id)
+        else {
+            return;
+        };
+        waveforms.set_cloned(
+            index,
+            LoadedWaveform {
+                hierarchy,
+                ..waveform
+            },
+        );
+    }
+    if let Some(previous_timescale) = previous_timescale {
+        convert_time_markers(previous_timescale);
+    }
+    STORE.selected_vars.lock_mut().replace_cloned(selected_vars);
+    // The ruler isn't redrawn with the reloaded vars and the waveform may be longer now
+    if let Some(controller) = STORE.pixi_canvas_controller.get_cloned().get_cloned() {
+        controller.redraw_ruler().await
+    }
+}
+
+/// Returns `true` when the var was removed by the reloading of its waveform,
+/// its signal doesn't have to be unloaded then
+pub fn take_reloaded_selected_var(selected_var: SelectedVar) -> bool {
+    let mut reloaded_selected_vars = RELOADED_SELECTED_VARS.lock().unwrap_throw();
+    let Some(index) = reloaded_selected_vars
+        .iter()
+        .position(|reloaded_var| *reloaded_var == selected_var)
+    else {
+        return false;
+    };
+    reloaded_selected_vars.swap_remove(index);
+    true
+}
+
+pub async fn refresh_recent_files() {
+    let recent_files = platform::recent_files().await;
+    STORE.recent_files.lock_mut().replace_cloned(recent_files);
+}
+
 pub struct HeaderPanel {
-    hierarchy: Mutable<Option<Arc<wellen::Hierarchy>>>,
+    waveforms: MutableVec<LoadedWaveform>,
     layout: Mutable<Layout>,
     mode: Mutable<Mode>,
-    loaded_filename: Mutable<Option<Filename>>,
+    disabled_components: MutableVec<shared::DisabledComponent>,
+    command_errors: MutableVec<shared::CommandError>,
+    recent_files: MutableVec<FilePath>,
+    waveform_loadings: MutableVec<WaveformLoadingStatus>,
+    changed_waveforms: MutableVec<WaveformId>,
+    live_tail: Mutable<bool>,
 }
 
 impl HeaderPanel {
     pub fn new(
-        hierarchy: Mutable<Option<Arc<wellen::Hierarchy>>>,
+        waveforms: MutableVec<LoadedWaveform>,
         layout: Mutable<Layout>,
         mode: Mutable<Mode>,
-        loaded_filename: Mutable<Option<Filename>>,
+        disabled_components: MutableVec<shared::DisabledComponent>,
+        command_errors: MutableVec<shared::CommandError>,
+        recent_files: MutableVec<FilePath>,
+        waveform_loadings: MutableVec<WaveformLoadingStatus>,
+        changed_waveforms: MutableVec<WaveformId>,
+        live_tail: Mutable<bool>,
     ) -> impl Element {
         Self {
-            hierarchy,
+            waveforms,
             layout,
-            loaded_filename,
             mode,
+            disabled_components,
+            command_errors,
+            recent_files,
+            waveform_loadings,
+            changed_waveforms,
+            live_tail,
         }
         .root()
     }
@@ -39,15 +310,26 @@ impl HeaderPanel {
                     .item(self.layout_switcher())
                     .item(self.mode_switcher())
                     .item(self.open_terminal())
-                    .item(self.open_konata_file()),
+                    .item(self.open_konata_file())
+                    .item(self.toggle_components_panel())
+                    .item(self.toggle_log_panel())
+                    .item(self.toggle_diff_panel()),
+            )
+            .item(self.waveform_loading_progress())
+            .item(self.waveform_file_change())
+            .item(self.recent_files())
+            .item(
+                Column::new()
+                    .s(Align::new().top())
+                    .s(Gap::new().y(5))
+                    .item(self.command_errors())
+                    .item(self.disabled_components()),
             )
     }
 
     #[cfg(FASTWAVE_PLATFORM = "TAURI")]
     fn load_button(&self) -> impl Element {
         let (hovered, hovered_signal) = Mutable::new_and_signal(false);
-        let hierarchy = self.hierarchy.clone();
-        let loaded_filename = self.loaded_filename.clone();
         Button::new()
             .s(Padding::new().x(20).y(10))
             .s(Background::new().color_signal(
@@ -55,26 +337,19 @@ impl HeaderPanel {
             ))
             .s(Align::new().left())
             .s(RoundedCorners::all(15))
-            .label(El::new().s(Font::new().no_wrap()).child_signal(
-                loaded_filename.signal_cloned().map_option(
-                    |filename| format!("Unload {filename}"),
-                    || format!("Load file.."),
-                ),
-            ))
+            .label(El::new().s(Font::new().no_wrap()).child("Load file.."))
+            .update_raw_el(|raw_el| {
+                raw_el.attr(
+                    "title",
+                    "Loaded waveforms are unloaded in the controls panel",
+                )
+            })
             .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
             .on_press(move || {
-                let mut hierarchy_lock = hierarchy.lock_mut();
-                if hierarchy_lock.is_some() {
-                    *hierarchy_lock = None;
-                    return;
-                }
-                drop(hierarchy_lock);
-                let hierarchy = hierarchy.clone();
-                let loaded_filename = loaded_filename.clone();
                 Task::start(async move {
-                    if let Some(filename) = platform::pick_and_load_waveform(None).await {
-                        loaded_filename.set_neq(Some(filename));
-                        hierarchy.set(Some(Arc::new(platform::get_hierarchy().await)))
+                    if let Some(waveform_info) = platform::pick_and_load_waveform(None).await {
+                        add_loaded_waveform(waveform_info).await;
+                        refresh_recent_files().await;
                     }
                 })
             })
@@ -83,8 +358,6 @@ impl HeaderPanel {
     #[cfg(FASTWAVE_PLATFORM = "BROWSER")]
     fn load_button(&self) -> impl Element {
         let (hovered, hovered_signal) = Mutable::new_and_signal(false);
-        let hierarchy = self.hierarchy.clone();
-        let loaded_filename = self.loaded_filename.clone();
         let file_input_id = "file_input_for_load_waveform_button";
         Row::new()
             .item(
@@ -96,28 +369,9 @@ impl HeaderPanel {
                     .s(Align::new().left())
                     .s(RoundedCorners::all(15))
                     .s(Cursor::new(CursorIcon::Pointer))
-                    .label(El::new().s(Font::new().no_wrap()).child_signal(
-                        loaded_filename.signal_cloned().map_option(
-                            |filename| format!("Unload {filename}"),
-                            || format!("Load file.."),
-                        ),
-                    ))
+                    .label(El::new().s(Font::new().no_wrap()).child("Load file.."))
                     .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
-                    .for_input(file_input_id)
-                    .on_click_event_with_options(
-                        EventOptions::new().preventable(),
-                        clone!((hierarchy) move |event| {
-                            let mut hierarchy_lock = hierarchy.lock_mut();
-                            if hierarchy_lock.is_some() {
-                                *hierarchy_lock = None;
-                                if let RawMouseEvent::Click(raw_event) = event.raw_event {
-                                    // @TODO Move to MoonZoon as a new API
-                                    raw_event.prevent_default();
-                                }
-                                return;
-                            }
-                        }),
-                    ),
+                    .for_input(file_input_id),
             )
             .item(
                 // @TODO https://github.com/MoonZoon/MoonZoon/issues/39
@@ -138,14 +392,11 @@ impl HeaderPanel {
                                 zoon::println!("file list is empty");
                                 return;
                             };
-                            let hierarchy = hierarchy.clone();
-                            let loaded_filename = loaded_filename.clone();
                             Task::start(async move {
-                                if let Some(filename) =
+                                if let Some(waveform_info) =
                                     platform::pick_and_load_waveform(Some(file)).await
                                 {
-                                    loaded_filename.set_neq(Some(filename));
-                                    hierarchy.set(Some(Arc::new(platform::get_hierarchy().await)))
+                                    add_loaded_waveform(waveform_info).await;
                                 }
                             })
                         })
@@ -153,6 +404,183 @@ impl HeaderPanel {
             )
     }
 
+    fn waveform_loading_progress(&self) -> impl Element {
+        Column::new()
+            .s(Align::new().top())
+            .s(Padding::new().top(5))
+            .s(Gap::new().y(5))
+            .items_signal_vec(self.waveform_loadings.signal_vec_cloned().map(|status| {
+                let WaveformLoadingStatus::Loading {
+                    waveform_id,
+                    filename,
+                    loaded_bytes,
+                    total_bytes,
+                } = status
+                else {
+                    return None;
+                };
+                // FST files don't report their body length
+                let ratio =
+                    (total_bytes > 0).then(|| (loaded_bytes as f64 / total_bytes as f64).min(1.));
+                let (hovered, hovered_signal) = Mutable::new_and_signal(false);
+                let row = Row::new()
+                    .s(Gap::new().x(10))
+                    .item(El::new().s(Font::new().no_wrap()).child(match ratio {
+                        Some(ratio) => format!("Loading {filename} {:.0}%", ratio * 100.),
+                        None => format!("Loading {filename}"),
+                    }))
+                    .item(ratio.map(|ratio| {
+                        El::new()
+                            .s(Width::exact(200))
+                            .s(Height::exact(10))
+                            .s(Background::new().color(COLOR_SLATE_BLUE))
+                            .s(RoundedCorners::all(5))
+                            .child(
+                                El::new()
+                                    .s(Width::exact((ratio * 200.) as u32))
+                                    .s(Height::fill())
+                                    .s(RoundedCorners::all(5))
+                                    .s(Background::new().color(COLOR_LIGHT_BLUE)),
+                            )
+                    }))
+                    .item(
+                        Button::new()
+                            .s(Padding::new().x(15).y(5))
+                            .s(Background::new().color_signal(
+                                hovered_signal
+                                    .map_bool(|| COLOR_MEDIUM_SLATE_BLUE, || COLOR_SLATE_BLUE),
+                            ))
+                            .s(RoundedCorners::all(15))
+                            .label("Cancel")
+                            .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
+                            .on_press(move || {
+                                Task::start(platform::cancel_waveform_loading(waveform_id))
+                            }),
+                    );
+                Some(row)
+            }))
+    }
+
+    fn waveform_file_change(&self) -> impl Element {
+        let is_live_tail_signal = map_ref! {
+            let live_tail = self.live_tail.signal(),
+            let is_loaded = self.waveforms.signal_vec_cloned().is_empty().map(not) => {
+                *live_tail && *is_loaded
+            }
+        };
+        let waveforms = self.waveforms.clone();
+        let changed_waveforms = self.changed_waveforms.clone();
+        let live_tail = self.live_tail.clone();
+        Row::new()
+            .s(Align::new().top())
+            .s(Padding::new().top(5))
+            .s(Gap::new().x(10))
+            .item(
+                Column::new()
+                    .s(Gap::new().y(5))
+                    .items_signal_vec(changed_waveforms.signal_vec().map(
+                    clone!((live_tail) move |waveform_id| {
+                        let filename = find_waveform(&waveforms, waveform_id)?.filename;
+                        Row::new()
+                            .s(Gap::new().x(10))
+                            .item(
+                                El::new()
+                                    .s(Font::new().no_wrap())
+                                    .child(format!("'{filename}' changed")),
+                            )
+                            .item(small_button(
+                                "Reload",
+                                "Selected variables, formats and zoom are kept",
+                                move || Task::start(reload_waveform(waveform_id)),
+                            ))
+                            .item(small_button(
+                                "Live tail",
+                                "Reload on every change, e.g. while a simulation writes the file",
+                                clone!((live_tail, changed_waveforms) move || {
+                                    live_tail.set_neq(true);
+                                    for waveform_id in changed_waveforms.lock_ref().iter() {
+                                        Task::start(reload_waveform(*waveform_id));
+                                    }
+                                }),
+                            ))
+                            .apply(Some)
+                    }),
+                )),
+            )
+            .item_signal(is_live_tail_signal.map_true(move || {
+                small_button(
+                    "Stop live tail",
+                    "Waveforms are reloaded on every change of their files",
+                    clone!((live_tail) move || live_tail.set_neq(false)),
+                )
+            }))
+    }
+
+    fn recent_files(&self) -> impl Element {
+        let recent_files = self.recent_files.clone();
+        Column::new()
+            .s(Align::new().top())
+            .s(Padding::new().top(5))
+            .s(Gap::new().y(5))
+            .item_signal(
+                recent_files
+                    .signal_vec_cloned()
+                    .is_empty()
+                    .map_false(|| El::new().child("Recent files")),
+            )
+            .items_signal_vec(recent_files.signal_vec_cloned().map(|path| {
+                let (hovered, hovered_signal) = Mutable::new_and_signal(false);
+                let filename = path
+                    .rsplit(['/', '\\'])
+                    .next()
+                    .unwrap_or_default()
+                    .to_owned();
+                Button::new()
+                    .s(Padding::new().x(15).y(5))
+                    .s(Background::new().color_signal(
+                        hovered_signal.map_bool(|| COLOR_MEDIUM_SLATE_BLUE, || COLOR_SLATE_BLUE),
+                    ))
+                    .s(RoundedCorners::all(15))
+                    .label(El::new().s(Font::new().no_wrap()).child(filename))
+                    .update_raw_el(|raw_el| raw_el.attr("title", &path))
+                    .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
+                    .on_press(move || {
+                        let path = path.clone();
+                        Task::start(async move {
+                            load_waveform_from_path(path).await;
+                        })
+                    })
+            }))
+    }
+
+    fn disabled_components(&self) -> impl Element {
+        let disabled_components = self.disabled_components.clone();
+        Column::new().s(Gap::new().y(5)).items_signal_vec(
+            disabled_components.signal_vec_cloned().enumerate().map(
+                clone!((disabled_components) move |(index, disabled_component)| {
+                    let label = format!(
+                        "{} '{}' disabled: {}",
+                        disabled_component.kind.as_static_str(),
+                        disabled_component.name,
+                        disabled_component.reason,
+                    );
+                    dismissible_notice(label, index, disabled_components.clone())
+                }),
+            ),
+        )
+    }
+
+    fn command_errors(&self) -> impl Element {
+        let command_errors = self.command_errors.clone();
+        Column::new().s(Gap::new().y(5)).items_signal_vec(
+            command_errors.signal_vec_cloned().enumerate().map(
+                clone!((command_errors) move |(index, command_error)| {
+                    dismissible_notice(command_error.to_string(), index, command_errors.clone())
+                }),
+            ),
+        )
+    }
+
     fn layout_switcher(&self) -> impl Element {
         let layout = self.layout.clone();
         let (hovered, hovered_signal) = Mutable::new_and_signal(false);
@@ -217,6 +645,54 @@ impl HeaderPanel {
             .on_press(move || Task::start(platform::open_konata_file()))
     }
 
+    fn toggle_components_panel(&self) -> impl Element {
+        let (hovered, hovered_signal) = Mutable::new_and_signal(false);
+        Button::new()
+            .s(Padding::new().x(20).y(10))
+            .s(Background::new().color_signal(
+                hovered_signal.map_bool(|| COLOR_MEDIUM_SLATE_BLUE, || COLOR_SLATE_BLUE),
+            ))
+            .s(Align::new().left())
+            .s(RoundedCorners::all(15))
+            .label(El::new().s(Font::new().no_wrap()).child("Components"))
+            .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
+            .on_press(move || {
+                let components_panel_open = !COMPONENTS_PANEL_OPEN.get();
+                COMPONENTS_PANEL_OPEN.set(components_panel_open);
+                if components_panel_open {
+                    Task::start(components_panel::refresh_components());
+                }
+            })
+    }
+
+    fn toggle_log_panel(&self) -> impl Element {
+        let (hovered, hovered_signal) = Mutable::new_and_signal(false);
+        Button::new()
+            .s(Padding::new().x(20).y(10))
+            .s(Background::new().color_signal(
+                hovered_signal.map_bool(|| COLOR_MEDIUM_SLATE_BLUE, || COLOR_SLATE_BLUE),
+            ))
+            .s(Align::new().left())
+            .s(RoundedCorners::all(15))
+            .label(El::new().s(Font::new().no_wrap()).child("Logs"))
+            .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
+            .on_press(|| LOG_PANEL_OPEN.update(|is_open| !is_open))
+    }
+
+    fn toggle_diff_panel(&self) -> impl Element {
+        let (hovered, hovered_signal) = Mutable::new_and_signal(false);
+        Button::new()
+            .s(Padding::new().x(20).y(10))
+            .s(Background::new().color_signal(
+                hovered_signal.map_bool(|| COLOR_MEDIUM_SLATE_BLUE, || COLOR_SLATE_BLUE),
+            ))
+            .s(Align::new().left())
+            .s(RoundedCorners::all(15))
+            .label(El::new().s(Font::new().no_wrap()).child("Diff"))
+            .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
+            .on_press(|| DIFF_PANEL_OPEN.update(|is_open| !is_open))
+    }
+
     fn open_terminal(&self) -> impl Element {
         let (hovered, hovered_signal) = Mutable::new_and_signal(false);
         Button::new()
@@ -234,3 +710,42 @@ impl HeaderPanel {
             })
     }
 }
+
+fn small_button(
+    label: &'static str,
+    title: &'static str,
+    on_press: impl FnMut() + 'static,
+) -> impl Element {
+    let (hovered, hovered_signal) = Mutable::new_and_signal(false);
+    Button::new()
+        .s(Padding::new().x(15).y(5))
+        .s(Background::new()
+            .color_signal(hovered_signal.map_bool(|| COLOR_MEDIUM_SLATE_BLUE, || COLOR_SLATE_BLUE)))
+        .s(RoundedCorners::all(15))
+        .label(El::new().s(Font::new().no_wrap()).child(label))
+        .update_raw_el(|raw_el| raw_el.attr("title", title))
+        .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
+        .on_press(on_press)
+}
+
+/// The notice removes itself from `notices` when clicked
+fn dismissible_notice<T: Clone>(
+    label: String,
+    index: ReadOnlyMutable<Option<usize>>,
+    notices: MutableVec<T>,
+) -> impl Element {
+    let (hovered, hovered_signal) = Mutable::new_and_signal(false);
+    Button::new()
+        .s(Padding::new().x(15).y(5))
+        .s(Background::new()
+            .color_signal(hovered_signal.map_bool(|| COLOR_MEDIUM_SLATE_BLUE, || COLOR_FIREBRICK)))
+        .s(RoundedCorners::all(15))
+        .label(label)
+        .update_raw_el(|raw_el| raw_el.attr("title", "Click to dismiss"))
+        .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
+        .on_press(move || {
+            if let Some(index) = index.get() {
+                notices.lock_mut().remove(index);
+            }
+        })
+}
@@ -106,7 +106,7 @@ impl IdePanel {
                         let Some(folder_path) = folder_path else {
                             return None;
                         };
-                        let root = platform::file_tree(folder_path).await;
+                        let root = platform::file_tree(folder_path).await?;
                         Some(this.file_tree_view_item(root))
                     }
                 })
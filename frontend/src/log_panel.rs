@@ -0,0 +1,155 @@
+use crate::theme::*;
+use shared::{LogEntry, LogSeverity};
+use zoon::*;
+
+// Only the newest matching entries are rendered,
+// and they are rendered at most once per the delay even when components log in a loop
+const MAX_LOG_ROWS: usize = 500;
+const LOG_ROWS_REDRAW_DELAY_MS: u32 = 100;
+
+pub static LOG_PANEL_OPEN: Lazy<Mutable<bool>> = Lazy::new(|| false.into());
+
+pub struct LogPanel {
+    log_entries: MutableVec<LogEntry>,
+    // Less severe entries are hidden
+    min_severity: Mutable<LogSeverity>,
+    // Case-insensitive, matched against sources and messages
+    filter: Mutable<String>,
+}
+
+impl LogPanel {
+    pub fn new(log_entries: MutableVec<LogEntry>) -> impl Element {
+        Self {
+            log_entries,
+            min_severity: Mutable::new(LogSeverity::Info),
+            filter: <_>::default(),
+        }
+        .root()
+    }
+
+    fn root(&self) -> impl Element {
+        Column::new()
+            .s(Padding::new().x(20).bottom(15))
+            .s(Gap::new().y(10))
+            .item(self.filters())
+            .item(self.log_entries())
+    }
+
+    fn filters(&self) -> impl Element {
+        Row::new()
+            .s(Gap::new().x(10))
+            .item(self.severity_button(LogSeverity::Info, "All"))
+            .item(self.severity_button(LogSeverity::Warning, "Warnings & Errors"))
+            .item(self.severity_button(LogSeverity::Error, "Errors"))
+            .item(self.filter_input())
+    }
+
+    fn severity_button(&self, severity: LogSeverity, label: &'static str) -> impl Element {
+        let (hovered, hovered_signal) = Mutable::new_and_signal(false);
+        let min_severity = self.min_severity.clone();
+        let background_color = map_ref! {
+            let hovered = hovered_signal,
+            let is_selected = min_severity.signal_ref(move |min_severity| *min_severity == severity) =>
+            if *hovered || *is_selected { COLOR_MEDIUM_SLATE_BLUE } else { COLOR_SLATE_BLUE }
+        };
+        Button::new()
+            .s(Padding::new().x(15).y(5))
+            .s(Background::new().color_signal(background_color))
+            .s(RoundedCorners::all(15))
+            .label(El::new().s(Font::new().no_wrap()).child(label))
+            .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
+            .on_press(move || min_severity.set_neq(severity))
+    }
+
+    fn filter_input(&self) -> impl Element {
+        let filter = self.filter.clone();
+        TextInput::new()
+            .s(Width::fill().max(400))
+            .s(Padding::new().x(15).y(5))
+            .s(RoundedCorners::all(15))
+            .s(Background::new().color(COLOR_SLATE_BLUE_WITH_ALPHA))
+            .s(Font::new().color(COLOR_LIGHT_BLUE))
+            .label_hidden("log filter")
+            .placeholder(Placeholder::new("Filter"))
+            .on_change(move |text| filter.set_neq(text.to_lowercase()))
+    }
+
+    fn log_entries(&self) -> impl Element {
+        let log_entries = self
+            .log_entries
+            .signal_vec_cloned()
+            .to_signal_cloned()
+            .throttle(|| Timer::sleep(LOG_ROWS_REDRAW_DELAY_MS));
+        let log_rows = map_ref! {
+            let log_entries = log_entries,
+            let min_severity = self.min_severity.signal(),
+            let filter = self.filter.signal_cloned() => {
+                let mut log_rows = log_entries
+                    .iter()
+                    .rev()
+                    .filter(|log_entry| {
+                        log_entry.severity >= *min_severity
+                            && format!("{} {}", log_entry.source, log_entry.message)
+                                .to_lowercase()
+                                .contains(filter.as_str())
+                    })
+                    .take(MAX_LOG_ROWS)
+                    .cloned()
+                    .collect::<Vec<_>>();
+                log_rows.reverse();
+                log_rows
+            }
+        };
+        Column::new()
+            .s(Height::default().max(250))
+            .s(Scrollbars::y_and_clip_x())
+            .s(Font::new().family([FontFamily::new("Courier New"), FontFamily::Monospace]))
+            .items_signal_vec(log_rows.to_signal_vec().map(Self::log_entry_row))
+    }
+
+    fn log_entry_row(log_entry: LogEntry) -> impl Element {
+        let LogEntry {
+            timestamp,
+            severity,
+            source,
+            message,
+        } = log_entry;
+        let severity_color = match severity {
+            LogSeverity::Info => COLOR_LIGHT_BLUE,
+            LogSeverity::Warning => COLOR_ORANGE,
+            LogSeverity::Error => COLOR_FIREBRICK,
+        };
+        Row::new()
+            .s(Gap::new().x(10))
+            .item(
+                El::new()
+                    .s(Width::exact(110))
+                    .child(format_timestamp(timestamp)),
+            )
+            .item(
+                El::new()
+                    .s(Width::exact(70))
+                    .s(Font::new().color(severity_color))
+                    .child(severity.as_static_str()),
+            )
+            .item(
+                El::new()
+                    .s(Width::exact(200))
+                    .s(Font::new().weight(FontWeight::Bold))
+                    .child(source),
+            )
+            .item(Paragraph::new().s(Width::fill()).content(message))
+    }
+}
+
+/// Local time with milliseconds, e.g. `14:05:09.042`
+fn format_timestamp(timestamp: u64) -> String {
+    let date = js_sys::Date::new(&JsValue::from_f64(timestamp as f64));
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        date.get_hours(),
+        date.get_minutes(),
+        date.get_seconds(),
+        date.get_milliseconds()
+    )
+}
@@ -1,6 +1,7 @@
 use shared::DiagramConnectorMessage;
 use std::{mem, path::PathBuf, sync::Arc};
 use term::TERM_OPEN;
+use wellen::GetItem;
 use zoon::*;
 
 mod platform;
@@ -24,6 +25,15 @@ use header_panel::HeaderPanel;
 mod command_panel;
 use command_panel::CommandPanel;
 
+mod components_panel;
+use components_panel::{ComponentsPanel, COMPONENTS_PANEL_OPEN};
+
+mod log_panel;
+use log_panel::{LogPanel, LOG_PANEL_OPEN};
+
+mod diff_panel;
+use diff_panel::{DiffPanel, DIFF_PANEL_OPEN};
+
 pub mod theme;
 use theme::*;
 
@@ -48,12 +58,74 @@ enum Mode {
 }
 
 type Filename = String;
+type FilePath = String;
+type FullVarName = String;
+type DecoderName = String;
+// Vars with the same name in different waveforms have their own formats and decoders
+type WaveformVarName = (shared::WaveformId, FullVarName);
+
+#[derive(Clone, Default)]
+struct TimeMarkers {
+    cursor: Mutable<Option<wellen::Time>>,
+    markers: MutableVec<shared::Marker>,
+}
+
+/// One of the waveforms loaded at once, e.g. golden and failing simulation runs
+#[derive(Clone)]
+struct LoadedWaveform {
+    id: shared::WaveformId,
+    filename: Filename,
+    hierarchy: Arc<wellen::Hierarchy>,
+}
+
+impl LoadedWaveform {
+    fn full_var_name(&self, var_ref: wellen::VarRef) -> FullVarName {
+        self.hierarchy.get(var_ref).full_name(&self.hierarchy)
+    }
+}
+
+/// Vars from different waveforms are shown in the same waveform panel
+#[derive(Clone, Copy, PartialEq)]
+struct SelectedVar {
+    waveform_id: shared::WaveformId,
+    var_ref: wellen::VarRef,
+}
+
+fn find_waveform(
+    waveforms: &MutableVec<LoadedWaveform>,
+    waveform_id: shared::WaveformId,
+) -> Option<LoadedWaveform> {
+    waveforms
+        .lock_ref()
+        .iter()
+        .find(|waveform| waveform.id == waveform_id)
+        .cloned()
+}
 
 #[derive(Default)]
 struct Store {
-    selected_var_refs: MutableVec<wellen::VarRef>,
-    hierarchy: Mutable<Option<Arc<wellen::Hierarchy>>>,
-    loaded_filename: Mutable<Option<Filename>>,
+    // In the loading order, times are shown in the time units of the first one
+    waveforms: MutableVec<LoadedWaveform>,
+    selected_vars: MutableVec<SelectedVar>,
+    var_decoders: MutableBTreeMap<WaveformVarName, DecoderName>,
+    var_formats: MutableBTreeMap<WaveformVarName, shared::VarFormat>,
+    var_render_modes: MutableBTreeMap<WaveformVarName, shared::RenderMode>,
+    annotation_rows: MutableVec<shared::AnnotationRow>,
+    // Results of the last diff of two waveforms
+    var_diffs: MutableVec<shared::VarDiff>,
+    diffed_waveforms: Mutable<Option<(shared::WaveformId, shared::WaveformId)>>,
+    selected_var_diff: Mutable<Option<shared::VarDiff>>,
+    disabled_components: MutableVec<shared::DisabledComponent>,
+    command_errors: MutableVec<shared::CommandError>,
+    recent_files: MutableVec<FilePath>,
+    // Only `WaveformLoadingStatus::Loading`
+    waveform_loadings: MutableVec<shared::WaveformLoadingStatus>,
+    // Waveforms with changed files that aren't reloaded automatically
+    changed_waveforms: MutableVec<shared::WaveformId>,
+    live_tail: Mutable<bool>,
+    components: MutableVec<shared::ComponentInfo>,
+    log_entries: MutableVec<shared::LogEntry>,
+    time_markers: TimeMarkers,
     pixi_canvas_controller: Mutable<Mutable<Option<SendWrapper<PixiController>>>>,
     excalidraw_canvas_controller: Mutable<Mutable<Option<SendWrapper<ExcalidrawController>>>>,
     code_editor_controller: Mutable<Mutable<Option<SendWrapper<CodeEditorController>>>>,
@@ -110,6 +182,74 @@ fn main() {
             }
         })
         .await;
+        platform::listen_disabled_components(|disabled_component| {
+            STORE
+                .disabled_components
+                .lock_mut()
+                .push_cloned(disabled_component);
+            Task::start(components_panel::refresh_components());
+        })
+        .await;
+        platform::listen_reloaded_components(|_| {
+            Task::start(components_panel::refresh_components());
+        })
+        .await;
+        platform::listen_log_entries(|new_log_entries| {
+            let mut log_entries = STORE.log_entries.lock_mut();
+            log_entries.extend(new_log_entries);
+            let overflow = log_entries.len().saturating_sub(shared::MAX_LOG_ENTRIES);
+            if overflow > 0 {
+                let mut index = 0;
+                log_entries.retain(|_| {
+                    index += 1;
+                    index > overflow
+                });
+            }
+        })
+        .await;
+        // Entries logged before the listener has been registered
+        STORE
+            .log_entries
+            .lock_mut()
+            .replace_cloned(platform::logs().await);
+        platform::listen_waveform_loading_status(|status| {
+            let waveform_id = status.waveform_id();
+            let mut waveform_loadings = STORE.waveform_loadings.lock_mut();
+            let index = waveform_loadings
+                .iter()
+                .position(|loading| loading.waveform_id() == waveform_id);
+            if let shared::WaveformLoadingStatus::Loading { .. } = status {
+                match index {
+                    Some(index) => waveform_loadings.set_cloned(index, status),
+                    None => waveform_loadings.push_cloned(status),
+                }
+                return;
+            }
+            if let Some(index) = index {
+                waveform_loadings.remove(index);
+            }
+            drop(waveform_loadings);
+            if let shared::WaveformLoadingStatus::Loaded { .. } = status {
+                // The time axis shared by all waveforms may be longer now
+                if let Some(controller) = STORE.pixi_canvas_controller.get_cloned().get_cloned() {
+                    Task::start(async move { controller.redraw_all_rows().await });
+                }
+            } else {
+                header_panel::remove_waveform(waveform_id);
+            }
+        })
+        .await;
+        platform::listen_dropped_waveform_files(|path| {
+            Task::start(async move {
+                header_panel::load_waveform_from_path(path).await;
+            });
+        })
+        .await;
+        platform::listen_waveform_file_changes(header_panel::on_waveform_file_change).await;
+        header_panel::refresh_recent_files().await;
+        for path in platform::waveform_paths_from_args().await {
+            header_panel::load_waveform_from_path(path).await;
+        }
         platform::listen_term_update(|down_msg| {
             term::TERMINAL_STATE.set(down_msg);
         })
@@ -118,11 +258,26 @@ fn main() {
 }
 
 fn root() -> impl Element {
-    let hierarchy = STORE.hierarchy.clone();
-    let selected_var_refs = STORE.selected_var_refs.clone();
+    let waveforms = STORE.waveforms.clone();
+    let selected_vars = STORE.selected_vars.clone();
+    let var_decoders = STORE.var_decoders.clone();
+    let var_formats = STORE.var_formats.clone();
+    let var_render_modes = STORE.var_render_modes.clone();
+    let annotation_rows = STORE.annotation_rows.clone();
+    let var_diffs = STORE.var_diffs.clone();
+    let diffed_waveforms = STORE.diffed_waveforms.clone();
+    let selected_var_diff = STORE.selected_var_diff.clone();
+    let disabled_components = STORE.disabled_components.clone();
+    let command_errors = STORE.command_errors.clone();
+    let recent_files = STORE.recent_files.clone();
+    let waveform_loadings = STORE.waveform_loadings.clone();
+    let changed_waveforms = STORE.changed_waveforms.clone();
+    let live_tail = STORE.live_tail.clone();
+    let components = STORE.components.clone();
+    let log_entries = STORE.log_entries.clone();
+    let time_markers = STORE.time_markers.clone();
     let layout: Mutable<Layout> = <_>::default();
     let mode: Mutable<Mode> = <_>::default();
-    let loaded_filename = STORE.loaded_filename.clone();
     let pixi_canvas_controller = STORE.pixi_canvas_controller.clone();
     let excalidraw_canvas_controller = STORE.excalidraw_canvas_controller.clone();
     let code_editor_controller = STORE.code_editor_controller.clone();
@@ -132,12 +287,37 @@ fn root() -> impl Element {
         .s(Scrollbars::y_and_clip_x())
         .s(Font::new().color(COLOR_LAVENDER))
         .item(HeaderPanel::new(
-            hierarchy.clone(),
+            waveforms.clone(),
             layout.clone(),
             mode.clone(),
-            loaded_filename.clone(),
+            disabled_components.clone(),
+            command_errors.clone(),
+            recent_files.clone(),
+            waveform_loadings.clone(),
+            changed_waveforms.clone(),
+            live_tail.clone(),
+        ))
+        .item_signal(
+            COMPONENTS_PANEL_OPEN
+                .signal()
+                .map_true(move || ComponentsPanel::new(components.clone())),
+        )
+        .item_signal(
+            LOG_PANEL_OPEN
+                .signal()
+                .map_true(move || LogPanel::new(log_entries.clone())),
+        )
+        .item_signal(DIFF_PANEL_OPEN.signal().map_true(
+            clone!((waveforms, selected_vars, time_markers, selected_var_diff) move || DiffPanel::new(
+                waveforms.clone(),
+                selected_vars.clone(),
+                time_markers.clone(),
+                var_diffs.clone(),
+                diffed_waveforms.clone(),
+                selected_var_diff.clone(),
+            )),
         ))
-        .item_signal(mode.signal().map(clone!((hierarchy, selected_var_refs, loaded_filename, pixi_canvas_controller) move |mode| match mode {
+        .item_signal(mode.signal().map(clone!((waveforms, selected_vars, time_markers, pixi_canvas_controller, var_decoders, var_formats, var_render_modes, annotation_rows, selected_var_diff) move |mode| match mode {
             Mode::Waves => {
                 Column::new()
                     .s(Height::fill())
@@ -148,42 +328,61 @@ fn root() -> impl Element {
                             .s(Gap::new().x(15))
                             .s(Height::growable().min(150))
                             .item(ControlsPanel::new(
-                                hierarchy.clone(),
-                                selected_var_refs.clone(),
+                                waveforms.clone(),
+                                selected_vars.clone(),
                                 layout.clone(),
-                                loaded_filename.clone(),
                             ))
                             .item_signal({
-                                let hierarchy = hierarchy.clone();
-                                let selected_var_refs = selected_var_refs.clone();
-                                let loaded_filename = loaded_filename.clone();
+                                let waveforms = waveforms.clone();
+                                let selected_vars = selected_vars.clone();
+                                let time_markers = time_markers.clone();
                                 let pixi_canvas_controller = pixi_canvas_controller.clone();
+                                let var_decoders = var_decoders.clone();
+                                let var_formats = var_formats.clone();
+                                let var_render_modes = var_render_modes.clone();
+                                let annotation_rows = annotation_rows.clone();
+                                let selected_var_diff = selected_var_diff.clone();
                                 map_ref!{
                                     let layout = layout.signal(),
-                                    let hierarchy_is_some = hierarchy.signal_ref(Option::is_some) => {
-                                        (*hierarchy_is_some && matches!(layout, Layout::Tree)).then(clone!((hierarchy, selected_var_refs, loaded_filename, pixi_canvas_controller) move || WaveformPanel::new(
-                                            hierarchy.clone(),
-                                            selected_var_refs.clone(),
-                                            loaded_filename.clone(),
+                                    let has_waveforms = waveforms.signal_vec_cloned().is_empty().map(not).dedupe() => {
+                                        (*has_waveforms && matches!(layout, Layout::Tree)).then(clone!((waveforms, selected_vars, time_markers, pixi_canvas_controller, var_decoders, var_formats, var_render_modes, annotation_rows, selected_var_diff) move || WaveformPanel::new(
+                                            waveforms.clone(),
+                                            selected_vars.clone(),
+                                            time_markers.clone(),
                                             pixi_canvas_controller.clone(),
+                                            var_decoders.clone(),
+                                            var_formats.clone(),
+                                            var_render_modes.clone(),
+                                            annotation_rows.clone(),
+                                            selected_var_diff.clone(),
                                         )))
                                     }
                                 }
                             }),
                     )
                     .item_signal({
-                        let hierarchy = hierarchy.clone();
-                        let selected_var_refs = selected_var_refs.clone();
-                        let loaded_filename = loaded_filename.clone();
+                        let waveforms = waveforms.clone();
+                        let selected_vars = selected_vars.clone();
+                        let time_markers = time_markers.clone();
                         let pixi_canvas_controller = pixi_canvas_controller.clone();
+                        let var_decoders = var_decoders.clone();
+                        let var_formats = var_formats.clone();
+                        let var_render_modes = var_render_modes.clone();
+                        let annotation_rows = annotation_rows.clone();
+                        let selected_var_diff = selected_var_diff.clone();
                         map_ref!{
                             let layout = layout.signal(),
-                            let hierarchy_is_some = hierarchy.signal_ref(Option::is_some) => {
-                                (*hierarchy_is_some && matches!(layout, Layout::Columns)).then(clone!((hierarchy, selected_var_refs, loaded_filename, pixi_canvas_controller) move || WaveformPanel::new(
-                                    hierarchy.clone(),
-                                    selected_var_refs.clone(),
-                                    loaded_filename.clone(),
+                            let has_waveforms = waveforms.signal_vec_cloned().is_empty().map(not).dedupe() => {
+                                (*has_waveforms && matches!(layout, Layout::Columns)).then(clone!((waveforms, selected_vars, time_markers, pixi_canvas_controller, var_decoders, var_formats, var_render_modes, annotation_rows, selected_var_diff) move || WaveformPanel::new(
+                                    waveforms.clone(),
+                                    selected_vars.clone(),
+                                    time_markers.clone(),
                                     pixi_canvas_controller.clone(),
+                                    var_decoders.clone(),
+                                    var_formats.clone(),
+                                    var_render_modes.clone(),
+                                    annotation_rows.clone(),
+                                    selected_var_diff.clone(),
                                 )))
                             }
                         }
@@ -19,12 +19,14 @@ mod browser;
 use browser as platform;
 
 type Filename = String;
+type FilePath = String;
 type FolderPath = String;
 type JavascriptCode = String;
 
 type AddedDecodersCount = usize;
 type RemovedDecodersCount = usize;
 type DecoderPath = String;
+type DecoderName = String;
 
 type AddedDiagramConnectorsCount = usize;
 type RemovedDiagramConnectorsCount = usize;
@@ -32,6 +34,14 @@ type DiagramConnectorPath = String;
 type DiagramConnectorName = String;
 type ComponentId = String;
 
+type AddedProtocolDecodersCount = usize;
+type RemovedProtocolDecodersCount = usize;
+type ProtocolDecoderPath = String;
+type ProtocolDecoderName = String;
+type FullVarName = String;
+
+type ComponentIndex = usize;
+
 use shared::term::{TerminalDownMsg, TerminalScreen};
 
 pub async fn show_window() {
@@ -40,41 +50,152 @@ pub async fn show_window() {
 
 // @TODO allow only supported file types by Wellen
 // @TODO remove the `file` parameter once we don't have to use FileInput element
-pub async fn pick_and_load_waveform(file: Option<gloo_file::File>) -> Option<Filename> {
-    platform::pick_and_load_waveform(file).await
+pub async fn pick_and_load_waveform(file: Option<gloo_file::File>) -> Option<shared::WaveformInfo> {
+    output_or_notify(platform::pick_and_load_waveform(file).await)
+}
+
+pub async fn load_waveform_from_path(path: FilePath) -> Option<shared::WaveformInfo> {
+    platform::load_waveform_from_path(path)
+        .await
+        .map_err(notify_command_error)
+        .ok()
+}
+
+/// Paths passed to the app, e.g. `fastwave golden.vcd failing.vcd`
+pub async fn waveform_paths_from_args() -> Vec<FilePath> {
+    platform::waveform_paths_from_args().await
+}
+
+/// Most recently loaded waveform files first
+pub async fn recent_files() -> Vec<FilePath> {
+    platform::recent_files().await
+}
+
+pub async fn listen_dropped_waveform_files(on_drop: impl FnMut(FilePath) + 'static) {
+    platform::listen_dropped_waveform_files(on_drop).await;
+}
+
+/// Errors aren't shown to the user, the reloaded file may be still being written by a simulation
+pub async fn reload_waveform(
+    waveform_id: shared::WaveformId,
+) -> Result<Filename, shared::CommandError> {
+    platform::reload_waveform(waveform_id).await
+}
+
+pub async fn listen_waveform_file_changes(on_change: impl FnMut(shared::WaveformId) + 'static) {
+    platform::listen_waveform_file_changes(on_change).await
+}
+
+pub async fn unload_waveform(waveform_id: shared::WaveformId) {
+    output_or_notify(platform::unload_waveform(waveform_id).await)
+}
+
+pub async fn cancel_waveform_loading(waveform_id: shared::WaveformId) {
+    output_or_notify(platform::cancel_waveform_loading(waveform_id).await)
+}
+
+/// Failed loadings are shown to the user in the header panel
+pub async fn listen_waveform_loading_status(
+    mut on_status: impl FnMut(shared::WaveformLoadingStatus) + 'static,
+) {
+    platform::listen_waveform_loading_status(move |status| {
+        if let shared::WaveformLoadingStatus::Failed { error, .. } = &status {
+            notify_command_error(error.clone());
+        }
+        on_status(status)
+    })
+    .await;
 }
 
 // @TODO allow only supported file type (*.fw.js)
 // @TODO remove the `file` parameter once we don't have to use FileInput element
 pub async fn load_file_with_selected_vars(file: Option<gloo_file::File>) -> Option<JavascriptCode> {
-    platform::load_file_with_selected_vars(file).await
+    output_or_notify(platform::load_file_with_selected_vars(file).await)
 }
 
-pub async fn get_hierarchy() -> wellen::Hierarchy {
-    platform::get_hierarchy().await
+pub async fn get_hierarchy(waveform_id: shared::WaveformId) -> Option<wellen::Hierarchy> {
+    platform::get_hierarchy(waveform_id)
+        .await
+        .map_err(notify_command_error)
+        .ok()
 }
 
 pub async fn load_signal_and_get_timeline(
+    waveform_id: shared::WaveformId,
     signal_ref: wellen::SignalRef,
+    var_name: FullVarName,
     timeline_zoom: f64,
     timeline_viewport_width: u32,
     timeline_viewport_x: i32,
     block_height: u32,
     var_format: shared::VarFormat,
+    render_mode: shared::RenderMode,
+    decoder_name: Option<DecoderName>,
 ) -> shared::Timeline {
-    platform::load_signal_and_get_timeline(
-        signal_ref,
-        timeline_zoom,
-        timeline_viewport_width,
-        timeline_viewport_x,
-        block_height,
-        var_format,
+    output_or_notify(
+        platform::load_signal_and_get_timeline(
+            waveform_id,
+            signal_ref,
+            var_name,
+            timeline_zoom,
+            timeline_viewport_width,
+            timeline_viewport_x,
+            block_height,
+            var_format,
+            render_mode,
+            decoder_name,
+        )
+        .await,
+    )
+}
+
+/// The ruler is empty until a waveform is loaded
+pub async fn get_timeline_ruler(
+    timeline_zoom: f64,
+    timeline_viewport_width: u32,
+    timeline_viewport_x: i32,
+) -> shared::TimelineRuler {
+    output_or_notify(
+        platform::get_timeline_ruler(timeline_zoom, timeline_viewport_width, timeline_viewport_x)
+            .await,
+    )
+    .unwrap_or_default()
+}
+
+/// `time` is on the time axis shared by all loaded waveforms
+pub async fn get_value_at_time(
+    waveform_id: shared::WaveformId,
+    signal_ref: wellen::SignalRef,
+    time: wellen::Time,
+    var_format: shared::VarFormat,
+) -> Option<String> {
+    output_or_notify(platform::get_value_at_time(waveform_id, signal_ref, time, var_format).await)
+}
+
+/// `time` is on the time axis shared by all loaded waveforms
+pub async fn get_values_at_time(
+    waveform_id: shared::WaveformId,
+    signal_refs_and_var_formats: Vec<(wellen::SignalRef, shared::VarFormat)>,
+    time: wellen::Time,
+) -> Vec<Option<String>> {
+    if signal_refs_and_var_formats.is_empty() {
+        return Vec::new();
+    }
+    output_or_notify(
+        platform::get_values_at_time(waveform_id, signal_refs_and_var_formats, time).await,
     )
-    .await
 }
 
-pub async fn unload_signal(signal_ref: wellen::SignalRef) {
-    platform::unload_signal(signal_ref).await
+pub async fn unload_signal(waveform_id: shared::WaveformId, signal_ref: wellen::SignalRef) {
+    output_or_notify(platform::unload_signal(waveform_id, signal_ref).await)
+}
+
+/// Vars of both waveforms with the same full names but different values
+pub async fn diff_waveforms(
+    waveform_id_a: shared::WaveformId,
+    waveform_id_b: shared::WaveformId,
+) -> Vec<shared::VarDiff> {
+    output_or_notify(platform::diff_waveforms(waveform_id_a, waveform_id_b).await)
 }
 
 pub async fn send_char(c: String) {
@@ -92,11 +213,53 @@ pub async fn add_decoders(decoder_paths: Vec<DecoderPath>) -> AddedDecodersCount
 pub async fn remove_all_decoders() -> RemovedDecodersCount {
     let count = platform::remove_all_decoders().await;
     if count > 0 {
+        prune_var_decoders().await;
         redraw_all_timeline_rows().await;
     }
     count
 }
 
+pub async fn decoder_names() -> Vec<DecoderName> {
+    platform::decoder_names().await
+}
+
+pub async fn decoders_cache_stats() -> shared::DecodersCacheStats {
+    platform::decoders_cache_stats().await
+}
+
+/// Failed commands are shown to the user in the header panel
+fn notify_command_error(error: shared::CommandError) {
+    zoon::eprintln!("Command failed: {error}");
+    STORE.command_errors.lock_mut().push_cloned(error);
+}
+
+fn output_or_notify<T: Default>(output: Result<T, shared::CommandError>) -> T {
+    output.unwrap_or_else(|error| {
+        notify_command_error(error);
+        T::default()
+    })
+}
+
+/// Vars assigned to removed decoders are shown without a decoder,
+/// assignments of disabled decoders are kept to be used again once they are enabled
+async fn prune_var_decoders() {
+    let decoder_names = platform::components()
+        .await
+        .into_iter()
+        .filter(|component| component.kind == shared::ComponentKind::Decoder)
+        .map(|component| component.name)
+        .collect::<Vec<_>>();
+    let mut var_decoders = STORE.var_decoders.lock_mut();
+    let removed_var_names = var_decoders
+        .iter()
+        .filter(|(_, decoder_name)| !decoder_names.contains(decoder_name))
+        .map(|(waveform_var_name, _)| waveform_var_name.clone())
+        .collect::<Vec<_>>();
+    for waveform_var_name in removed_var_names {
+        var_decoders.remove(&waveform_var_name);
+    }
+}
+
 async fn redraw_all_timeline_rows() {
     if let Some(controller) = STORE.pixi_canvas_controller.get_cloned().get_cloned() {
         controller.redraw_all_rows().await
@@ -121,6 +284,25 @@ pub async fn listen_diagram_connectors_messages(
     platform::listen_diagram_connectors_messages(on_message).await;
 }
 
+pub async fn listen_disabled_components(
+    on_disabled: impl FnMut(shared::DisabledComponent) + 'static,
+) {
+    platform::listen_disabled_components(on_disabled).await;
+}
+
+/// Timeline rows are redrawn when a reloaded component is a decoder
+pub async fn listen_reloaded_components(
+    mut on_reloaded: impl FnMut(shared::ComponentInfo) + 'static,
+) {
+    platform::listen_reloaded_components(move |reloaded_component| {
+        if reloaded_component.kind == shared::ComponentKind::Decoder {
+            zoon::Task::start(redraw_all_timeline_rows());
+        }
+        on_reloaded(reloaded_component)
+    })
+    .await;
+}
+
 pub async fn listen_term_update(on_message: impl FnMut(TerminalDownMsg) + 'static) {
     platform::listen_term_update(on_message).await;
 }
@@ -133,8 +315,74 @@ pub async fn notify_diagram_connector_text_change(
     platform::notify_diagram_connector_text_change(diagram_connector, component_id, text).await;
 }
 
+pub async fn add_protocol_decoders(
+    protocol_decoder_paths: Vec<ProtocolDecoderPath>,
+) -> AddedProtocolDecodersCount {
+    platform::add_protocol_decoders(protocol_decoder_paths).await
+}
+
+pub async fn remove_all_protocol_decoders() -> RemovedProtocolDecodersCount {
+    platform::remove_all_protocol_decoders().await
+}
+
+pub async fn decode_protocol(
+    waveform_id: shared::WaveformId,
+    protocol_decoder_name: ProtocolDecoderName,
+    input_var_names: Vec<FullVarName>,
+) -> Vec<shared::AnnotationRow> {
+    output_or_notify(
+        platform::decode_protocol(waveform_id, protocol_decoder_name, input_var_names).await,
+    )
+}
+
+pub async fn components() -> Vec<shared::ComponentInfo> {
+    platform::components().await
+}
+
+pub async fn set_component_enabled(
+    kind: shared::ComponentKind,
+    index: ComponentIndex,
+    is_enabled: bool,
+) -> bool {
+    let is_changed = platform::set_component_enabled(kind, index, is_enabled).await;
+    if is_changed && kind == shared::ComponentKind::Decoder {
+        redraw_all_timeline_rows().await;
+    }
+    is_changed
+}
+
+pub async fn remove_component(kind: shared::ComponentKind, index: ComponentIndex) -> bool {
+    let is_removed = platform::remove_component(kind, index).await;
+    if is_removed && kind == shared::ComponentKind::Decoder {
+        prune_var_decoders().await;
+        redraw_all_timeline_rows().await;
+    }
+    is_removed
+}
+
+pub async fn move_component(
+    kind: shared::ComponentKind,
+    old_index: ComponentIndex,
+    new_index: ComponentIndex,
+) -> bool {
+    let is_moved = platform::move_component(kind, old_index, new_index).await;
+    if is_moved && kind == shared::ComponentKind::Decoder {
+        redraw_all_timeline_rows().await;
+    }
+    is_moved
+}
+
+pub async fn logs() -> Vec<shared::LogEntry> {
+    platform::logs().await
+}
+
+/// Entries logged shortly after each other are received together
+pub async fn listen_log_entries(on_log_entries: impl FnMut(Vec<shared::LogEntry>) + 'static) {
+    platform::listen_log_entries(on_log_entries).await;
+}
+
 pub async fn open_konata_file() {
-    platform::open_konata_file().await;
+    output_or_notify(platform::open_konata_file().await)
 }
 
 pub async fn read_file(path: &str) -> Result<String, String> {
@@ -142,9 +390,9 @@ pub async fn read_file(path: &str) -> Result<String, String> {
 }
 
 pub async fn select_folder_to_open() -> Option<FolderPath> {
-    platform::select_folder_to_open().await
+    output_or_notify(platform::select_folder_to_open().await)
 }
 
-pub async fn file_tree(path: PathBuf) -> shared::FileTreeItem {
-    platform::file_tree(path).await
+pub async fn file_tree(path: PathBuf) -> Option<shared::FileTreeItem> {
+    output_or_notify(platform::file_tree(path).await.map(Some))
 }
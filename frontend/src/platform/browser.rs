@@ -1,32 +1,75 @@
-use shared::wellen_helpers;
+use shared::{waveform_commands, wellen_helpers, CommandError, TimeAxis, WaveformId, WaveformInfo};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Mutex;
 use wellen::simple::Waveform;
 use zoon::{eprintln, *};
 
 #[derive(Default)]
 struct BrowserPlatformStore {
-    waveform: Mutex<Option<Waveform>>,
+    waveforms: Mutex<BTreeMap<WaveformId, Waveform>>,
+    next_waveform_id: AtomicUsize,
 }
 
 static BROWSER_PLATFORM_STORE: Lazy<BrowserPlatformStore> = lazy::default();
 
+fn loaded_waveform_mut(
+    waveforms: &mut BTreeMap<WaveformId, Waveform>,
+    waveform_id: WaveformId,
+) -> Result<&mut Waveform, CommandError> {
+    waveforms
+        .get_mut(&waveform_id)
+        .ok_or(CommandError::WaveformNotFound { waveform_id })
+}
+
+fn time_axis(waveforms: &BTreeMap<WaveformId, Waveform>) -> Option<TimeAxis> {
+    TimeAxis::shared(waveforms.values().map(TimeAxis::new))
+}
+
+/// Converts `time` on the shared time axis to the time of the waveform
+fn waveform_time(
+    waveforms: &BTreeMap<WaveformId, Waveform>,
+    waveform_id: WaveformId,
+    time: wellen::Time,
+) -> wellen::Time {
+    match (time_axis(waveforms), waveforms.get(&waveform_id)) {
+        (Some(time_axis), Some(waveform)) => time_axis.time_to(time, TimeAxis::new(waveform)),
+        _ => time,
+    }
+}
+
 pub(super) async fn show_window() {}
 
 pub(super) async fn pick_and_load_waveform(
     file: Option<gloo_file::File>,
-) -> Option<super::Filename> {
+) -> Result<Option<WaveformInfo>, CommandError> {
     let file = file.unwrap_throw();
 
     let content = gloo_file::futures::read_as_bytes(&file)
         .await
-        .unwrap_throw();
+        .map_err(|error| CommandError::FileReadingFailed {
+            path: file.name(),
+            reason: error.to_string(),
+        })?;
 
-    let waveform = wellen_helpers::read_from_bytes(content);
-    let Ok(waveform) = waveform else {
-        panic!("Waveform file reading failed")
-    };
-    *BROWSER_PLATFORM_STORE.waveform.lock().unwrap_throw() = Some(waveform);
-    Some(file.name())
+    let waveform = wellen_helpers::read_from_bytes(content).map_err(|error| {
+        CommandError::WaveformLoadingFailed {
+            path: file.name(),
+            reason: error.to_string(),
+        }
+    })?;
+    let waveform_id = BROWSER_PLATFORM_STORE
+        .next_waveform_id
+        .fetch_add(1, Ordering::Relaxed);
+    BROWSER_PLATFORM_STORE
+        .waveforms
+        .lock()
+        .unwrap_throw()
+        .insert(waveform_id, waveform);
+    Ok(Some(WaveformInfo {
+        id: waveform_id,
+        filename: file.name(),
+    }))
 }
 
 // @TODO Use this `pick_and_load_waveform` version once `showOpenFilePicker` is supported by Safari and Firefox
@@ -67,42 +110,117 @@ pub(super) async fn pick_and_load_waveform(
 //     Some(file.name())
 // }
 
+pub(super) async fn load_waveform_from_path(
+    path: super::FilePath,
+) -> Result<WaveformInfo, CommandError> {
+    Err(CommandError::WaveformLoadingFailed {
+        path,
+        reason: "Loading waveforms from paths is not supported in the browser".to_owned(),
+    })
+}
+
+pub(super) async fn waveform_paths_from_args() -> Vec<super::FilePath> {
+    // There are no command-line arguments in the browser
+    Vec::new()
+}
+
+pub(super) async fn recent_files() -> Vec<super::FilePath> {
+    // Waveforms can't be loaded from paths in the browser
+    Vec::new()
+}
+
+pub(super) async fn listen_dropped_waveform_files(_on_drop: impl FnMut(super::FilePath) + 'static) {
+    // Waveforms can't be loaded from paths in the browser
+}
+
+pub(super) async fn reload_waveform(
+    _waveform_id: WaveformId,
+) -> Result<super::Filename, CommandError> {
+    Err(CommandError::Unexpected {
+        reason: "Reloading waveforms is not supported in the browser".to_owned(),
+    })
+}
+
+pub(super) async fn listen_waveform_file_changes(_on_change: impl FnMut(WaveformId) + 'static) {
+    // Files can't be watched in the browser
+}
+
+pub(super) async fn unload_waveform(waveform_id: WaveformId) -> Result<(), CommandError> {
+    BROWSER_PLATFORM_STORE
+        .waveforms
+        .lock()
+        .unwrap_throw()
+        .remove(&waveform_id);
+    Ok(())
+}
+
+pub(super) async fn cancel_waveform_loading(_waveform_id: WaveformId) -> Result<(), CommandError> {
+    // Waveforms are loaded at once in the browser
+    Ok(())
+}
+
+pub(super) async fn listen_waveform_loading_status(
+    _on_status: impl FnMut(shared::WaveformLoadingStatus) + 'static,
+) {
+    // Waveforms are loaded at once in the browser
+}
+
 // @TODO allow only supported file type (*.fw.js)
 // @TODO remove the `file` parameter once we don't have to use FileInput element
 pub async fn load_file_with_selected_vars(
     file: Option<gloo_file::File>,
-) -> Option<super::JavascriptCode> {
+) -> Result<Option<super::JavascriptCode>, CommandError> {
     let file = file.unwrap_throw();
 
-    let javascript_code = gloo_file::futures::read_as_text(&file).await.unwrap_throw();
+    let javascript_code = gloo_file::futures::read_as_text(&file)
+        .await
+        .map_err(|error| CommandError::FileReadingFailed {
+            path: file.name(),
+            reason: error.to_string(),
+        })?;
 
-    Some(javascript_code)
+    Ok(Some(javascript_code))
 }
 
 // @TODO Use alternative `load_file_with_selected_vars` version once `showOpenFilePicker` is supported by Safari and Firefox
 // https://caniuse.com/mdn-api_window_showopenfilepicker
 // (see the `pick_and_load_waveform` method above)
 
-pub(super) async fn get_hierarchy() -> wellen::Hierarchy {
-    let waveform = BROWSER_PLATFORM_STORE.waveform.lock().unwrap_throw();
-    let hierarchy = waveform.as_ref().unwrap_throw().hierarchy();
+pub(super) async fn get_hierarchy(
+    waveform_id: WaveformId,
+) -> Result<wellen::Hierarchy, CommandError> {
+    let mut waveforms = BROWSER_PLATFORM_STORE.waveforms.lock().unwrap_throw();
+    let hierarchy = loaded_waveform_mut(&mut waveforms, waveform_id)?.hierarchy();
     // @TODO Wrap `hierarchy` in `Waveform` with `Rc/Arc` or add the method `take` / `clone` or refactor?
-    serde_json::from_value(serde_json::to_value(hierarchy).unwrap_throw()).unwrap_throw()
+    Ok(serde_json::from_value(serde_json::to_value(hierarchy).unwrap_throw()).unwrap_throw())
 }
 
 pub(super) async fn load_signal_and_get_timeline(
+    waveform_id: WaveformId,
     signal_ref: wellen::SignalRef,
+    var_name: super::FullVarName,
     timeline_zoom: f64,
     timeline_viewport_width: u32,
     timeline_viewport_x: i32,
     block_height: u32,
     var_format: shared::VarFormat,
-) -> shared::Timeline {
-    let mut waveform_lock = BROWSER_PLATFORM_STORE.waveform.lock().unwrap();
-    let waveform = waveform_lock.as_mut().unwrap();
-    waveform.load_signals_multi_threaded(&[signal_ref]);
-    let signal = waveform.get_signal(signal_ref).unwrap();
+    render_mode: shared::RenderMode,
+    decoder_name: Option<super::DecoderName>,
+) -> Result<shared::Timeline, CommandError> {
+    let mut waveforms = BROWSER_PLATFORM_STORE.waveforms.lock().unwrap_throw();
+    let time_axis = time_axis(&waveforms);
+    let waveform = loaded_waveform_mut(&mut waveforms, waveform_id)?;
+    let timeline_zoom = time_axis.map_or(timeline_zoom, |time_axis| {
+        time_axis.waveform_zoom(timeline_zoom, TimeAxis::new(waveform))
+    });
+    let signal_ref = waveform_commands::load_signal(waveform, signal_ref.index())?;
+    let signal = waveform
+        .get_signal(signal_ref)
+        .ok_or(CommandError::SignalNotFound {
+            signal_ref_index: signal_ref.index(),
+        })?;
     let time_table = waveform.time_table();
+    let serialized_var_format = serde_wasm_bindgen::to_value(&var_format).unwrap_throw();
     let timeline = shared::signal_to_timeline(
         signal,
         time_table,
@@ -111,30 +229,120 @@ pub(super) async fn load_signal_and_get_timeline(
         timeline_viewport_x,
         block_height,
         var_format,
-        |value| Box::pin(async { value }),
+        render_mode,
+        |input: shared::DecoderInput| {
+            let decoded_value = decoder_name.as_ref().and_then(|decoder_name| {
+                let decoded_value = component_runtime::decode_signal_value(
+                    decoder_name,
+                    &var_name,
+                    &input.value.to_bit_string().unwrap_or_default(),
+                    &input.formatted_value,
+                    &serialized_var_format,
+                    input.time,
+                );
+                serde_wasm_bindgen::from_value::<Option<shared::DecodedValue>>(decoded_value)
+                    .unwrap_throw()
+            });
+            let decoded_value = decoded_value.unwrap_or_else(|| input.formatted_value.into());
+            Box::pin(async { decoded_value })
+        },
     )
     .await;
-    timeline
+    Ok(timeline)
+}
+
+pub(super) async fn get_timeline_ruler(
+    timeline_zoom: f64,
+    timeline_viewport_width: u32,
+    timeline_viewport_x: i32,
+) -> Result<Option<shared::TimelineRuler>, CommandError> {
+    let waveforms = BROWSER_PLATFORM_STORE.waveforms.lock().unwrap_throw();
+    let ruler = time_axis(&waveforms).map(|time_axis| {
+        time_axis.timeline_ruler(timeline_zoom, timeline_viewport_width, timeline_viewport_x)
+    });
+    Ok(ruler)
 }
 
-pub(super) async fn unload_signal(signal_ref: wellen::SignalRef) {
-    let mut waveform_lock = BROWSER_PLATFORM_STORE.waveform.lock().unwrap_throw();
-    let waveform = waveform_lock.as_mut().unwrap_throw();
-    waveform.unload_signals(&[signal_ref]);
+pub(super) async fn get_value_at_time(
+    waveform_id: WaveformId,
+    signal_ref: wellen::SignalRef,
+    time: wellen::Time,
+    var_format: shared::VarFormat,
+) -> Result<Option<String>, CommandError> {
+    let mut waveforms = BROWSER_PLATFORM_STORE.waveforms.lock().unwrap_throw();
+    let time = waveform_time(&waveforms, waveform_id, time);
+    let waveform = loaded_waveform_mut(&mut waveforms, waveform_id)?;
+    waveform_commands::value_at_time(Some(waveform), signal_ref.index(), time, var_format)
 }
 
+pub(super) async fn get_values_at_time(
+    waveform_id: WaveformId,
+    signal_refs_and_var_formats: Vec<(wellen::SignalRef, shared::VarFormat)>,
+    time: wellen::Time,
+) -> Result<Vec<Option<String>>, CommandError> {
+    let mut waveforms = BROWSER_PLATFORM_STORE.waveforms.lock().unwrap_throw();
+    let time = waveform_time(&waveforms, waveform_id, time);
+    let waveform = loaded_waveform_mut(&mut waveforms, waveform_id)?;
+    let signal_ref_indices_and_var_formats = signal_refs_and_var_formats
+        .into_iter()
+        .map(|(signal_ref, var_format)| (signal_ref.index(), var_format))
+        .collect();
+    waveform_commands::values_at_time(Some(waveform), signal_ref_indices_and_var_formats, time)
+}
+
+pub(super) async fn unload_signal(
+    waveform_id: WaveformId,
+    signal_ref: wellen::SignalRef,
+) -> Result<(), CommandError> {
+    let mut waveforms = BROWSER_PLATFORM_STORE.waveforms.lock().unwrap_throw();
+    let waveform = loaded_waveform_mut(&mut waveforms, waveform_id)?;
+    waveform_commands::unload_signal(Some(waveform), signal_ref.index())
+}
+
+pub(super) async fn diff_waveforms(
+    waveform_id_a: WaveformId,
+    waveform_id_b: WaveformId,
+) -> Result<Vec<shared::VarDiff>, CommandError> {
+    if waveform_id_a == waveform_id_b {
+        return Ok(Vec::new());
+    }
+    let mut waveforms = BROWSER_PLATFORM_STORE.waveforms.lock().unwrap_throw();
+    let time_axis = time_axis(&waveforms).ok_or(CommandError::NoWaveformLoaded)?;
+    // Taken out of the map to borrow both waveforms mutably
+    let mut waveform_b =
+        waveforms
+            .remove(&waveform_id_b)
+            .ok_or(CommandError::WaveformNotFound {
+                waveform_id: waveform_id_b,
+            })?;
+    let var_diffs = loaded_waveform_mut(&mut waveforms, waveform_id_a)
+        .map(|waveform_a| shared::diff_waveforms(waveform_a, &mut waveform_b, time_axis));
+    waveforms.insert(waveform_id_b, waveform_b);
+    var_diffs
+}
+
+// Decoders are fetched from `decoder_paths` (relative to the app URL)
+// and run by `frontend/typescript/component_runtime`
 pub(super) async fn add_decoders(
-    _decoder_paths: Vec<super::DecoderPath>,
+    decoder_paths: Vec<super::DecoderPath>,
 ) -> super::AddedDecodersCount {
-    // @TODO error message for user
-    eprintln!("Adding decoders is not supported in the browser.");
-    0
+    let count = component_runtime::add_decoders(decoder_paths)
+        .await
+        .unwrap_throw();
+    serde_wasm_bindgen::from_value(count).unwrap_throw()
 }
 
 pub(super) async fn remove_all_decoders() -> super::RemovedDecodersCount {
-    // @TODO error message for user
-    eprintln!("Removing decoders is not supported in the browser.");
-    0
+    component_runtime::remove_all_decoders()
+}
+
+pub(super) async fn decoder_names() -> Vec<super::DecoderName> {
+    serde_wasm_bindgen::from_value(component_runtime::decoder_names()).unwrap_throw()
+}
+
+pub(super) async fn decoders_cache_stats() -> shared::DecodersCacheStats {
+    // Decoders run in the same thread in the browser so decoded values are not cached
+    shared::DecodersCacheStats::default()
 }
 
 pub(super) async fn add_diagram_connectors(
@@ -158,6 +366,18 @@ pub async fn listen_diagram_connectors_messages(
     eprintln!("Removing listen for diagram connectors messages is not supported in the browser.");
 }
 
+pub(super) async fn listen_disabled_components(
+    _on_disabled: impl FnMut(shared::DisabledComponent) + 'static,
+) {
+    // Components are not supported in the browser
+}
+
+pub(super) async fn listen_reloaded_components(
+    _on_reloaded: impl FnMut(shared::ComponentInfo) + 'static,
+) {
+    // Components are not supported in the browser
+}
+
 pub async fn notify_diagram_connector_text_change(
     diagram_connector: DiagramConnectorName,
     component_id: ComponentId,
@@ -167,9 +387,83 @@ pub async fn notify_diagram_connector_text_change(
     eprintln!("Diagram connectors notifications are not supported in the browser.");
 }
 
-pub async fn open_konata_file() {
+pub(super) async fn add_protocol_decoders(
+    _protocol_decoder_paths: Vec<super::ProtocolDecoderPath>,
+) -> super::AddedProtocolDecodersCount {
+    // @TODO error message for user
+    eprintln!("Adding protocol decoders is not supported in the browser.");
+    0
+}
+
+pub(super) async fn remove_all_protocol_decoders() -> super::RemovedProtocolDecodersCount {
+    // @TODO error message for user
+    eprintln!("Removing protocol decoders is not supported in the browser.");
+    0
+}
+
+pub(super) async fn decode_protocol(
+    _waveform_id: WaveformId,
+    protocol_decoder_name: super::ProtocolDecoderName,
+    _input_var_names: Vec<super::FullVarName>,
+) -> Result<Vec<shared::AnnotationRow>, CommandError> {
+    Err(CommandError::ProtocolDecodingFailed {
+        protocol_decoder_name,
+        reason: "Protocol decoding is not supported in the browser".to_owned(),
+    })
+}
+
+pub(super) async fn components() -> Vec<shared::ComponentInfo> {
+    // Components are not supported in the browser
+    Vec::new()
+}
+
+pub(super) async fn set_component_enabled(
+    _kind: shared::ComponentKind,
+    _index: super::ComponentIndex,
+    _is_enabled: bool,
+) -> bool {
     // @TODO error message for user
-    eprintln!("Opening Konata files is not supported in the browser.");
+    eprintln!("Enabling or disabling components is not supported in the browser.");
+    false
+}
+
+pub(super) async fn remove_component(
+    _kind: shared::ComponentKind,
+    _index: super::ComponentIndex,
+) -> bool {
+    // @TODO error message for user
+    eprintln!("Removing components is not supported in the browser.");
+    false
+}
+
+pub(super) async fn move_component(
+    _kind: shared::ComponentKind,
+    _old_index: super::ComponentIndex,
+    _new_index: super::ComponentIndex,
+) -> bool {
+    // @TODO error message for user
+    eprintln!("Moving components is not supported in the browser.");
+    false
+}
+
+// Only decoders log in the browser, their logs are collected by `frontend/typescript/component_runtime`
+pub(super) async fn logs() -> Vec<shared::LogEntry> {
+    serde_wasm_bindgen::from_value(component_runtime::logs()).unwrap_throw()
+}
+
+pub(super) async fn listen_log_entries(
+    mut on_log_entries: impl FnMut(Vec<shared::LogEntry>) + 'static,
+) {
+    let on_log_entries = move |log_entries: JsValue| {
+        on_log_entries(serde_wasm_bindgen::from_value(log_entries).unwrap_throw())
+    };
+    component_runtime::listen_log_entries(Closure::new(on_log_entries).into_js_value())
+}
+
+pub async fn open_konata_file() -> Result<(), CommandError> {
+    Err(CommandError::Unexpected {
+        reason: "Opening Konata files is not supported in the browser".to_owned(),
+    })
 }
 
 pub async fn read_file(path: &str) -> Result<String, String> {
@@ -180,13 +474,44 @@ pub async fn read_file(path: &str) -> Result<String, String> {
     ))
 }
 
-pub async fn select_folder_to_open() -> Option<super::FolderPath> {
-    // @TODO error message for user
-    eprintln!("Opening folders is not supported in the browser.");
-    None
+pub async fn select_folder_to_open() -> Result<Option<super::FolderPath>, CommandError> {
+    Err(CommandError::Unexpected {
+        reason: "Opening folders is not supported in the browser".to_owned(),
+    })
 }
 
-pub async fn file_tree(_path: PathBuf) -> shared::FileTreeItem {
-    // @TODO error message for user
-    panic!("Opening folders is not supported in the browser.");
+pub async fn file_tree(_path: PathBuf) -> Result<shared::FileTreeItem, CommandError> {
+    Err(CommandError::Unexpected {
+        reason: "Opening folders is not supported in the browser".to_owned(),
+    })
+}
+
+mod component_runtime {
+    use zoon::*;
+
+    // Note: Add all corresponding methods to `frontend/typescript/component_runtime/component_runtime.ts`
+    #[wasm_bindgen(module = "/typescript/bundles/component_runtime.js")]
+    extern "C" {
+        #[wasm_bindgen(catch)]
+        pub async fn add_decoders(
+            decoder_paths: Vec<super::super::DecoderPath>,
+        ) -> Result<JsValue, JsValue>;
+
+        pub fn remove_all_decoders() -> super::super::RemovedDecodersCount;
+
+        pub fn decoder_names() -> JsValue;
+
+        pub fn decode_signal_value(
+            decoder_name: &str,
+            var_name: &str,
+            raw_bits: &str,
+            formatted_value: &str,
+            var_format: &JsValue,
+            time: wellen::Time,
+        ) -> JsValue;
+
+        pub fn logs() -> JsValue;
+
+        pub fn listen_log_entries(on_log_entries: JsValue);
+    }
 }
@@ -9,54 +9,193 @@ pub(super) async fn show_window() {
 
 pub(super) async fn pick_and_load_waveform(
     _file: Option<gloo_file::File>,
-) -> Option<super::Filename> {
-    tauri_glue::pick_and_load_waveform()
+) -> Result<Option<shared::WaveformInfo>, shared::CommandError> {
+    let waveform_info = tauri_glue::pick_and_load_waveform()
         .await
+        .map_err(command_error)?;
+    Ok(serde_wasm_bindgen::from_value(waveform_info).unwrap_throw())
+}
+
+pub(super) async fn load_waveform_from_path(
+    path: super::FilePath,
+) -> Result<shared::WaveformInfo, shared::CommandError> {
+    let waveform_info = tauri_glue::load_waveform_from_path(path)
+        .await
+        .map_err(command_error)?;
+    Ok(serde_wasm_bindgen::from_value(waveform_info).unwrap_throw())
+}
+
+pub(super) async fn waveform_paths_from_args() -> Vec<super::FilePath> {
+    serde_wasm_bindgen::from_value(tauri_glue::waveform_paths_from_args().await.unwrap_throw())
         .unwrap_throw()
-        .as_string()
+}
+
+pub(super) async fn recent_files() -> Vec<super::FilePath> {
+    serde_wasm_bindgen::from_value(tauri_glue::recent_files().await.unwrap_throw()).unwrap_throw()
+}
+
+pub(super) async fn listen_dropped_waveform_files(
+    mut on_drop: impl FnMut(super::FilePath) + 'static,
+) {
+    let on_drop = move |path: JsValue| on_drop(path.as_string().unwrap_throw());
+    tauri_glue::listen_dropped_waveform_files(Closure::new(on_drop).into_js_value()).await
+}
+
+pub(super) async fn reload_waveform(
+    waveform_id: shared::WaveformId,
+) -> Result<super::Filename, shared::CommandError> {
+    let filename = tauri_glue::reload_waveform(waveform_id)
+        .await
+        .map_err(command_error)?;
+    Ok(filename.as_string().unwrap_throw())
+}
+
+pub(super) async fn listen_waveform_file_changes(
+    mut on_change: impl FnMut(shared::WaveformId) + 'static,
+) {
+    let on_change = move |waveform_id: JsValue| {
+        on_change(serde_wasm_bindgen::from_value(waveform_id).unwrap_throw())
+    };
+    tauri_glue::listen_waveform_file_changes(Closure::new(on_change).into_js_value()).await
+}
+
+pub(super) async fn unload_waveform(
+    waveform_id: shared::WaveformId,
+) -> Result<(), shared::CommandError> {
+    tauri_glue::unload_waveform(waveform_id)
+        .await
+        .map_err(command_error)
+}
+
+pub(super) async fn cancel_waveform_loading(
+    waveform_id: shared::WaveformId,
+) -> Result<(), shared::CommandError> {
+    tauri_glue::cancel_waveform_loading(waveform_id)
+        .await
+        .map_err(command_error)
+}
+
+pub(super) async fn listen_waveform_loading_status(
+    mut on_status: impl FnMut(shared::WaveformLoadingStatus) + 'static,
+) {
+    let on_status =
+        move |status: JsValue| on_status(serde_wasm_bindgen::from_value(status).unwrap_throw());
+    tauri_glue::listen_waveform_loading_status(Closure::new(on_status).into_js_value()).await
 }
 
 pub(super) async fn load_file_with_selected_vars(
     _file: Option<gloo_file::File>,
-) -> Option<super::JavascriptCode> {
-    tauri_glue::load_file_with_selected_vars()
+) -> Result<Option<super::JavascriptCode>, shared::CommandError> {
+    let javascript_code = tauri_glue::load_file_with_selected_vars()
         .await
-        .unwrap_throw()
-        .as_string()
+        .map_err(command_error)?;
+    Ok(javascript_code.as_string())
 }
 
-pub(super) async fn get_hierarchy() -> wellen::Hierarchy {
-    serde_wasm_bindgen::from_value(tauri_glue::get_hierarchy().await.unwrap_throw()).unwrap_throw()
+pub(super) async fn get_hierarchy(
+    waveform_id: shared::WaveformId,
+) -> Result<wellen::Hierarchy, shared::CommandError> {
+    let hierarchy = tauri_glue::get_hierarchy(waveform_id)
+        .await
+        .map_err(command_error)?;
+    Ok(serde_wasm_bindgen::from_value(hierarchy).unwrap_throw())
 }
 
 pub(super) async fn load_signal_and_get_timeline(
+    waveform_id: shared::WaveformId,
     signal_ref: wellen::SignalRef,
+    var_name: super::FullVarName,
     timeline_zoom: f64,
     timeline_viewport_width: u32,
     timeline_viewport_x: i32,
     block_height: u32,
     var_format: shared::VarFormat,
-) -> shared::Timeline {
+    render_mode: shared::RenderMode,
+    decoder_name: Option<super::DecoderName>,
+) -> Result<shared::Timeline, shared::CommandError> {
     let var_format = serde_wasm_bindgen::to_value(&var_format).unwrap_throw();
-    serde_wasm_bindgen::from_value(
-        tauri_glue::load_signal_and_get_timeline(
-            signal_ref.index(),
-            timeline_zoom,
-            timeline_viewport_width,
-            timeline_viewport_x,
-            block_height,
-            var_format,
-        )
-        .await
-        .unwrap_throw(),
+    let render_mode = serde_wasm_bindgen::to_value(&render_mode).unwrap_throw();
+    let timeline = tauri_glue::load_signal_and_get_timeline(
+        waveform_id,
+        signal_ref.index(),
+        var_name,
+        timeline_zoom,
+        timeline_viewport_width,
+        timeline_viewport_x,
+        block_height,
+        var_format,
+        render_mode,
+        decoder_name,
     )
-    .unwrap_throw()
+    .await
+    .map_err(command_error)?;
+    Ok(serde_wasm_bindgen::from_value(timeline).unwrap_throw())
+}
+
+pub(super) async fn get_timeline_ruler(
+    timeline_zoom: f64,
+    timeline_viewport_width: u32,
+    timeline_viewport_x: i32,
+) -> Result<Option<shared::TimelineRuler>, shared::CommandError> {
+    let ruler =
+        tauri_glue::get_timeline_ruler(timeline_zoom, timeline_viewport_width, timeline_viewport_x)
+            .await
+            .map_err(command_error)?;
+    Ok(serde_wasm_bindgen::from_value(ruler).unwrap_throw())
 }
 
-pub(super) async fn unload_signal(signal_ref: wellen::SignalRef) {
-    tauri_glue::unload_signal(signal_ref.index())
+pub(super) async fn get_value_at_time(
+    waveform_id: shared::WaveformId,
+    signal_ref: wellen::SignalRef,
+    time: wellen::Time,
+    var_format: shared::VarFormat,
+) -> Result<Option<String>, shared::CommandError> {
+    let var_format = serde_wasm_bindgen::to_value(&var_format).unwrap_throw();
+    let value =
+        tauri_glue::get_value_at_time(waveform_id, signal_ref.index(), time as f64, var_format)
+            .await
+            .map_err(command_error)?;
+    Ok(value.as_string())
+}
+
+pub(super) async fn get_values_at_time(
+    waveform_id: shared::WaveformId,
+    signal_refs_and_var_formats: Vec<(wellen::SignalRef, shared::VarFormat)>,
+    time: wellen::Time,
+) -> Result<Vec<Option<String>>, shared::CommandError> {
+    let signal_ref_indices_and_var_formats = signal_refs_and_var_formats
+        .into_iter()
+        .map(|(signal_ref, var_format)| (signal_ref.index(), var_format))
+        .collect::<Vec<_>>();
+    let signal_ref_indices_and_var_formats =
+        serde_wasm_bindgen::to_value(&signal_ref_indices_and_var_formats).unwrap_throw();
+    let values = tauri_glue::get_values_at_time(
+        waveform_id,
+        signal_ref_indices_and_var_formats,
+        time as f64,
+    )
+    .await
+    .map_err(command_error)?;
+    Ok(serde_wasm_bindgen::from_value(values).unwrap_throw())
+}
+
+pub(super) async fn unload_signal(
+    waveform_id: shared::WaveformId,
+    signal_ref: wellen::SignalRef,
+) -> Result<(), shared::CommandError> {
+    tauri_glue::unload_signal(waveform_id, signal_ref.index())
         .await
-        .unwrap_throw()
+        .map_err(command_error)
+}
+
+pub(super) async fn diff_waveforms(
+    waveform_id_a: shared::WaveformId,
+    waveform_id_b: shared::WaveformId,
+) -> Result<Vec<shared::VarDiff>, shared::CommandError> {
+    let var_diffs = tauri_glue::diff_waveforms(waveform_id_a, waveform_id_b)
+        .await
+        .map_err(command_error)?;
+    Ok(serde_wasm_bindgen::from_value(var_diffs).unwrap_throw())
 }
 
 pub(super) async fn send_char(c: String) {
@@ -75,6 +214,15 @@ pub(super) async fn remove_all_decoders() -> super::RemovedDecodersCount {
         .unwrap_throw()
 }
 
+pub(super) async fn decoder_names() -> Vec<super::DecoderName> {
+    serde_wasm_bindgen::from_value(tauri_glue::decoder_names().await.unwrap_throw()).unwrap_throw()
+}
+
+pub(super) async fn decoders_cache_stats() -> shared::DecodersCacheStats {
+    serde_wasm_bindgen::from_value(tauri_glue::decoders_cache_stats().await.unwrap_throw())
+        .unwrap_throw()
+}
+
 pub(super) async fn add_diagram_connectors(
     diagram_connector_paths: Vec<super::DecoderPath>,
 ) -> super::AddedDiagramConnectorsCount {
@@ -103,6 +251,24 @@ pub(super) async fn listen_diagram_connectors_messages(
     tauri_glue::listen_diagram_connectors_messages(Closure::new(on_message).into_js_value()).await
 }
 
+pub(super) async fn listen_disabled_components(
+    mut on_disabled: impl FnMut(shared::DisabledComponent) + 'static,
+) {
+    let on_disabled = move |disabled_component: JsValue| {
+        on_disabled(serde_wasm_bindgen::from_value(disabled_component).unwrap_throw())
+    };
+    tauri_glue::listen_disabled_components(Closure::new(on_disabled).into_js_value()).await
+}
+
+pub(super) async fn listen_reloaded_components(
+    mut on_reloaded: impl FnMut(shared::ComponentInfo) + 'static,
+) {
+    let on_reloaded = move |reloaded_component: JsValue| {
+        on_reloaded(serde_wasm_bindgen::from_value(reloaded_component).unwrap_throw())
+    };
+    tauri_glue::listen_reloaded_components(Closure::new(on_reloaded).into_js_value()).await
+}
+
 pub(super) async fn listen_term_update(mut on_message: impl FnMut(TerminalDownMsg) + 'static) {
     let on_message =
         move |message: JsValue| on_message(serde_wasm_bindgen::from_value(message).unwrap_throw());
@@ -119,8 +285,98 @@ pub(super) async fn notify_diagram_connector_text_change(
         .unwrap_throw();
 }
 
-pub(super) async fn open_konata_file() {
-    tauri_glue::open_konata_file().await;
+pub(super) async fn add_protocol_decoders(
+    protocol_decoder_paths: Vec<super::ProtocolDecoderPath>,
+) -> super::AddedProtocolDecodersCount {
+    serde_wasm_bindgen::from_value(
+        tauri_glue::add_protocol_decoders(protocol_decoder_paths)
+            .await
+            .unwrap_throw(),
+    )
+    .unwrap_throw()
+}
+
+pub(super) async fn remove_all_protocol_decoders() -> super::RemovedProtocolDecodersCount {
+    serde_wasm_bindgen::from_value(
+        tauri_glue::remove_all_protocol_decoders()
+            .await
+            .unwrap_throw(),
+    )
+    .unwrap_throw()
+}
+
+pub(super) async fn decode_protocol(
+    waveform_id: shared::WaveformId,
+    protocol_decoder_name: super::ProtocolDecoderName,
+    input_var_names: Vec<super::FullVarName>,
+) -> Result<Vec<shared::AnnotationRow>, shared::CommandError> {
+    let annotation_rows =
+        tauri_glue::decode_protocol(waveform_id, protocol_decoder_name, input_var_names)
+            .await
+            .map_err(command_error)?;
+    Ok(serde_wasm_bindgen::from_value(annotation_rows).unwrap_throw())
+}
+
+pub(super) async fn components() -> Vec<shared::ComponentInfo> {
+    serde_wasm_bindgen::from_value(tauri_glue::components().await.unwrap_throw()).unwrap_throw()
+}
+
+pub(super) async fn set_component_enabled(
+    kind: shared::ComponentKind,
+    index: super::ComponentIndex,
+    is_enabled: bool,
+) -> bool {
+    let kind = serde_wasm_bindgen::to_value(&kind).unwrap_throw();
+    serde_wasm_bindgen::from_value(
+        tauri_glue::set_component_enabled(kind, index, is_enabled)
+            .await
+            .unwrap_throw(),
+    )
+    .unwrap_throw()
+}
+
+pub(super) async fn remove_component(
+    kind: shared::ComponentKind,
+    index: super::ComponentIndex,
+) -> bool {
+    let kind = serde_wasm_bindgen::to_value(&kind).unwrap_throw();
+    serde_wasm_bindgen::from_value(
+        tauri_glue::remove_component(kind, index)
+            .await
+            .unwrap_throw(),
+    )
+    .unwrap_throw()
+}
+
+pub(super) async fn move_component(
+    kind: shared::ComponentKind,
+    old_index: super::ComponentIndex,
+    new_index: super::ComponentIndex,
+) -> bool {
+    let kind = serde_wasm_bindgen::to_value(&kind).unwrap_throw();
+    serde_wasm_bindgen::from_value(
+        tauri_glue::move_component(kind, old_index, new_index)
+            .await
+            .unwrap_throw(),
+    )
+    .unwrap_throw()
+}
+
+pub(super) async fn logs() -> Vec<shared::LogEntry> {
+    serde_wasm_bindgen::from_value(tauri_glue::logs().await.unwrap_throw()).unwrap_throw()
+}
+
+pub(super) async fn listen_log_entries(
+    mut on_log_entries: impl FnMut(Vec<shared::LogEntry>) + 'static,
+) {
+    let on_log_entries = move |log_entries: JsValue| {
+        on_log_entries(serde_wasm_bindgen::from_value(log_entries).unwrap_throw())
+    };
+    tauri_glue::listen_log_entries(Closure::new(on_log_entries).into_js_value()).await
+}
+
+pub(super) async fn open_konata_file() -> Result<(), shared::CommandError> {
+    tauri_glue::open_konata_file().await.map_err(command_error)
 }
 
 pub(super) async fn read_file(path: &str) -> Result<String, String> {
@@ -136,16 +392,28 @@ pub(super) async fn read_file(path: &str) -> Result<String, String> {
         })
 }
 
-pub(super) async fn select_folder_to_open() -> Option<super::FolderPath> {
+pub(super) async fn select_folder_to_open(
+) -> Result<Option<super::FolderPath>, shared::CommandError> {
     tauri_glue::select_folder_to_open()
         .await
-        .unwrap_throw()
-        .as_string()
+        .map(|folder_path| folder_path.as_string())
+        .map_err(command_error)
 }
 
-pub async fn file_tree(path: PathBuf) -> shared::FileTreeItem {
+pub async fn file_tree(path: PathBuf) -> Result<shared::FileTreeItem, shared::CommandError> {
     let path = path.to_str().unwrap_throw();
-    serde_wasm_bindgen::from_value(tauri_glue::file_tree(path).await.unwrap_throw()).unwrap_throw()
+    let root = tauri_glue::file_tree(path).await.map_err(command_error)?;
+    Ok(serde_wasm_bindgen::from_value(root).unwrap_throw())
+}
+
+/// Commands fail with `shared::CommandError`,
+/// other errors (e.g. a missing command) are converted to `CommandError::Unexpected`
+fn command_error(error: JsValue) -> shared::CommandError {
+    serde_wasm_bindgen::from_value(error.clone()).unwrap_or_else(|_| {
+        shared::CommandError::Unexpected {
+            reason: format!("{error:?}"),
+        }
+    })
 }
 
 mod tauri_glue {
@@ -160,24 +428,87 @@ mod tauri_glue {
         #[wasm_bindgen(catch)]
         pub async fn pick_and_load_waveform() -> Result<JsValue, JsValue>;
 
+        #[wasm_bindgen(catch)]
+        pub async fn load_waveform_from_path(
+            path: super::super::FilePath,
+        ) -> Result<JsValue, JsValue>;
+
+        #[wasm_bindgen(catch)]
+        pub async fn waveform_paths_from_args() -> Result<JsValue, JsValue>;
+
+        #[wasm_bindgen(catch)]
+        pub async fn recent_files() -> Result<JsValue, JsValue>;
+
+        pub async fn listen_dropped_waveform_files(on_drop: JsValue);
+
+        #[wasm_bindgen(catch)]
+        pub async fn reload_waveform(waveform_id: shared::WaveformId) -> Result<JsValue, JsValue>;
+
+        pub async fn listen_waveform_file_changes(on_change: JsValue);
+
+        #[wasm_bindgen(catch)]
+        pub async fn unload_waveform(waveform_id: shared::WaveformId) -> Result<(), JsValue>;
+
+        #[wasm_bindgen(catch)]
+        pub async fn cancel_waveform_loading(
+            waveform_id: shared::WaveformId,
+        ) -> Result<(), JsValue>;
+
+        pub async fn listen_waveform_loading_status(on_status: JsValue);
+
         #[wasm_bindgen(catch)]
         pub async fn load_file_with_selected_vars() -> Result<JsValue, JsValue>;
 
         #[wasm_bindgen(catch)]
-        pub async fn get_hierarchy() -> Result<JsValue, JsValue>;
+        pub async fn get_hierarchy(waveform_id: shared::WaveformId) -> Result<JsValue, JsValue>;
 
         #[wasm_bindgen(catch)]
         pub async fn load_signal_and_get_timeline(
+            waveform_id: shared::WaveformId,
             signal_ref_index: usize,
+            var_name: String,
             timeline_zoom: f64,
             timeline_viewport_width: u32,
             timeline_viewport_x: i32,
             block_height: u32,
             var_format: JsValue,
+            render_mode: JsValue,
+            decoder_name: Option<String>,
         ) -> Result<JsValue, JsValue>;
 
         #[wasm_bindgen(catch)]
-        pub async fn unload_signal(signal_ref_index: usize) -> Result<(), JsValue>;
+        pub async fn get_timeline_ruler(
+            timeline_zoom: f64,
+            timeline_viewport_width: u32,
+            timeline_viewport_x: i32,
+        ) -> Result<JsValue, JsValue>;
+
+        #[wasm_bindgen(catch)]
+        pub async fn get_value_at_time(
+            waveform_id: shared::WaveformId,
+            signal_ref_index: usize,
+            time: f64,
+            var_format: JsValue,
+        ) -> Result<JsValue, JsValue>;
+
+        #[wasm_bindgen(catch)]
+        pub async fn get_values_at_time(
+            waveform_id: shared::WaveformId,
+            signal_ref_indices_and_var_formats: JsValue,
+            time: f64,
+        ) -> Result<JsValue, JsValue>;
+
+        #[wasm_bindgen(catch)]
+        pub async fn unload_signal(
+            waveform_id: shared::WaveformId,
+            signal_ref_index: usize,
+        ) -> Result<(), JsValue>;
+
+        #[wasm_bindgen(catch)]
+        pub async fn diff_waveforms(
+            waveform_id_a: shared::WaveformId,
+            waveform_id_b: shared::WaveformId,
+        ) -> Result<JsValue, JsValue>;
 
         #[wasm_bindgen(catch)]
         pub async fn send_char(c: String) -> Result<(), JsValue>;
@@ -190,6 +521,12 @@ mod tauri_glue {
         #[wasm_bindgen(catch)]
         pub async fn remove_all_decoders() -> Result<JsValue, JsValue>;
 
+        #[wasm_bindgen(catch)]
+        pub async fn decoder_names() -> Result<JsValue, JsValue>;
+
+        #[wasm_bindgen(catch)]
+        pub async fn decoders_cache_stats() -> Result<JsValue, JsValue>;
+
         #[wasm_bindgen(catch)]
         pub async fn add_diagram_connectors(
             diagram_connector_paths: Vec<super::super::DiagramConnectorPath>,
@@ -200,6 +537,10 @@ mod tauri_glue {
 
         pub async fn listen_diagram_connectors_messages(on_event: JsValue);
 
+        pub async fn listen_disabled_components(on_event: JsValue);
+
+        pub async fn listen_reloaded_components(on_event: JsValue);
+
         pub async fn listen_term_update(on_event: JsValue);
 
         #[wasm_bindgen(catch)]
@@ -209,7 +550,51 @@ mod tauri_glue {
             text: String,
         ) -> Result<(), JsValue>;
 
-        pub async fn open_konata_file();
+        #[wasm_bindgen(catch)]
+        pub async fn add_protocol_decoders(
+            protocol_decoder_paths: Vec<super::super::ProtocolDecoderPath>,
+        ) -> Result<JsValue, JsValue>;
+
+        #[wasm_bindgen(catch)]
+        pub async fn remove_all_protocol_decoders() -> Result<JsValue, JsValue>;
+
+        #[wasm_bindgen(catch)]
+        pub async fn decode_protocol(
+            waveform_id: shared::WaveformId,
+            protocol_decoder_name: super::super::ProtocolDecoderName,
+            input_var_names: Vec<super::super::FullVarName>,
+        ) -> Result<JsValue, JsValue>;
+
+        #[wasm_bindgen(catch)]
+        pub async fn components() -> Result<JsValue, JsValue>;
+
+        #[wasm_bindgen(catch)]
+        pub async fn set_component_enabled(
+            kind: JsValue,
+            index: super::super::ComponentIndex,
+            is_enabled: bool,
+        ) -> Result<JsValue, JsValue>;
+
+        #[wasm_bindgen(catch)]
+        pub async fn remove_component(
+            kind: JsValue,
+            index: super::super::ComponentIndex,
+        ) -> Result<JsValue, JsValue>;
+
+        #[wasm_bindgen(catch)]
+        pub async fn move_component(
+            kind: JsValue,
+            old_index: super::super::ComponentIndex,
+            new_index: super::super::ComponentIndex,
+        ) -> Result<JsValue, JsValue>;
+
+        #[wasm_bindgen(catch)]
+        pub async fn logs() -> Result<JsValue, JsValue>;
+
+        pub async fn listen_log_entries(on_event: JsValue);
+
+        #[wasm_bindgen(catch)]
+        pub async fn open_konata_file() -> Result<(), JsValue>;
 
         #[wasm_bindgen(catch)]
         pub async fn read_file(path: &str) -> Result<JsValue, JsValue>;
@@ -1,8 +1,17 @@
-use crate::{platform, STORE};
+use crate::{
+    components_panel::refresh_components, diff_panel, find_waveform, header_panel, platform,
+    SelectedVar, STORE,
+};
+use std::collections::BTreeMap;
 use wellen::GetItem;
 use zoon::*;
 
+type Filename = String;
+type FilePath = String;
 type FullVarName = String;
+type DecoderName = String;
+type MarkerName = String;
+type Time = f64;
 
 type AddedDecodersCount = usize;
 type RemovedDecodersCount = usize;
@@ -12,6 +21,13 @@ type AddedDiagramConnectorsCount = usize;
 type RemovedDiagramConnectorsCount = usize;
 type DiagramConnectorPath = String;
 
+type AddedProtocolDecodersCount = usize;
+type RemovedProtocolDecodersCount = usize;
+type ProtocolDecoderPath = String;
+type ProtocolDecoderName = String;
+
+type ComponentIndex = usize;
+
 #[wasm_bindgen(module = "/typescript/bundles/strict_eval.js")]
 extern "C" {
     #[wasm_bindgen(catch)]
@@ -30,58 +46,246 @@ impl FW {
 
     /// JS: `FW.clear_selected_vars()` -> `4`
     pub fn clear_selected_vars() -> usize {
-        let mut vars = STORE.selected_var_refs.lock_mut();
+        let mut vars = STORE.selected_vars.lock_mut();
         let var_count = vars.len();
         vars.clear();
         var_count
     }
 
+    /// Vars are looked up in the first loaded waveform containing them.
     /// JS: `FW.select_vars(["simple_tb.s.A", "simple_tb.s.B"])` -> `2`
     pub fn select_vars(full_var_names: Vec<FullVarName>) -> usize {
-        if let Some(hierarchy) = STORE.hierarchy.get_cloned() {
-            let mut new_var_refs = Vec::new();
-            for full_var_name in full_var_names {
-                let path_with_name = full_var_name.split_terminator('.').collect::<Vec<_>>();
-                if let Some((name, path)) = path_with_name.split_last() {
-                    if let Some(var_ref) = hierarchy.lookup_var(path, name) {
-                        new_var_refs.push(var_ref);
-                    }
-                }
+        let mut new_selected_vars = Vec::new();
+        for full_var_name in full_var_names {
+            if let Some(selected_var) = lookup_selected_var(&full_var_name) {
+                new_selected_vars.push(selected_var);
             }
-            let var_ref_count = new_var_refs.len();
-            STORE.selected_var_refs.lock_mut().replace(new_var_refs);
-            return var_ref_count;
         }
-        0
+        let selected_var_count = new_selected_vars.len();
+        STORE.selected_vars.lock_mut().replace(new_selected_vars);
+        selected_var_count
+    }
+
+    /// The waveform is added to the already loaded ones.
+    /// JS: `FW.load_waveform("../test_files/simple.vcd")` -> `simple.vcd`
+    pub async fn load_waveform(path: FilePath) -> Option<Filename> {
+        header_panel::load_waveform_from_path(path).await
     }
 
     /// JS: `FW.loaded_filename()` -> `simple.vcd`
     pub fn loaded_filename() -> Option<String> {
-        STORE.loaded_filename.get_cloned()
+        STORE
+            .waveforms
+            .lock_ref()
+            .first()
+            .map(|waveform| waveform.filename.clone())
+    }
+
+    /// JS: `FW.loaded_filenames()` -> `["golden.vcd", "failing.vcd"]`
+    pub fn loaded_filenames() -> Vec<Filename> {
+        STORE
+            .waveforms
+            .lock_ref()
+            .iter()
+            .map(|waveform| waveform.filename.clone())
+            .collect()
+    }
+
+    /// Results are also shown in the diff panel.
+    /// JS: `FW.diff_waveforms("golden.vcd", "failing.vcd")` -> `[{full_var_name: "simple_tb.s.B", first_mismatch_time: 50, mismatch_count: 1, mismatches: [{start: 50, end: 150}]}]`
+    pub async fn diff_waveforms(filename_a: Filename, filename_b: Filename) -> JsValue {
+        let waveform_id = |filename: &str| {
+            STORE
+                .waveforms
+                .lock_ref()
+                .iter()
+                .find(|waveform| waveform.filename == filename)
+                .map(|waveform| waveform.id)
+        };
+        let var_diffs = match (waveform_id(&filename_a), waveform_id(&filename_b)) {
+            (Some(waveform_id_a), Some(waveform_id_b)) => {
+                diff_panel::diff_waveforms(waveform_id_a, waveform_id_b).await
+            }
+            _ => Vec::new(),
+        };
+        serde_wasm_bindgen::to_value(&var_diffs).unwrap_throw()
     }
 
     /// JS: `FW.selected_vars()` -> `["simple_tb.s.A", "simple_tb.s.B"]`
     pub fn selected_vars() -> Vec<FullVarName> {
-        if let Some(hierarchy) = STORE.hierarchy.get_cloned() {
-            let mut full_var_names = Vec::new();
-            for var_ref in STORE.selected_var_refs.lock_ref().as_slice() {
-                let var = hierarchy.get(*var_ref);
-                let var_name = var.full_name(&hierarchy);
-                full_var_names.push(var_name);
+        let mut full_var_names = Vec::new();
+        for selected_var in STORE.selected_vars.lock_ref().as_slice() {
+            if let Some(waveform) = find_waveform(&STORE.waveforms, selected_var.waveform_id) {
+                full_var_names.push(waveform.full_var_name(selected_var.var_ref));
+            }
+        }
+        full_var_names
+    }
+
+    /// JS: `FW.set_cursor(1200)`
+    pub fn set_cursor(time: Option<Time>) {
+        STORE
+            .time_markers
+            .cursor
+            .set_neq(time.map(|time| time as wellen::Time));
+    }
+
+    /// JS: `FW.cursor()` -> `1200`
+    pub fn cursor() -> Option<Time> {
+        STORE.time_markers.cursor.get().map(|time| time as Time)
+    }
+
+    /// JS: `FW.add_marker("M1", 1200)` -> `3`
+    pub fn add_marker(name: MarkerName, time: Time) -> usize {
+        let mut markers = STORE.time_markers.markers.lock_mut();
+        markers.retain(|marker| marker.name != name);
+        markers.push_cloned(shared::Marker {
+            name,
+            time: time as wellen::Time,
+        });
+        markers.len()
+    }
+
+    /// JS: `FW.remove_marker("M1")` -> `true`
+    pub fn remove_marker(name: MarkerName) -> bool {
+        let mut markers = STORE.time_markers.markers.lock_mut();
+        let marker_count = markers.len();
+        markers.retain(|marker| marker.name != name);
+        markers.len() != marker_count
+    }
+
+    /// JS: `FW.clear_markers()` -> `2`
+    pub fn clear_markers() -> usize {
+        let mut markers = STORE.time_markers.markers.lock_mut();
+        let marker_count = markers.len();
+        markers.clear();
+        marker_count
+    }
+
+    /// JS: `FW.set_markers([{name: "M1", time: 1200}, {name: "M2", time: 1500}])` -> `2`
+    pub fn set_markers(markers: JsValue) -> usize {
+        let markers: Vec<shared::Marker> = serde_wasm_bindgen::from_value(markers).unwrap_throw();
+        let marker_count = markers.len();
+        STORE
+            .time_markers
+            .markers
+            .lock_mut()
+            .replace_cloned(markers);
+        marker_count
+    }
+
+    /// JS: `FW.markers()` -> `[{name: "M1", time: 1200}, {name: "M2", time: 1500}]`
+    pub fn markers() -> JsValue {
+        let markers = STORE.time_markers.markers.lock_ref();
+        serde_wasm_bindgen::to_value(markers.as_slice()).unwrap_throw()
+    }
+
+    /// JS: `FW.marker_delta("M1", "M2")` -> `300`
+    pub fn marker_delta(from_name: MarkerName, to_name: MarkerName) -> Option<Time> {
+        let markers = STORE.time_markers.markers.lock_ref();
+        let time = |name: &str| {
+            markers
+                .iter()
+                .find(|marker| marker.name == name)
+                .map(|marker| marker.time as Time)
+        };
+        Some(time(&to_name)? - time(&from_name)?)
+    }
+
+    /// JS: `FW.values_at(["simple_tb.s.A", "simple_tb.s.B"], 1200)` -> `["a", undefined]`
+    /// JS: `FW.values_at(["simple_tb.s.A"], 1200, "Unsigned")` -> `["10"]`
+    pub async fn values_at(
+        full_var_names: Vec<FullVarName>,
+        time: Time,
+        var_format: JsValue,
+    ) -> JsValue {
+        let var_format = if var_format.is_undefined() {
+            shared::VarFormat::default()
+        } else {
+            serde_wasm_bindgen::from_value(var_format).unwrap_throw()
+        };
+        let mut values = vec![None; full_var_names.len()];
+        // Values are requested once per waveform
+        let mut value_indices_and_signal_refs = BTreeMap::<_, (Vec<_>, Vec<_>)>::new();
+        for (index, full_var_name) in full_var_names.iter().enumerate() {
+            let Some(selected_var) = lookup_selected_var(full_var_name) else {
+                continue;
+            };
+            let Some(waveform) = find_waveform(&STORE.waveforms, selected_var.waveform_id) else {
+                continue;
+            };
+            let (value_indices, signal_refs_and_var_formats) = value_indices_and_signal_refs
+                .entry(waveform.id)
+                .or_default();
+            value_indices.push(index);
+            signal_refs_and_var_formats.push((
+                waveform.hierarchy.get(selected_var.var_ref).signal_ref(),
+                var_format,
+            ));
+        }
+        for (waveform_id, (value_indices, signal_refs_and_var_formats)) in
+            value_indices_and_signal_refs
+        {
+            let found_values = platform::get_values_at_time(
+                waveform_id,
+                signal_refs_and_var_formats,
+                time as wellen::Time,
+            )
+            .await;
+            for (index, value) in value_indices.into_iter().zip(found_values) {
+                values[index] = value;
             }
-            return full_var_names;
         }
-        Vec::new()
+        serde_wasm_bindgen::to_value(&values).unwrap_throw()
     }
 
     /// JS: `FW.add_decoders(["../test_files/components/rust_decoder/rust_decoder.wasm"])` -> `1`
     pub async fn add_decoders(decoder_paths: Vec<DecoderPath>) -> AddedDecodersCount {
-        platform::add_decoders(decoder_paths).await
+        let added_decoders_count = platform::add_decoders(decoder_paths).await;
+        refresh_components().await;
+        added_decoders_count
     }
 
     /// JS: `FW.remove_all_decoders()` -> `5`
     pub async fn remove_all_decoders() -> RemovedDecodersCount {
-        platform::remove_all_decoders().await
+        let removed_decoders_count = platform::remove_all_decoders().await;
+        refresh_components().await;
+        removed_decoders_count
+    }
+
+    /// The var is looked up in the first loaded waveform containing it.
+    /// JS: `FW.set_var_decoder("simple_tb.s.A", "Rust Test Decoder")`
+    /// JS: `FW.set_var_decoder("simple_tb.s.A", null)`
+    pub fn set_var_decoder(full_var_name: FullVarName, decoder_name: Option<DecoderName>) {
+        let Some(selected_var) = lookup_selected_var(&full_var_name) else {
+            return;
+        };
+        let waveform_var_name = (selected_var.waveform_id, full_var_name);
+        let mut var_decoders = STORE.var_decoders.lock_mut();
+        match decoder_name {
+            Some(decoder_name) => {
+                var_decoders.insert_cloned(waveform_var_name, decoder_name);
+            }
+            None => {
+                var_decoders.remove(&waveform_var_name);
+            }
+        }
+    }
+
+    /// JS: `FW.var_decoder("simple_tb.s.A")` -> `Rust Test Decoder`
+    pub fn var_decoder(full_var_name: FullVarName) -> Option<DecoderName> {
+        let selected_var = lookup_selected_var(&full_var_name)?;
+        STORE
+            .var_decoders
+            .lock_ref()
+            .get(&(selected_var.waveform_id, full_var_name))
+            .cloned()
+    }
+
+    /// JS: `FW.decoders_cache_stats()` -> `{hits: 1520, misses: 48, len: 48, capacity: 10000}`
+    pub async fn decoders_cache_stats() -> JsValue {
+        let stats = platform::decoders_cache_stats().await;
+        serde_wasm_bindgen::to_value(&stats).unwrap_throw()
     }
 
     // @TODO replace argument once Excalidraw's `convertToExcalidrawElements` works: {type: "rectangle", x: 100, y: 250}
@@ -101,11 +305,130 @@ impl FW {
     pub async fn add_diagram_connectors(
         connector_paths: Vec<DiagramConnectorPath>,
     ) -> AddedDiagramConnectorsCount {
-        platform::add_diagram_connectors(connector_paths).await
+        let added_diagram_connectors_count =
+            platform::add_diagram_connectors(connector_paths).await;
+        refresh_components().await;
+        added_diagram_connectors_count
     }
 
     /// JS: `FW.remove_all_diagram_connectors()` -> `5`
     pub async fn remove_all_diagram_connectors() -> RemovedDiagramConnectorsCount {
-        platform::remove_all_diagram_connectors().await
+        let removed_diagram_connectors_count = platform::remove_all_diagram_connectors().await;
+        refresh_components().await;
+        removed_diagram_connectors_count
+    }
+
+    /// JS: `FW.add_protocol_decoders(["../test_files/components/rust_uart_decoder/rust_uart_decoder.wasm"])` -> `1`
+    pub async fn add_protocol_decoders(
+        protocol_decoder_paths: Vec<ProtocolDecoderPath>,
+    ) -> AddedProtocolDecodersCount {
+        let added_protocol_decoders_count =
+            platform::add_protocol_decoders(protocol_decoder_paths).await;
+        refresh_components().await;
+        added_protocol_decoders_count
+    }
+
+    /// JS: `FW.remove_all_protocol_decoders()` -> `1`
+    pub async fn remove_all_protocol_decoders() -> RemovedProtocolDecodersCount {
+        let removed_protocol_decoders_count = platform::remove_all_protocol_decoders().await;
+        refresh_components().await;
+        removed_protocol_decoders_count
+    }
+
+    /// Adds rows with decoded transactions below the selected vars.
+    /// Vars are decoded in the first loaded waveform containing the first of them.
+    /// JS: `FW.decode_protocol("UART", ["uart_tb.rx"])` -> `1`
+    pub async fn decode_protocol(
+        protocol_decoder_name: ProtocolDecoderName,
+        input_var_names: Vec<FullVarName>,
+    ) -> usize {
+        let Some(selected_var) = input_var_names
+            .first()
+            .and_then(|full_var_name| lookup_selected_var(full_var_name))
+        else {
+            eprintln!("No loaded waveform contains the input vars of '{protocol_decoder_name}'");
+            return 0;
+        };
+        let annotation_rows = platform::decode_protocol(
+            selected_var.waveform_id,
+            protocol_decoder_name,
+            input_var_names,
+        )
+        .await;
+        let annotation_row_count = annotation_rows.len();
+        STORE.annotation_rows.lock_mut().extend(annotation_rows);
+        annotation_row_count
     }
+
+    /// JS: `FW.components()` -> `[{kind: "Decoder", index: 0, name: "Rust Test Decoder", path: "../test_files/components/rust_decoder/rust_decoder.wasm", status: "Enabled"}]`
+    pub async fn components() -> JsValue {
+        let components = platform::components().await;
+        serde_wasm_bindgen::to_value(&components).unwrap_throw()
+    }
+
+    /// JS: `FW.set_component_enabled("Decoder", 0, false)` -> `true`
+    pub async fn set_component_enabled(
+        kind: JsValue,
+        index: ComponentIndex,
+        is_enabled: bool,
+    ) -> Result<bool, JsValue> {
+        let kind = serde_wasm_bindgen::from_value(kind)?;
+        let is_changed = platform::set_component_enabled(kind, index, is_enabled).await;
+        refresh_components().await;
+        Ok(is_changed)
+    }
+
+    /// JS: `FW.remove_component("Decoder", 0)` -> `true`
+    pub async fn remove_component(kind: JsValue, index: ComponentIndex) -> Result<bool, JsValue> {
+        let kind = serde_wasm_bindgen::from_value(kind)?;
+        let is_removed = platform::remove_component(kind, index).await;
+        refresh_components().await;
+        Ok(is_removed)
+    }
+
+    /// JS: `FW.move_component("Decoder", 1, 0)` -> `true`
+    pub async fn move_component(
+        kind: JsValue,
+        old_index: ComponentIndex,
+        new_index: ComponentIndex,
+    ) -> Result<bool, JsValue> {
+        let kind = serde_wasm_bindgen::from_value(kind)?;
+        let is_moved = platform::move_component(kind, old_index, new_index).await;
+        refresh_components().await;
+        Ok(is_moved)
+    }
+
+    /// Entries logged since the app start, e.g. after `FW.add_decoders(["../test_files/components/rust_decoder/rust_decoder.wasm"])`:
+    /// JS: `FW.logs()` -> `[{timestamp: 1718103600000, severity: "Info", source: "Decoder", message: "'Rust Test Decoder' initialized"}, {timestamp: 1718103600002, severity: "Info", source: "Decoder", message: "'Rust Test Decoder' added"}]`
+    pub fn logs() -> JsValue {
+        let log_entries = STORE.log_entries.lock_ref();
+        serde_wasm_bindgen::to_value(log_entries.as_slice()).unwrap_throw()
+    }
+
+    /// JS: `FW.clear_annotation_rows()` -> `2`
+    pub fn clear_annotation_rows() -> usize {
+        let mut annotation_rows = STORE.annotation_rows.lock_mut();
+        let annotation_row_count = annotation_rows.len();
+        annotation_rows.clear();
+        annotation_row_count
+    }
+}
+
+/// Finds the var in the first loaded waveform containing it
+fn lookup_selected_var(full_var_name: &str) -> Option<SelectedVar> {
+    STORE.waveforms.lock_ref().iter().find_map(|waveform| {
+        lookup_var_ref(&waveform.hierarchy, full_var_name).map(|var_ref| SelectedVar {
+            waveform_id: waveform.id,
+            var_ref,
+        })
+    })
+}
+
+pub fn lookup_var_ref(
+    hierarchy: &wellen::Hierarchy,
+    full_var_name: &str,
+) -> Option<wellen::VarRef> {
+    let path_with_name = full_var_name.split_terminator('.').collect::<Vec<_>>();
+    let (name, path) = path_with_name.split_last()?;
+    hierarchy.lookup_var(path, name)
 }
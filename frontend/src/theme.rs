@@ -25,3 +25,9 @@ pub const COLOR_DARK_SLATE_BLUE: Oklch = color!("oklch(41.43% 0.125 262.26)");
 
 // pub const COLOR_LAVENDER: Rgba = color!("Lavender");  // oklch(93.09% 0.027 285.86)
 pub const COLOR_LAVENDER: Oklch = color!("oklch(93.09% 0.027 262.26)");
+
+// pub const COLOR_FIREBRICK: Rgba = color!("FireBrick");  // oklch(50.6% 0.18 27.5)
+pub const COLOR_FIREBRICK: Oklch = color!("oklch(50.6% 0.18 27.5)");
+
+// pub const COLOR_ORANGE: Rgba = color!("Orange");  // oklch(79.27% 0.171 70.67)
+pub const COLOR_ORANGE: Oklch = color!("oklch(79.27% 0.171 70.67)");
@@ -1,5 +1,7 @@
-use crate::{platform, script_bridge, theme::*, Filename};
-use std::sync::Arc;
+use crate::{
+    find_waveform, header_panel, platform, script_bridge, theme::*, DecoderName, LoadedWaveform,
+    SelectedVar, TimeMarkers, WaveformVarName,
+};
 use wellen::GetItem;
 use zoon::*;
 
@@ -9,27 +11,44 @@ pub use pixi_canvas::PixiController;
 
 const ROW_HEIGHT: u32 = 40;
 const ROW_GAP: u32 = 4;
+const RULER_HEIGHT: u32 = 30;
+const VALUE_AT_CURSOR_WIDTH: u32 = 150;
 
 #[derive(Clone)]
 pub struct WaveformPanel {
-    selected_var_refs: MutableVec<wellen::VarRef>,
-    hierarchy: Mutable<Option<Arc<wellen::Hierarchy>>>,
-    loaded_filename: Mutable<Option<Filename>>,
+    waveforms: MutableVec<LoadedWaveform>,
+    selected_vars: MutableVec<SelectedVar>,
+    time_markers: TimeMarkers,
     canvas_controller: Mutable<Mutable<Option<SendWrapper<PixiController>>>>,
+    var_decoders: MutableBTreeMap<WaveformVarName, DecoderName>,
+    var_formats: MutableBTreeMap<WaveformVarName, shared::VarFormat>,
+    var_render_modes: MutableBTreeMap<WaveformVarName, shared::RenderMode>,
+    annotation_rows: MutableVec<shared::AnnotationRow>,
+    selected_var_diff: Mutable<Option<shared::VarDiff>>,
 }
 
 impl WaveformPanel {
     pub fn new(
-        hierarchy: Mutable<Option<Arc<wellen::Hierarchy>>>,
-        selected_var_refs: MutableVec<wellen::VarRef>,
-        loaded_filename: Mutable<Option<Filename>>,
+        waveforms: MutableVec<LoadedWaveform>,
+        selected_vars: MutableVec<SelectedVar>,
+        time_markers: TimeMarkers,
         canvas_controller: Mutable<Mutable<Option<SendWrapper<PixiController>>>>,
+        var_decoders: MutableBTreeMap<WaveformVarName, DecoderName>,
+        var_formats: MutableBTreeMap<WaveformVarName, shared::VarFormat>,
+        var_render_modes: MutableBTreeMap<WaveformVarName, shared::RenderMode>,
+        annotation_rows: MutableVec<shared::AnnotationRow>,
+        selected_var_diff: Mutable<Option<shared::VarDiff>>,
     ) -> impl Element {
         Self {
-            selected_var_refs,
-            hierarchy,
-            loaded_filename,
+            waveforms,
+            selected_vars,
+            time_markers,
             canvas_controller,
+            var_decoders,
+            var_formats,
+            var_render_modes,
+            annotation_rows,
+            selected_var_diff,
         }
         .root()
     }
@@ -42,6 +61,7 @@ impl WaveformPanel {
             .s(Height::fill())
             .s(Gap::new().y(20))
             .item(self.selected_vars_controls())
+            .item(self.time_markers_panel())
             .item(self.vars_and_timelines_panel())
     }
 
@@ -60,6 +80,7 @@ impl WaveformPanel {
             Row::new()
                 .s(Align::new().center_x())
                 .s(Gap::new().x(15))
+                .item(El::new().s(Font::new().no_wrap()).child("Cursor: Click"))
                 .item(El::new().s(Font::new().no_wrap()).child("Zoom: Wheel"))
                 .item(
                     El::new()
@@ -69,6 +90,98 @@ impl WaveformPanel {
         )
     }
 
+    fn time_markers_panel(&self) -> impl Element {
+        let waveforms = self.waveforms.clone();
+        Row::new()
+            .multiline()
+            .s(Gap::new().x(15).y(10))
+            .item(self.add_marker_button())
+            .item(El::new().s(Font::new().no_wrap()).child_signal(
+                self.time_markers.cursor.signal().map(
+                    clone!((waveforms) move |cursor| match cursor {
+                        Some(time) => format!("Cursor: {}", format_time(time, &waveforms)),
+                        None => "Cursor: -".to_owned(),
+                    }),
+                ),
+            ))
+            .items_signal_vec(
+                self.time_markers
+                    .markers
+                    .signal_vec_cloned()
+                    .to_signal_cloned()
+                    .map(clone!((self => s) move |mut markers| {
+                        markers.sort_by_key(|marker| marker.time);
+                        let mut elements = Vec::new();
+                        let mut previous_marker: Option<shared::Marker> = None;
+                        for marker in markers {
+                            if let Some(previous_marker) = previous_marker {
+                                let delta = marker.time - previous_marker.time;
+                                elements.push(
+                                    El::new()
+                                        .s(Font::new().no_wrap().color(COLOR_LIGHT_BLUE))
+                                        .child(format!(
+                                            "Δ {} → {}: {}",
+                                            previous_marker.name,
+                                            marker.name,
+                                            format_time(delta, &s.waveforms)
+                                        ))
+                                        .unify(),
+                                );
+                            }
+                            elements.push(s.marker_button(marker.clone()).unify());
+                            previous_marker = Some(marker);
+                        }
+                        elements
+                    }))
+                    .to_signal_vec(),
+            )
+    }
+
+    fn add_marker_button(&self) -> impl Element {
+        let (hovered, hovered_signal) = Mutable::new_and_signal(false);
+        let time_markers = self.time_markers.clone();
+        Button::new()
+            .s(Padding::new().x(15).y(5))
+            .s(Background::new().color_signal(
+                hovered_signal.map_bool(|| COLOR_MEDIUM_SLATE_BLUE, || COLOR_SLATE_BLUE),
+            ))
+            .s(RoundedCorners::all(15))
+            .s(Font::new().no_wrap())
+            .label("Add marker at cursor")
+            .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
+            .on_press(move || {
+                let Some(time) = time_markers.cursor.get() else {
+                    return;
+                };
+                let mut markers = time_markers.markers.lock_mut();
+                let name = (1..)
+                    .map(|number| format!("M{number}"))
+                    .find(|name| markers.iter().all(|marker| &marker.name != name))
+                    .unwrap_throw();
+                markers.push_cloned(shared::Marker { name, time });
+            })
+    }
+
+    fn marker_button(&self, marker: shared::Marker) -> impl Element {
+        let (hovered, hovered_signal) = Mutable::new_and_signal(false);
+        let markers = self.time_markers.markers.clone();
+        Button::new()
+            .s(Padding::new().x(15).y(5))
+            .s(Background::new().color_signal(
+                hovered_signal.map_bool(|| COLOR_MEDIUM_SLATE_BLUE, || COLOR_SLATE_BLUE),
+            ))
+            .s(RoundedCorners::all(15))
+            .s(Font::new().no_wrap())
+            .label_signal(hovered.signal().map_bool(
+                clone!((marker) move || format!("Remove {}", marker.name)),
+                clone!((marker, self.waveforms => waveforms) move || {
+                    format!("{}: {}", marker.name, format_time(marker.time, &waveforms))
+                }),
+            ))
+            .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
+            .on_press(move || markers.lock_mut().retain(|other| other.name != marker.name))
+    }
+
     fn load_save_selected_vars_buttons(&self) -> impl Element {
         Row::new()
             .s(Gap::new().x(20))
@@ -171,9 +284,10 @@ impl WaveformPanel {
 
     fn save_selected_vars_button(&self) -> impl Element {
         let (hovered, hovered_signal) = Mutable::new_and_signal(false);
-        let loaded_filename = self.loaded_filename.clone();
-        let selected_var_refs = self.selected_var_refs.clone();
-        let hierarchy = self.hierarchy.clone();
+        let waveforms = self.waveforms.clone();
+        let selected_vars = self.selected_vars.clone();
+        let markers = self.time_markers.markers.clone();
+        let var_decoders = self.var_decoders.clone();
         Button::new()
             .s(Padding::new().x(20).y(10))
             .s(Background::new().color_signal(
@@ -183,20 +297,50 @@ impl WaveformPanel {
             .label("Save")
             .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
             .on_press(move || {
-                let loaded_filename = loaded_filename.get_cloned().unwrap_throw();
+                // Vars are looked up by their names in all loaded waveforms
+                let Some(loaded_filename) = waveforms
+                    .lock_ref()
+                    .first()
+                    .map(|waveform| waveform.filename.clone())
+                else {
+                    return;
+                };
                 let file_name = format!("{}_vars.fw.js", loaded_filename.replace('.', "_"));
 
-                let hierarchy = hierarchy.get_cloned().unwrap_throw();
+                let var_decoders = var_decoders.lock_ref();
                 let mut full_var_names = Vec::new();
-                for var_ref in selected_var_refs.lock_ref().as_slice() {
-                    let var = hierarchy.get(*var_ref);
-                    let var_name = var.full_name(&hierarchy);
-                    full_var_names.push(format!("\"{var_name}\""));
+                let mut var_decoder_setters = Vec::new();
+                for selected_var in selected_vars.lock_ref().as_slice() {
+                    let Some(waveform) = find_waveform(&waveforms, selected_var.waveform_id) else {
+                        continue;
+                    };
+                    let var_name = waveform.full_var_name(selected_var.var_ref);
+                    let var_name_literal = serde_json::to_string(&var_name).unwrap_throw();
+                    full_var_names.push(var_name_literal.clone());
+                    if let Some(decoder_name) = var_decoders.get(&(waveform.id, var_name)) {
+                        let decoder_name_literal =
+                            serde_json::to_string(decoder_name).unwrap_throw();
+                        var_decoder_setters.push(format!(
+                            "FW.set_var_decoder({var_name_literal}, {decoder_name_literal})"
+                        ));
+                    }
                 }
                 let full_var_names_string = full_var_names.join(",\n\t\t");
+                let var_decoder_setters_string = var_decoder_setters.join("\n\t");
+                let markers_string = markers
+                    .lock_ref()
+                    .iter()
+                    .map(|marker| {
+                        let name = serde_json::to_string(&marker.name).unwrap_throw();
+                        format!("{{name: {name}, time: {}}}", marker.time)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",\n\t\t");
                 let file_content = include_str!("waveform_panel/template_vars.px.js")
                     .replacen("{LOADED_FILENAME}", &loaded_filename, 1)
-                    .replacen("{FULL_VAR_NAMES}", &full_var_names_string, 1);
+                    .replacen("{FULL_VAR_NAMES}", &full_var_names_string, 1)
+                    .replacen("{MARKERS}", &markers_string, 1)
+                    .replacen("{VAR_DECODERS}", &var_decoder_setters_string, 1);
 
                 // @TODO we need to use ugly code with temp anchor element until (if ever)
                 // `showSaveFilePicker` is supported in Safari and Firefox (https://caniuse.com/?search=showSaveFilePicker)
@@ -228,38 +372,58 @@ impl WaveformPanel {
             .s(Gap::new().y(ROW_GAP))
             .s(Align::new().top())
             .on_viewport_size_change(move |_, height| height_getter.set_neq(height))
-            .items_signal_vec(self.selected_var_refs.signal_vec().enumerate().map(
-                clone!((self => s) move |(index, var_ref)| {
-                    s.selected_var_panel(index, var_ref)
+            // Note: Space for the time ruler drawn at the top of the canvas
+            .item(El::new().s(Height::exact(RULER_HEIGHT)))
+            .items_signal_vec(self.selected_vars.signal_vec().enumerate().map(
+                clone!((self => s) move |(index, selected_var)| {
+                    s.selected_var_panel(index, selected_var)
+                }),
+            ))
+            .items_signal_vec(self.annotation_rows.signal_vec_cloned().enumerate().map(
+                clone!((self => s) move |(index, annotation_row)| {
+                    s.annotation_row_name_button(&annotation_row.name, index)
                 }),
             ))
     }
 
     fn canvas(&self, selected_vars_panel_height: ReadOnlyMutable<u32>) -> impl Element {
-        let selected_var_refs = self.selected_var_refs.clone();
-        let hierarchy = self.hierarchy.clone();
+        let selected_vars = self.selected_vars.clone();
+        let time_markers = self.time_markers.clone();
+        let waveforms = self.waveforms.clone();
         let canvas_controller = self.canvas_controller.clone();
-        PixiCanvas::new(ROW_HEIGHT, ROW_GAP)
+        let var_decoders = self.var_decoders.clone();
+        let var_formats = self.var_formats.clone();
+        let var_render_modes = self.var_render_modes.clone();
+        let annotation_rows = self.annotation_rows.clone();
+        let selected_var_diff = self.selected_var_diff.clone();
+        PixiCanvas::new(ROW_HEIGHT, ROW_GAP, RULER_HEIGHT)
             .s(Align::new().top())
             .s(Width::fill())
             .s(Height::exact_signal(selected_vars_panel_height.signal()))
-            .task_with_controller(move |controller| {
+            .task_with_controller(clone!((selected_vars, waveforms, var_decoders) move |controller| {
                 canvas_controller.set(controller.clone());
-                selected_var_refs.signal_vec().delay_remove(clone!((hierarchy) move |var_ref| {
-                    clone!((var_ref, hierarchy) async move {
-                        if let Some(hierarchy) = hierarchy.get_cloned() {
+                selected_vars.signal_vec().delay_remove(clone!((waveforms) move |selected_var| {
+                    // The reloaded backend waveform has no loaded signals
+                    let is_reloaded = header_panel::take_reloaded_selected_var(selected_var);
+                    clone!((selected_var, waveforms) async move {
+                        if is_reloaded {
+                            return;
+                        }
+                        // Signals of unloaded waveforms don't need to be unloaded
+                        if let Some(waveform) = find_waveform(&waveforms, selected_var.waveform_id) {
                             // @TODO unload only when no other selected variable use it?
-                            platform::unload_signal(hierarchy.get(var_ref).signal_ref()).await;
+                            let signal_ref = waveform.hierarchy.get(selected_var.var_ref).signal_ref();
+                            platform::unload_signal(waveform.id, signal_ref).await;
                         }
                     })
-                })).for_each(clone!((controller, hierarchy) move |vec_diff| {
-                    clone!((controller, hierarchy) async move {
+                })).for_each(clone!((controller, waveforms, var_decoders, var_formats, var_render_modes) move |vec_diff| {
+                    clone!((controller, waveforms, var_decoders, var_formats, var_render_modes) async move {
                         match vec_diff {
                             VecDiff::Replace { values } => {
                                 let controller = controller.wait_for_some_cloned().await;
                                 controller.clear_vars();
-                                for var_ref in values {
-                                    Self::push_var(&controller, &hierarchy, var_ref).await;
+                                for selected_var in values {
+                                    Self::push_var(&controller, &waveforms, &var_decoders, &var_formats, &var_render_modes, selected_var).await;
                                 }
                             },
                             VecDiff::InsertAt { index: _, value: _ } => { todo!("`task_with_controller` + `InsertAt`") }
@@ -270,9 +434,9 @@ impl WaveformPanel {
                                 }
                             }
                             VecDiff::Move { old_index: _, new_index: _ } => { todo!("`task_with_controller` + `Move`") }
-                            VecDiff::Push { value: var_ref } => {
+                            VecDiff::Push { value: selected_var } => {
                                 if let Some(controller) = controller.lock_ref().as_ref() {
-                                    Self::push_var(controller, &hierarchy, var_ref).await;
+                                    Self::push_var(controller, &waveforms, &var_decoders, &var_formats, &var_render_modes, selected_var).await;
                                 }
                             }
                             VecDiff::Pop {} => {
@@ -288,61 +452,173 @@ impl WaveformPanel {
                         }
                     })
                 }))
+            }))
+            .task_with_controller(clone!((selected_vars, waveforms) move |controller| {
+                var_decoders.signal_map_cloned().for_each(move |_| {
+                    if let Some(controller) = controller.lock_ref().as_ref() {
+                        let var_decoders = var_decoders.lock_ref();
+                        for (index, selected_var) in selected_vars.lock_ref().iter().enumerate() {
+                            if let Some(waveform) = find_waveform(&waveforms, selected_var.waveform_id) {
+                                let waveform_var_name = (waveform.id, waveform.full_var_name(selected_var.var_ref));
+                                controller.set_var_decoder(index, var_decoders.get(&waveform_var_name).cloned());
+                            }
+                        }
+                    }
+                    async {}
+                })
+            }))
+            .task_with_controller(move |controller| {
+                map_ref! {
+                    let controller = controller.signal_cloned(),
+                    let annotation_rows = annotation_rows.signal_vec_cloned().to_signal_cloned() => {
+                        (controller.clone(), annotation_rows.clone())
+                    }
+                }
+                .for_each_sync(|(controller, annotation_rows)| {
+                    if let Some(controller) = controller {
+                        controller.set_annotation_rows(
+                            serde_wasm_bindgen::to_value(&annotation_rows).unwrap_throw(),
+                        );
+                    }
+                })
+            })
+            .task_with_controller(move |controller| {
+                map_ref! {
+                    let controller = controller.signal_cloned(),
+                    let diff_mismatches = selected_var_diff.signal_ref(|var_diff| {
+                        var_diff.as_ref().map(|var_diff| var_diff.mismatches.clone()).unwrap_or_default()
+                    }) => {
+                        (controller.clone(), diff_mismatches.clone())
+                    }
+                }
+                .for_each_sync(|(controller, diff_mismatches)| {
+                    if let Some(controller) = controller {
+                        controller.set_diff_mismatches(
+                            serde_wasm_bindgen::to_value(&diff_mismatches).unwrap_throw(),
+                        );
+                    }
+                })
+            })
+            .task_with_controller(move |controller| {
+                map_ref! {
+                    let controller = controller.signal_cloned(),
+                    let cursor = time_markers.cursor.signal(),
+                    let markers = time_markers.markers.signal_vec_cloned().to_signal_cloned() => {
+                        (controller.clone(), *cursor, markers.clone())
+                    }
+                }
+                .for_each_sync(|(controller, cursor, markers)| {
+                    if let Some(controller) = controller {
+                        controller.set_cursor(cursor.map(|time| time as f64));
+                        controller.set_markers(serde_wasm_bindgen::to_value(&markers).unwrap_throw());
+                    }
+                })
             })
     }
 
     async fn push_var(
         controller: &PixiController,
-        hierarchy: &Mutable<Option<Arc<wellen::Hierarchy>>>,
-        var_ref: wellen::VarRef,
+        waveforms: &MutableVec<LoadedWaveform>,
+        var_decoders: &MutableBTreeMap<WaveformVarName, DecoderName>,
+        var_formats: &MutableBTreeMap<WaveformVarName, shared::VarFormat>,
+        var_render_modes: &MutableBTreeMap<WaveformVarName, shared::RenderMode>,
+        selected_var: SelectedVar,
     ) {
-        let hierarchy = hierarchy.get_cloned().unwrap();
-
-        let var_format = shared::VarFormat::default();
+        let Some(waveform) = find_waveform(waveforms, selected_var.waveform_id) else {
+            return;
+        };
+        let hierarchy = waveform.hierarchy;
 
-        let var = hierarchy.get(var_ref);
+        let var = hierarchy.get(selected_var.var_ref);
         let signal_ref = var.signal_ref();
+        let full_var_name = var.full_name(&hierarchy);
+        let waveform_var_name = (waveform.id, full_var_name.clone());
+
+        let var_format = var_formats
+            .lock_ref()
+            .get(&waveform_var_name)
+            .copied()
+            .unwrap_or_default();
+        let render_mode = var_render_modes
+            .lock_ref()
+            .get(&waveform_var_name)
+            .copied()
+            .unwrap_or_default();
+
+        let decoder_name = var_decoders.lock_ref().get(&waveform_var_name).cloned();
         let timeline = platform::load_signal_and_get_timeline(
+            waveform.id,
             signal_ref,
+            full_var_name.clone(),
             controller.get_timeline_zoom(),
             controller.get_timeline_viewport_width(),
             controller.get_timeline_viewport_x(),
             ROW_HEIGHT,
             var_format,
+            render_mode,
+            decoder_name.clone(),
         )
         .await;
 
-        // @TODO render timeline with time units
-        // let timescale = hierarchy.timescale();
-
         // Note: Sync `timeline`'s type with the `Timeline` in `frontend/typescript/pixi_canvas/pixi_canvas.ts'
         let timeline = serde_wasm_bindgen::to_value(&timeline).unwrap_throw();
         let signal_ref_index = signal_ref.index();
         let var_format = serde_wasm_bindgen::to_value(&var_format).unwrap_throw();
-        controller.push_var(signal_ref_index, timeline, var_format);
+        let render_mode = serde_wasm_bindgen::to_value(&render_mode).unwrap_throw();
+        controller.push_var(
+            waveform.id,
+            signal_ref_index,
+            full_var_name,
+            timeline,
+            var_format,
+            render_mode,
+            decoder_name,
+        );
     }
 
     fn selected_var_panel(
         &self,
         index: ReadOnlyMutable<Option<usize>>,
-        var_ref: wellen::VarRef,
+        selected_var: SelectedVar,
     ) -> Option<impl Element> {
-        let Some(hierarchy) = self.hierarchy.get_cloned() else {
+        let Some(waveform) = find_waveform(&self.waveforms, selected_var.waveform_id) else {
             None?
         };
-        let var = hierarchy.get(var_ref);
+        let hierarchy = &waveform.hierarchy;
+        let var = hierarchy.get(selected_var.var_ref);
+        let waveform_var_name = (waveform.id, var.full_name(hierarchy));
+        // Kept in `var_formats` to survive the waveform reloading
+        let var_format = Mutable::new(
+            self.var_formats
+                .lock_ref()
+                .get(&waveform_var_name)
+                .copied()
+                .unwrap_or_default(),
+        );
         Row::new()
-            .item(self.selected_var_name_button(var.name(&hierarchy), index.clone()))
-            .item(self.selected_var_format_button(index))
+            .item(self.selected_var_name_button(
+                var.name(hierarchy),
+                &waveform.filename,
+                index.clone(),
+            ))
+            .item(self.selected_var_value_at_cursor(
+                waveform.id,
+                var.signal_ref(),
+                var_format.read_only(),
+            ))
+            .item(self.selected_var_render_mode_button(index.clone(), waveform_var_name.clone()))
+            .item(self.selected_var_format_button(index, var_format, waveform_var_name))
             .apply(Some)
     }
 
+    /// `filename` tells apart vars with the same name from different waveforms
     fn selected_var_name_button(
         &self,
         name: &str,
+        filename: &str,
         index: ReadOnlyMutable<Option<usize>>,
     ) -> impl Element {
-        let selected_var_refs = self.selected_var_refs.clone();
+        let selected_vars = self.selected_vars.clone();
         let (hovered, hovered_signal) = Mutable::new_and_signal(false);
         Button::new()
             .s(Height::exact(ROW_HEIGHT))
@@ -356,7 +632,7 @@ impl WaveformPanel {
                     .update_raw_el(|raw_el| {
                         raw_el
                             // @TODO move `title` to MZ API? (as `native_tooltip`?)
-                            .attr("title", name)
+                            .attr("title", &format!("{name}\n{filename}"))
                             // Note: `text-overflow` / ellipsis` doesn't work with flex and dynamic sizes
                             .style("text-overflow", "ellipsis")
                             .style("display", "inline-block")
@@ -365,37 +641,188 @@ impl WaveformPanel {
                     .s(Width::default().max(400))
                     .s(Align::new().left())
                     .s(Padding::new().left(20).right(17).y(10))
+                    .child(
+                        Row::new().s(Gap::new().x(10)).item(name).item(
+                            El::new()
+                                .s(Font::new().size(12).no_wrap().color(COLOR_LIGHT_BLUE))
+                                .child(filename),
+                        ),
+                    ),
+            )
+            .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
+            .on_press(move || {
+                if let Some(index) = index.get() {
+                    selected_vars.lock_mut().remove(index);
+                }
+            })
+    }
+
+    fn annotation_row_name_button(
+        &self,
+        name: &str,
+        index: ReadOnlyMutable<Option<usize>>,
+    ) -> impl Element {
+        let annotation_rows = self.annotation_rows.clone();
+        let (hovered, hovered_signal) = Mutable::new_and_signal(false);
+        Button::new()
+            .s(Height::exact(ROW_HEIGHT))
+            .s(Width::growable())
+            .s(Background::new().color_signal(
+                hovered_signal.map_bool(|| COLOR_SLATE_BLUE, || COLOR_SLATE_BLUE_WITH_ALPHA),
+            ))
+            .s(RoundedCorners::all(15))
+            .label(
+                El::new()
+                    .update_raw_el(|raw_el| {
+                        raw_el
+                            .attr("title", name)
+                            .style("text-overflow", "ellipsis")
+                            .style("display", "inline-block")
+                    })
+                    .s(Scrollbars::both().visible(false))
+                    .s(Width::default().max(400))
+                    .s(Align::new().left())
+                    .s(Padding::new().left(20).right(17).y(10))
                     .child(name),
             )
             .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
             .on_press(move || {
                 if let Some(index) = index.get() {
-                    selected_var_refs.lock_mut().remove(index);
+                    annotation_rows.lock_mut().remove(index);
                 }
             })
     }
 
-    fn selected_var_format_button(&self, index: ReadOnlyMutable<Option<usize>>) -> impl Element {
-        let var_format = Mutable::new(shared::VarFormat::default());
+    fn selected_var_value_at_cursor(
+        &self,
+        waveform_id: shared::WaveformId,
+        signal_ref: wellen::SignalRef,
+        var_format: ReadOnlyMutable<shared::VarFormat>,
+    ) -> impl Element {
+        let value = map_ref! {
+            let cursor = self.time_markers.cursor.signal(),
+            let var_format = var_format.signal() => (*cursor, *var_format)
+        }
+        .map_future(move |(cursor, var_format)| async move {
+            platform::get_value_at_time(waveform_id, signal_ref, cursor?, var_format).await
+        })
+        .map(|value| value.flatten().unwrap_or_default());
+        El::new()
+            .s(Height::exact(ROW_HEIGHT))
+            .s(Width::exact(VALUE_AT_CURSOR_WIDTH))
+            .s(Background::new().color(COLOR_SLATE_BLUE_WITH_ALPHA))
+            .s(RoundedCorners::all(5))
+            .s(Scrollbars::both().visible(false))
+            .s(Padding::new().x(10))
+            .s(Font::new()
+                .no_wrap()
+                .color(COLOR_WHITE)
+                .family([FontFamily::new("Courier New"), FontFamily::Monospace]))
+            .child(El::new().s(Align::new().center_y()).child_signal(value))
+    }
+
+    fn selected_var_render_mode_button(
+        &self,
+        index: ReadOnlyMutable<Option<usize>>,
+        waveform_var_name: WaveformVarName,
+    ) -> impl Element {
+        // Kept in `var_render_modes` to survive the waveform reloading
+        let render_mode = Mutable::new(
+            self.var_render_modes
+                .lock_ref()
+                .get(&waveform_var_name)
+                .copied()
+                .unwrap_or_default(),
+        );
         let (hovered, hovered_signal) = Mutable::new_and_signal(false);
         let canvas_controller = self.canvas_controller.clone();
+        let var_render_modes = self.var_render_modes.clone();
         Button::new()
             .s(Height::exact(ROW_HEIGHT))
             .s(Width::exact(70))
             .s(Background::new().color_signal(
                 hovered_signal.map_bool(|| COLOR_SLATE_BLUE, || COLOR_SLATE_BLUE_WITH_ALPHA),
             ))
+            .s(RoundedCorners::all(5))
+            .label(
+                El::new()
+                    .s(Align::center())
+                    .s(Padding::new().x(10).y(10))
+                    .child_signal(render_mode.signal().map(|mode| mode.as_static_str())),
+            )
+            .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
+            .on_press(move || {
+                let next_mode = render_mode.get().next();
+                render_mode.set(next_mode);
+                var_render_modes
+                    .lock_mut()
+                    .insert_cloned(waveform_var_name.clone(), next_mode);
+                if let Some(canvas_controller) = canvas_controller.get_cloned().lock_ref().as_ref()
+                {
+                    if let Some(index) = index.get() {
+                        canvas_controller.set_render_mode(
+                            index,
+                            serde_wasm_bindgen::to_value(&next_mode).unwrap_throw(),
+                        );
+                    }
+                }
+            })
+    }
+
+    fn selected_var_format_button(
+        &self,
+        index: ReadOnlyMutable<Option<usize>>,
+        var_format: Mutable<shared::VarFormat>,
+        waveform_var_name: WaveformVarName,
+    ) -> impl Element {
+        let (hovered, hovered_signal) = Mutable::new_and_signal(false);
+        let canvas_controller = self.canvas_controller.clone();
+        let var_decoders = self.var_decoders.clone();
+        let var_formats = self.var_formats.clone();
+        let label = map_ref! {
+            let var_format = var_format.signal(),
+            let decoder_name = var_decoders.signal_map_cloned().key_cloned(waveform_var_name.clone()) => {
+                match decoder_name {
+                    Some(decoder_name) => format!("{} | {decoder_name}", var_format.as_static_str()),
+                    None => var_format.as_static_str().to_owned(),
+                }
+            }
+        };
+        Button::new()
+            .s(Height::exact(ROW_HEIGHT))
+            .s(Width::default().min(70))
+            .s(Background::new().color_signal(
+                hovered_signal.map_bool(|| COLOR_SLATE_BLUE, || COLOR_SLATE_BLUE_WITH_ALPHA),
+            ))
             .s(RoundedCorners::new().left(5))
             .label(
                 El::new()
                     .s(Align::center())
                     .s(Padding::new().left(20).right(17).y(10))
-                    .child_signal(var_format.signal().map(|format| format.as_static_str())),
+                    .s(Font::new().no_wrap())
+                    .child_signal(label),
             )
+            .update_raw_el(|raw_el| {
+                raw_el
+                    .attr("title", "Click: Next format\nRight click: Next decoder")
+                    .event_handler_with_options(EventOptions::new().preventable(), {
+                        let waveform_var_name = waveform_var_name.clone();
+                        move |event: events::ContextMenu| {
+                            event.prevent_default();
+                            Task::start(select_next_var_decoder(
+                                var_decoders.clone(),
+                                waveform_var_name.clone(),
+                            ));
+                        }
+                    })
+            })
             .on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
             .on_press(move || {
                 let next_format = var_format.get().next();
                 var_format.set(next_format);
+                var_formats
+                    .lock_mut()
+                    .insert_cloned(waveform_var_name.clone(), next_format);
                 if let Some(canvas_controller) = canvas_controller.get_cloned().lock_ref().as_ref()
                 {
                     if let Some(index) = index.get() {
@@ -408,3 +835,35 @@ impl WaveformPanel {
             })
     }
 }
+
+async fn select_next_var_decoder(
+    var_decoders: MutableBTreeMap<WaveformVarName, DecoderName>,
+    waveform_var_name: WaveformVarName,
+) {
+    let decoder_names = platform::decoder_names().await;
+    let mut var_decoders = var_decoders.lock_mut();
+    let next_decoder_name = match var_decoders.get(&waveform_var_name) {
+        Some(decoder_name) => decoder_names
+            .iter()
+            .skip_while(|name| *name != decoder_name)
+            .nth(1),
+        None => decoder_names.first(),
+    };
+    match next_decoder_name {
+        Some(decoder_name) => {
+            var_decoders.insert_cloned(waveform_var_name, decoder_name.clone());
+        }
+        None => {
+            var_decoders.remove(&waveform_var_name);
+        }
+    }
+}
+
+/// Times of all waveforms are in the time units of the first loaded one
+pub fn format_time(time: wellen::Time, waveforms: &MutableVec<LoadedWaveform>) -> String {
+    let timescale = waveforms
+        .lock_ref()
+        .first()
+        .and_then(|waveform| waveform.hierarchy.timescale());
+    shared::format_time(time, timescale)
+}
@@ -1,4 +1,4 @@
-use crate::platform;
+use crate::{platform, STORE};
 pub use js_bridge::PixiController;
 use std::rc::Rc;
 use zoon::*;
@@ -10,7 +10,7 @@ pub struct PixiCanvas {
     width: ReadOnlyMutable<u32>,
     #[allow(dead_code)]
     height: ReadOnlyMutable<u32>,
-    task_with_controller: Mutable<Option<TaskHandle>>,
+    tasks_with_controller: Mutable<Vec<TaskHandle>>,
 }
 
 impl Element for PixiCanvas {}
@@ -31,7 +31,7 @@ impl AddNearbyElement<'_> for PixiCanvas {}
 impl HasIds for PixiCanvas {}
 
 impl PixiCanvas {
-    pub fn new(row_height: u32, row_gap: u32) -> Self {
+    pub fn new(row_height: u32, row_gap: u32, ruler_height: u32) -> Self {
         let controller: Mutable<Option<SendWrapper<js_bridge::PixiController>>> =
             Mutable::new(None);
         let width = Mutable::new(0);
@@ -51,24 +51,29 @@ impl PixiCanvas {
                 })),
             ),
         );
-        let task_with_controller = Mutable::new(None);
+        let tasks_with_controller = Mutable::new(Vec::new());
         // -- FastWave-specific --
         let timeline_getter = Rc::new(Closure::new(
-            |signal_ref_index,
+            |var_signal_row: js_bridge::VarSignalRow,
              timeline_zoom,
              timeline_viewport_width,
              timeline_viewport_x,
-             row_height,
-             var_format| {
+             row_height| {
                 future_to_promise(async move {
-                    let signal_ref = wellen::SignalRef::from_index(signal_ref_index).unwrap_throw();
+                    let signal_ref =
+                        wellen::SignalRef::from_index(var_signal_row.signal_ref_index())
+                            .unwrap_throw();
                     let timeline = platform::load_signal_and_get_timeline(
+                        var_signal_row.waveform_id(),
                         signal_ref,
+                        var_signal_row.full_var_name(),
                         timeline_zoom,
                         timeline_viewport_width,
                         timeline_viewport_x,
                         row_height,
-                        serde_wasm_bindgen::from_value(var_format).unwrap_throw(),
+                        serde_wasm_bindgen::from_value(var_signal_row.var_format()).unwrap_throw(),
+                        serde_wasm_bindgen::from_value(var_signal_row.render_mode()).unwrap_throw(),
+                        var_signal_row.decoder_name(),
                     )
                     .await;
                     let timeline = serde_wasm_bindgen::to_value(&timeline).unwrap_throw();
@@ -76,12 +81,32 @@ impl PixiCanvas {
                 })
             },
         ));
+        let ruler_getter = Rc::new(Closure::new(
+            |timeline_zoom, timeline_viewport_width, timeline_viewport_x| {
+                future_to_promise(async move {
+                    let ruler = platform::get_timeline_ruler(
+                        timeline_zoom,
+                        timeline_viewport_width,
+                        timeline_viewport_x,
+                    )
+                    .await;
+                    let ruler = serde_wasm_bindgen::to_value(&ruler).unwrap_throw();
+                    Ok(ruler)
+                })
+            },
+        ));
+        let cursor_change_handler = Rc::new(Closure::new(|time: f64| {
+            STORE
+                .time_markers
+                .cursor
+                .set_neq(Some(time.round() as wellen::Time));
+        }));
         // -- // --
         Self {
             controller: controller.clone(),
             width: width.read_only(),
             height: height.read_only(),
-            task_with_controller: task_with_controller.clone(),
+            tasks_with_controller: tasks_with_controller.clone(),
             raw_el: El::new()
                 .s(Clip::both())
                 .on_viewport_size_change(clone!((width, height) move |new_width, new_height| {
@@ -104,24 +129,31 @@ impl PixiCanvas {
                         }),
                     )
                 })
-                .after_insert(clone!((controller, timeline_getter) move |element| {
-                    Task::start(async move {
-                        let pixi_controller = SendWrapper::new(js_bridge::PixiController::new(
-                            1.,
-                            width.get(),
-                            0,
-                            row_height,
-                            row_gap,
-                            &timeline_getter
-                        ));
-                        pixi_controller.init(&element).await;
-                        controller.set(Some(pixi_controller));
-                    });
-                }))
+                .after_insert(
+                    clone!((controller, timeline_getter, ruler_getter, cursor_change_handler) move |element| {
+                        Task::start(async move {
+                            let pixi_controller = SendWrapper::new(js_bridge::PixiController::new(
+                                1.,
+                                width.get(),
+                                0,
+                                row_height,
+                                row_gap,
+                                ruler_height,
+                                &timeline_getter,
+                                &ruler_getter,
+                                &cursor_change_handler,
+                            ));
+                            pixi_controller.init(&element).await;
+                            controller.set(Some(pixi_controller));
+                        });
+                    }),
+                )
                 .after_remove(move |_| {
                     drop(timeline_getter);
+                    drop(ruler_getter);
+                    drop(cursor_change_handler);
                     drop(resize_task);
-                    drop(task_with_controller);
+                    drop(tasks_with_controller);
                     if let Some(controller) = controller.take() {
                         controller.destroy();
                     }
@@ -134,8 +166,9 @@ impl PixiCanvas {
         self,
         f: impl FnOnce(Mutable<Option<SendWrapper<js_bridge::PixiController>>>) -> FUT,
     ) -> Self {
-        self.task_with_controller
-            .set(Some(Task::start_droppable(f(self.controller.clone()))));
+        self.tasks_with_controller
+            .lock_mut()
+            .push(Task::start_droppable(f(self.controller.clone())));
         self
     }
 }
@@ -144,22 +177,50 @@ mod js_bridge {
     use zoon::*;
 
     type TimelinePromise = js_sys::Promise;
-    type SignalRefIndex = usize;
+    type WaveformId = shared::WaveformId;
     type TimelineZoom = f64;
     type TimelineViewportWidth = u32;
     type TimelineViewportX = i32;
     type RowHeight = u32;
-    type VarFormatJs = JsValue;
     type TimelineGetter = Closure<
         dyn FnMut(
-            SignalRefIndex,
+            VarSignalRow,
             TimelineZoom,
             TimelineViewportWidth,
             TimelineViewportX,
             RowHeight,
-            VarFormatJs,
         ) -> TimelinePromise,
     >;
+    type TimelineRulerPromise = js_sys::Promise;
+    type TimelineRulerGetter = Closure<
+        dyn FnMut(TimelineZoom, TimelineViewportWidth, TimelineViewportX) -> TimelineRulerPromise,
+    >;
+    type Time = f64;
+    type CursorChangeHandler = Closure<dyn FnMut(Time)>;
+
+    // Note: Sync with `VarSignalRow` fields in `frontend/typescript/pixi_canvas/pixi_canvas.ts`
+    #[wasm_bindgen]
+    extern "C" {
+        pub type VarSignalRow;
+
+        #[wasm_bindgen(method, getter)]
+        pub fn waveform_id(this: &VarSignalRow) -> WaveformId;
+
+        #[wasm_bindgen(method, getter)]
+        pub fn signal_ref_index(this: &VarSignalRow) -> usize;
+
+        #[wasm_bindgen(method, getter)]
+        pub fn full_var_name(this: &VarSignalRow) -> String;
+
+        #[wasm_bindgen(method, getter)]
+        pub fn var_format(this: &VarSignalRow) -> JsValue;
+
+        #[wasm_bindgen(method, getter)]
+        pub fn render_mode(this: &VarSignalRow) -> JsValue;
+
+        #[wasm_bindgen(method, getter)]
+        pub fn decoder_name(this: &VarSignalRow) -> Option<String>;
+    }
 
     // Note: Add all corresponding methods to `frontend/typescript/pixi_canvas/pixi_canvas.ts`
     #[wasm_bindgen(module = "/typescript/bundles/pixi_canvas.js")]
@@ -167,7 +228,7 @@ mod js_bridge {
         #[derive(Clone)]
         pub type PixiController;
 
-        // @TODO `row_height` and `row_gap` is FastWave-specific
+        // @TODO `row_height`, `row_gap` and `ruler_height` is FastWave-specific
         #[wasm_bindgen(constructor)]
         pub fn new(
             timeline_zoom: f64,
@@ -175,7 +236,10 @@ mod js_bridge {
             timeline_viewport_x: i32,
             row_height: u32,
             row_gap: u32,
+            ruler_height: u32,
             timeline_getter: &TimelineGetter,
+            ruler_getter: &TimelineRulerGetter,
+            on_cursor_change: &CursorChangeHandler,
         ) -> PixiController;
 
         #[wasm_bindgen(method)]
@@ -201,6 +265,12 @@ mod js_bridge {
         #[wasm_bindgen(method)]
         pub fn set_var_format(this: &PixiController, index: usize, var_format: JsValue);
 
+        #[wasm_bindgen(method)]
+        pub fn set_render_mode(this: &PixiController, index: usize, render_mode: JsValue);
+
+        #[wasm_bindgen(method)]
+        pub fn set_var_decoder(this: &PixiController, index: usize, decoder_name: Option<String>);
+
         #[wasm_bindgen(method)]
         pub fn zoom_or_pan(
             this: &PixiController,
@@ -209,15 +279,34 @@ mod js_bridge {
             offset_x: u32,
         );
 
+        #[wasm_bindgen(method)]
+        pub fn set_cursor(this: &PixiController, time: Option<Time>);
+
+        #[wasm_bindgen(method)]
+        pub fn set_markers(this: &PixiController, markers: JsValue);
+
+        #[wasm_bindgen(method)]
+        pub fn set_annotation_rows(this: &PixiController, annotation_rows: JsValue);
+
+        #[wasm_bindgen(method)]
+        pub fn set_diff_mismatches(this: &PixiController, diff_mismatches: JsValue);
+
+        #[wasm_bindgen(method)]
+        pub fn scroll_to_time(this: &PixiController, time: Time);
+
         #[wasm_bindgen(method)]
         pub fn remove_var(this: &PixiController, index: usize);
 
         #[wasm_bindgen(method)]
         pub fn push_var(
             this: &PixiController,
+            waveform_id: WaveformId,
             signal_ref_index: usize,
+            full_var_name: String,
             timeline: JsValue,
             var_format: JsValue,
+            render_mode: JsValue,
+            decoder_name: Option<String>,
         );
 
         #[wasm_bindgen(method)]
@@ -228,5 +317,8 @@ mod js_bridge {
 
         #[wasm_bindgen(method)]
         pub async fn redraw_all_rows(this: &PixiController);
+
+        #[wasm_bindgen(method)]
+        pub async fn redraw_ruler(this: &PixiController);
     }
 }
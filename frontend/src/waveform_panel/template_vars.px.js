@@ -2,4 +2,8 @@ if (FW.loaded_filename() === "{LOADED_FILENAME}") {
     FW.select_vars([
         {FULL_VAR_NAMES}
     ])
+    FW.set_markers([
+        {MARKERS}
+    ])
+    {VAR_DECODERS}
 }
@@ -925,4 +925,921 @@ fn mainFragment(
             cv = cu.value;
             v = uv["${r}"];
             ${a};`)}}return new Function("ud","uv","renderer","syncData",e.join(`
-`))}var tb=f(()=>{wn();Lt();kl();Q_()});var Ws,eb=f(()=>{w();tb();Ws=class{constructor(t){this._cache={},this._uniformGroupSyncHash={},this._renderer=t,this.gl=null,this._cache={}}contextChange(t){this.gl=t}updateUniformGroup(t,e,r){let s=this._renderer.shader._getProgramData(e);(!t.isStatic||t._dirtyId!==s.uniformDirtyGroups[t.uid])&&(s.uniformDirtyGroups[t.uid]=t._dirtyId,this._getUniformSyncFunction(t,e)(s.uniformData,t.uniforms,this._renderer,r))}_getUniformSyncFunction(t,e){return this._uniformGroupSyncHash[t._signature]?.[e._key]||this._createUniformSyncFunction(t,e)}_createUniformSyncFunction(t,e){let r=this._uniformGroupSyncHash[t._signature]||(this._uniformGroupSyncHash[t._signature]={}),s=this._getSignature(t,e._uniformData,"u");return this._cache[s]||(this._cache[s]=this._generateUniformsSync(t,e._uniformData)),r[e._key]=this._cache[s],r[e._key]}_generateUniformsSync(t,e){return J_(t,e)}_getSignature(t,e,r){let s=t.uniforms,o=[`${r}-`];for(let n in s)o.push(n),e[n]&&o.push(e[n].type);return o.join("-")}destroy(){this._renderer=null,this._cache=null}};Ws.extension={type:[x.WebGLSystem],name:"uniformGroup"}});function rb(i){let t={};return t.normal=[i.ONE,i.ONE_MINUS_SRC_ALPHA],t.add=[i.ONE,i.ONE],t.multiply=[i.DST_COLOR,i.ONE_MINUS_SRC_ALPHA,i.ONE,i.ONE_MINUS_SRC_ALPHA],t.screen=[i.ONE,i.ONE_MINUS_SRC_COLOR,i.ONE,i.ONE_MINUS_SRC_ALPHA],t.none=[0,0],t["normal-npm"]=[i.SRC_ALPHA,i.ONE_MINUS_SRC_ALPHA,i.ONE,i.ONE_MINUS_SRC_ALPHA],t["add-npm"]=[i.SRC_ALPHA,i.ONE,i.ONE,i.ONE],t["screen-npm"]=[i.SRC_ALPHA,i.ONE_MINUS_SRC_COLOR,i.ONE,i.ONE_MINUS_SRC_ALPHA],t.erase=[i.ZERO,i.ONE_MINUS_SRC_ALPHA],t}var ib=f(()=>{"use strict"});var iT,sT,oT,nT,aT,hT,sb,ob,nb=f(()=>{w();oe();ib();iT=0,sT=1,oT=2,nT=3,aT=4,hT=5,sb=class ec{constructor(){this.gl=null,this.stateId=0,this.polygonOffset=0,this.blendMode="none",this._blendEq=!1,this.map=[],this.map[iT]=this.setBlend,this.map[sT]=this.setOffset,this.map[oT]=this.setCullFace,this.map[nT]=this.setDepthTest,this.map[aT]=this.setFrontFace,this.map[hT]=this.setDepthMask,this.checks=[],this.defaultState=bt.for2d()}contextChange(t){this.gl=t,this.blendModesMap=rb(t),this.reset()}set(t){if(t=t||this.defaultState,this.stateId!==t.data){let e=this.stateId^t.data,r=0;for(;e;)e&1&&this.map[r].call(this,!!(t.data&1<<r)),e=e>>1,r++;this.stateId=t.data}for(let e=0;e<this.checks.length;e++)this.checks[e](this,t)}forceState(t){t=t||this.defaultState;for(let e=0;e<this.map.length;e++)this.map[e].call(this,!!(t.data&1<<e));for(let e=0;e<this.checks.length;e++)this.checks[e](this,t);this.stateId=t.data}setBlend(t){this._updateCheck(ec._checkBlendMode,t),this.gl[t?"enable":"disable"](this.gl.BLEND)}setOffset(t){this._updateCheck(ec._checkPolygonOffset,t),this.gl[t?"enable":"disable"](this.gl.POLYGON_OFFSET_FILL)}setDepthTest(t){this.gl[t?"enable":"disable"](this.gl.DEPTH_TEST)}setDepthMask(t){this.gl.depthMask(t)}setCullFace(t){this.gl[t?"enable":"disable"](this.gl.CULL_FACE)}setFrontFace(t){this.gl.frontFace(this.gl[t?"CW":"CCW"])}setBlendMode(t){if(this.blendModesMap[t]||(t="normal"),t===this.blendMode)return;this.blendMode=t;let e=this.blendModesMap[t],r=this.gl;e.length===2?r.blendFunc(e[0],e[1]):r.blendFuncSeparate(e[0],e[1],e[2],e[3]),e.length===6?(this._blendEq=!0,r.blendEquationSeparate(e[4],e[5])):this._blendEq&&(this._blendEq=!1,r.blendEquationSeparate(r.FUNC_ADD,r.FUNC_ADD))}setPolygonOffset(t,e){this.gl.polygonOffset(t,e)}reset(){this.gl.pixelStorei(this.gl.UNPACK_FLIP_Y_WEBGL,!1),this.forceState(this.defaultState),this._blendEq=!0,this.blendMode="",this.setBlendMode("normal")}_updateCheck(t,e){let r=this.checks.indexOf(t);e&&r===-1?this.checks.push(t):!e&&r!==-1&&this.checks.splice(r,1)}static _checkBlendMode(t,e){t.setBlendMode(e.blendMode)}static _checkPolygonOffset(t,e){t.setPolygonOffset(1,e.polygonOffset)}destroy(){this.gl=null,this.checks.length=0}};sb.extension={type:[x.WebGLSystem],name:"state"};ob=sb});var Ln,ab=f(()=>{$l();Ln=class{constructor(t){this.target=Xl.TEXTURE_2D,this.texture=t,this.width=-1,this.height=-1,this.type=K.UNSIGNED_BYTE,this.internalFormat=Gn.RGBA,this.format=Gn.RGBA,this.samplerType=0}}});var hb,lb=f(()=>{"use strict";hb={id:"buffer",upload(i,t,e){t.width===i.width||t.height===i.height?e.texSubImage2D(e.TEXTURE_2D,0,0,0,i.width,i.height,t.format,t.type,i.resource):e.texImage2D(t.target,0,t.internalFormat,i.width,i.height,0,t.format,t.type,i.resource),t.width=i.width,t.height=i.height}}});var lT,cb,ub=f(()=>{"use strict";lT={"bc1-rgba-unorm":!0,"bc1-rgba-unorm-srgb":!0,"bc2-rgba-unorm":!0,"bc2-rgba-unorm-srgb":!0,"bc3-rgba-unorm":!0,"bc3-rgba-unorm-srgb":!0,"bc4-r-unorm":!0,"bc4-r-snorm":!0,"bc5-rg-unorm":!0,"bc5-rg-snorm":!0,"bc6h-rgb-ufloat":!0,"bc6h-rgb-float":!0,"bc7-rgba-unorm":!0,"bc7-rgba-unorm-srgb":!0,"etc2-rgb8unorm":!0,"etc2-rgb8unorm-srgb":!0,"etc2-rgb8a1unorm":!0,"etc2-rgb8a1unorm-srgb":!0,"etc2-rgba8unorm":!0,"etc2-rgba8unorm-srgb":!0,"eac-r11unorm":!0,"eac-r11snorm":!0,"eac-rg11unorm":!0,"eac-rg11snorm":!0,"astc-4x4-unorm":!0,"astc-4x4-unorm-srgb":!0,"astc-5x4-unorm":!0,"astc-5x4-unorm-srgb":!0,"astc-5x5-unorm":!0,"astc-5x5-unorm-srgb":!0,"astc-6x5-unorm":!0,"astc-6x5-unorm-srgb":!0,"astc-6x6-unorm":!0,"astc-6x6-unorm-srgb":!0,"astc-8x5-unorm":!0,"astc-8x5-unorm-srgb":!0,"astc-8x6-unorm":!0,"astc-8x6-unorm-srgb":!0,"astc-8x8-unorm":!0,"astc-8x8-unorm-srgb":!0,"astc-10x5-unorm":!0,"astc-10x5-unorm-srgb":!0,"astc-10x6-unorm":!0,"astc-10x6-unorm-srgb":!0,"astc-10x8-unorm":!0,"astc-10x8-unorm-srgb":!0,"astc-10x10-unorm":!0,"astc-10x10-unorm-srgb":!0,"astc-12x10-unorm":!0,"astc-12x10-unorm-srgb":!0,"astc-12x12-unorm":!0,"astc-12x12-unorm-srgb":!0},cb={id:"compressed",upload(i,t,e){e.pixelStorei(e.UNPACK_ALIGNMENT,4);let r=i.pixelWidth,s=i.pixelHeight,o=!!lT[i.format];for(let n=0;n<i.resource.length;n++){let a=i.resource[n];o?e.compressedTexImage2D(e.TEXTURE_2D,n,t.internalFormat,r,s,0,a):e.texImage2D(e.TEXTURE_2D,n,t.internalFormat,r,s,0,t.format,t.type,a),r=Math.max(r>>1,1),s=Math.max(s>>1,1)}}}});var Nn,rc=f(()=>{"use strict";Nn={id:"image",upload(i,t,e,r){let s=i.alphaMode==="premultiply-alpha-on-upload";e.pixelStorei(e.UNPACK_PREMULTIPLY_ALPHA_WEBGL,s);let o=t.width,n=t.height,a=i.pixelWidth,h=i.pixelHeight,c=i.resourceWidth,l=i.resourceHeight;c<a||l<h?((o!==a||n!==h)&&e.texImage2D(t.target,0,t.internalFormat,a,h,0,t.format,t.type,null),r===2?e.texSubImage2D(e.TEXTURE_2D,0,0,0,c,l,t.format,t.type,i.resource):e.texSubImage2D(t.target,0,0,0,t.format,t.type,i.resource)):o===a&&n===h?e.texSubImage2D(e.TEXTURE_2D,0,0,0,i.width,i.height,t.format,t.type,i.resource):r===2?e.texImage2D(t.target,0,t.internalFormat,a,h,0,t.format,t.type,i.resource):e.texImage2D(t.target,0,t.internalFormat,t.format,t.type,i.resource),t.width=a,t.height=h}}});var db,fb=f(()=>{rc();db={id:"video",upload(i,t,e,r){if(!i.isValid){e.texImage2D(t.target,0,t.internalFormat,1,1,0,t.format,t.type,null);return}Nn.upload(i,t,e,r)}}});var ic,pb,Hn,mb,gb=f(()=>{"use strict";ic={linear:9729,nearest:9728},pb={linear:{linear:9987,nearest:9985},nearest:{linear:9986,nearest:9984}},Hn={"clamp-to-edge":33071,repeat:10497,"mirror-repeat":33648},mb={never:512,less:513,equal:514,"less-equal":515,greater:516,"not-equal":517,"greater-equal":518,always:519}});function sc(i,t,e,r,s,o,n,a){let h=o;if(!a||i.addressModeU!=="repeat"||i.addressModeV!=="repeat"||i.addressModeW!=="repeat"){let c=Hn[n?"clamp-to-edge":i.addressModeU],l=Hn[n?"clamp-to-edge":i.addressModeV],u=Hn[n?"clamp-to-edge":i.addressModeW];t[s](h,t.TEXTURE_WRAP_S,c),t[s](h,t.TEXTURE_WRAP_T,l),t.TEXTURE_WRAP_R&&t[s](h,t.TEXTURE_WRAP_R,u)}if((!a||i.magFilter!=="linear")&&t[s](h,t.TEXTURE_MAG_FILTER,ic[i.magFilter]),e){if(!a||i.mipmapFilter!=="linear"){let c=pb[i.minFilter][i.mipmapFilter];t[s](h,t.TEXTURE_MIN_FILTER,c)}}else t[s](h,t.TEXTURE_MIN_FILTER,ic[i.minFilter]);if(r&&i.maxAnisotropy>1){let c=Math.min(i.maxAnisotropy,t.getParameter(r.MAX_TEXTURE_MAX_ANISOTROPY_EXT));t[s](h,r.TEXTURE_MAX_ANISOTROPY_EXT,c)}i.compare&&t[s](h,t.TEXTURE_COMPARE_FUNC,mb[i.compare])}var xb=f(()=>{gb()});function _b(i){return{r8unorm:i.RED,r8snorm:i.RED,r8uint:i.RED,r8sint:i.RED,r16uint:i.RED,r16sint:i.RED,r16float:i.RED,rg8unorm:i.RG,rg8snorm:i.RG,rg8uint:i.RG,rg8sint:i.RG,r32uint:i.RED,r32sint:i.RED,r32float:i.RED,rg16uint:i.RG,rg16sint:i.RG,rg16float:i.RG,rgba8unorm:i.RGBA,"rgba8unorm-srgb":i.RGBA,rgba8snorm:i.RGBA,rgba8uint:i.RGBA,rgba8sint:i.RGBA,bgra8unorm:i.RGBA,"bgra8unorm-srgb":i.RGBA,rgb9e5ufloat:i.RGB,rgb10a2unorm:i.RGBA,rg11b10ufloat:i.RGB,rg32uint:i.RG,rg32sint:i.RG,rg32float:i.RG,rgba16uint:i.RGBA,rgba16sint:i.RGBA,rgba16float:i.RGBA,rgba32uint:i.RGBA,rgba32sint:i.RGBA,rgba32float:i.RGBA,stencil8:i.STENCIL_INDEX8,depth16unorm:i.DEPTH_COMPONENT,depth24plus:i.DEPTH_COMPONENT,"depth24plus-stencil8":i.DEPTH_STENCIL,depth32float:i.DEPTH_COMPONENT,"depth32float-stencil8":i.DEPTH_STENCIL}}var bb=f(()=>{"use strict"});function yb(i,t){let e={},r=i.RGBA;return i instanceof N.get().getWebGLRenderingContext()?t.srgb&&(e={"rgba8unorm-srgb":t.srgb.SRGB8_ALPHA8_EXT,"bgra8unorm-srgb":t.srgb.SRGB8_ALPHA8_EXT}):(e={"rgba8unorm-srgb":i.SRGB8_ALPHA8,"bgra8unorm-srgb":i.SRGB8_ALPHA8},r=i.RGBA8),{r8unorm:i.R8,r8snorm:i.R8_SNORM,r8uint:i.R8UI,r8sint:i.R8I,r16uint:i.R16UI,r16sint:i.R16I,r16float:i.R16F,rg8unorm:i.RG8,rg8snorm:i.RG8_SNORM,rg8uint:i.RG8UI,rg8sint:i.RG8I,r32uint:i.R32UI,r32sint:i.R32I,r32float:i.R32F,rg16uint:i.RG16UI,rg16sint:i.RG16I,rg16float:i.RG16F,rgba8unorm:i.RGBA,...e,rgba8snorm:i.RGBA8_SNORM,rgba8uint:i.RGBA8UI,rgba8sint:i.RGBA8I,bgra8unorm:r,rgb9e5ufloat:i.RGB9_E5,rgb10a2unorm:i.RGB10_A2,rg11b10ufloat:i.R11F_G11F_B10F,rg32uint:i.RG32UI,rg32sint:i.RG32I,rg32float:i.RG32F,rgba16uint:i.RGBA16UI,rgba16sint:i.RGBA16I,rgba16float:i.RGBA16F,rgba32uint:i.RGBA32UI,rgba32sint:i.RGBA32I,rgba32float:i.RGBA32F,stencil8:i.STENCIL_INDEX8,depth16unorm:i.DEPTH_COMPONENT16,depth24plus:i.DEPTH_COMPONENT24,"depth24plus-stencil8":i.DEPTH24_STENCIL8,depth32float:i.DEPTH_COMPONENT32F,"depth32float-stencil8":i.DEPTH32F_STENCIL8,...t.s3tc?{"bc1-rgba-unorm":t.s3tc.COMPRESSED_RGBA_S3TC_DXT1_EXT,"bc2-rgba-unorm":t.s3tc.COMPRESSED_RGBA_S3TC_DXT3_EXT,"bc3-rgba-unorm":t.s3tc.COMPRESSED_RGBA_S3TC_DXT5_EXT}:{},...t.s3tc_sRGB?{"bc1-rgba-unorm-srgb":t.s3tc_sRGB.COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT,"bc2-rgba-unorm-srgb":t.s3tc_sRGB.COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT,"bc3-rgba-unorm-srgb":t.s3tc_sRGB.COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT}:{},...t.rgtc?{"bc4-r-unorm":t.rgtc.COMPRESSED_RED_RGTC1_EXT,"bc4-r-snorm":t.rgtc.COMPRESSED_SIGNED_RED_RGTC1_EXT,"bc5-rg-unorm":t.rgtc.COMPRESSED_RED_GREEN_RGTC2_EXT,"bc5-rg-snorm":t.rgtc.COMPRESSED_SIGNED_RED_GREEN_RGTC2_EXT}:{},...t.bptc?{"bc6h-rgb-float":t.bptc.COMPRESSED_RGB_BPTC_SIGNED_FLOAT_EXT,"bc6h-rgb-ufloat":t.bptc.COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT_EXT,"bc7-rgba-unorm":t.bptc.COMPRESSED_RGBA_BPTC_UNORM_EXT,"bc7-rgba-unorm-srgb":t.bptc.COMPRESSED_SRGB_ALPHA_BPTC_UNORM_EXT}:{},...t.etc?{"etc2-rgb8unorm":t.etc.COMPRESSED_RGB8_ETC2,"etc2-rgb8unorm-srgb":t.etc.COMPRESSED_SRGB8_ETC2,"etc2-rgb8a1unorm":t.etc.COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2,"etc2-rgb8a1unorm-srgb":t.etc.COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2,"etc2-rgba8unorm":t.etc.COMPRESSED_RGBA8_ETC2_EAC,"etc2-rgba8unorm-srgb":t.etc.COMPRESSED_SRGB8_ALPHA8_ETC2_EAC,"eac-r11unorm":t.etc.COMPRESSED_R11_EAC,"eac-rg11unorm":t.etc.COMPRESSED_SIGNED_RG11_EAC}:{},...t.astc?{"astc-4x4-unorm":t.astc.COMPRESSED_RGBA_ASTC_4x4_KHR,"astc-4x4-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_4x4_KHR,"astc-5x4-unorm":t.astc.COMPRESSED_RGBA_ASTC_5x4_KHR,"astc-5x4-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_5x4_KHR,"astc-5x5-unorm":t.astc.COMPRESSED_RGBA_ASTC_5x5_KHR,"astc-5x5-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_5x5_KHR,"astc-6x5-unorm":t.astc.COMPRESSED_RGBA_ASTC_6x5_KHR,"astc-6x5-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_6x5_KHR,"astc-6x6-unorm":t.astc.COMPRESSED_RGBA_ASTC_6x6_KHR,"astc-6x6-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_6x6_KHR,"astc-8x5-unorm":t.astc.COMPRESSED_RGBA_ASTC_8x5_KHR,"astc-8x5-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_8x5_KHR,"astc-8x6-unorm":t.astc.COMPRESSED_RGBA_ASTC_8x6_KHR,"astc-8x6-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_8x6_KHR,"astc-8x8-unorm":t.astc.COMPRESSED_RGBA_ASTC_8x8_KHR,"astc-8x8-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_8x8_KHR,"astc-10x5-unorm":t.astc.COMPRESSED_RGBA_ASTC_10x5_KHR,"astc-10x5-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_10x5_KHR,"astc-10x6-unorm":t.astc.COMPRESSED_RGBA_ASTC_10x6_KHR,"astc-10x6-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_10x6_KHR,"astc-10x8-unorm":t.astc.COMPRESSED_RGBA_ASTC_10x8_KHR,"astc-10x8-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_10x8_KHR,"astc-10x10-unorm":t.astc.COMPRESSED_RGBA_ASTC_10x10_KHR,"astc-10x10-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_10x10_KHR,"astc-12x10-unorm":t.astc.COMPRESSED_RGBA_ASTC_12x10_KHR,"astc-12x10-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_12x10_KHR,"astc-12x12-unorm":t.astc.COMPRESSED_RGBA_ASTC_12x12_KHR,"astc-12x12-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_12x12_KHR}:{}}}var vb=f(()=>{pt()});function Tb(i){return{r8unorm:i.UNSIGNED_BYTE,r8snorm:i.BYTE,r8uint:i.UNSIGNED_BYTE,r8sint:i.BYTE,r16uint:i.UNSIGNED_SHORT,r16sint:i.SHORT,r16float:i.HALF_FLOAT,rg8unorm:i.UNSIGNED_BYTE,rg8snorm:i.BYTE,rg8uint:i.UNSIGNED_BYTE,rg8sint:i.BYTE,r32uint:i.UNSIGNED_INT,r32sint:i.INT,r32float:i.FLOAT,rg16uint:i.UNSIGNED_SHORT,rg16sint:i.SHORT,rg16float:i.HALF_FLOAT,rgba8unorm:i.UNSIGNED_BYTE,"rgba8unorm-srgb":i.UNSIGNED_BYTE,rgba8snorm:i.BYTE,rgba8uint:i.UNSIGNED_BYTE,rgba8sint:i.BYTE,bgra8unorm:i.UNSIGNED_BYTE,"bgra8unorm-srgb":i.UNSIGNED_BYTE,rgb9e5ufloat:i.UNSIGNED_INT_5_9_9_9_REV,rgb10a2unorm:i.UNSIGNED_INT_2_10_10_10_REV,rg11b10ufloat:i.UNSIGNED_INT_10F_11F_11F_REV,rg32uint:i.UNSIGNED_INT,rg32sint:i.INT,rg32float:i.FLOAT,rgba16uint:i.UNSIGNED_SHORT,rgba16sint:i.SHORT,rgba16float:i.HALF_FLOAT,rgba32uint:i.UNSIGNED_INT,rgba32sint:i.INT,rgba32float:i.FLOAT,stencil8:i.UNSIGNED_BYTE,depth16unorm:i.UNSIGNED_SHORT,depth24plus:i.UNSIGNED_INT,"depth24plus-stencil8":i.UNSIGNED_INT_24_8,depth32float:i.FLOAT,"depth32float-stencil8":i.FLOAT_32_UNSIGNED_INT_24_8_REV}}var Sb=f(()=>{"use strict"});var cT,Vs,wb=f(()=>{pt();w();tt();ab();lb();ub();rc();fb();xb();bb();vb();Sb();cT=4,Vs=class{constructor(t){this.managedTextures=[],this._glTextures=Object.create(null),this._glSamplers=Object.create(null),this._boundTextures=[],this._activeTextureLocation=-1,this._boundSamplers=Object.create(null),this._uploads={image:Nn,buffer:hb,video:db,compressed:cb},this._useSeparateSamplers=!1,this._renderer=t}contextChange(t){this._gl=t,this._mapFormatToInternalFormat||(this._mapFormatToInternalFormat=yb(t,this._renderer.context.extensions),this._mapFormatToType=Tb(t),this._mapFormatToFormat=_b(t)),this._glTextures=Object.create(null),this._glSamplers=Object.create(null),this._boundSamplers=Object.create(null);for(let e=0;e<16;e++)this.bind(E.EMPTY,e)}initSource(t){this.bind(t)}bind(t,e=0){let r=t.source;t?(this.bindSource(r,e),this._useSeparateSamplers&&this._bindSampler(r.style,e)):(this.bindSource(null,e),this._useSeparateSamplers&&this._bindSampler(null,e))}bindSource(t,e=0){let r=this._gl;if(t._touched=this._renderer.textureGC.count,this._boundTextures[e]!==t){this._boundTextures[e]=t,this._activateLocation(e),t=t||E.EMPTY.source;let s=this.getGlSource(t);r.bindTexture(s.target,s.texture)}}_bindSampler(t,e=0){let r=this._gl;if(!t){this._boundSamplers[e]=null,r.bindSampler(e,null);return}let s=this._getGlSampler(t);this._boundSamplers[e]!==s&&(this._boundSamplers[e]=s,r.bindSampler(e,s))}unbind(t){let e=t.source,r=this._boundTextures,s=this._gl;for(let o=0;o<r.length;o++)if(r[o]===e){this._activateLocation(o);let n=this.getGlSource(e);s.bindTexture(n.target,null),r[o]=null}}_activateLocation(t){this._activeTextureLocation!==t&&(this._activeTextureLocation=t,this._gl.activeTexture(this._gl.TEXTURE0+t))}_initSource(t){let e=this._gl,r=new Ln(e.createTexture());if(r.type=this._mapFormatToType[t.format],r.internalFormat=this._mapFormatToInternalFormat[t.format],r.format=this._mapFormatToFormat[t.format],t.autoGenerateMipmaps&&(this._renderer.context.supports.nonPowOf2mipmaps||t.isPowerOfTwo)){let s=Math.max(t.width,t.height);t.mipLevelCount=Math.floor(Math.log2(s))+1}return this._glTextures[t.uid]=r,this.managedTextures.includes(t)||(t.on("update",this.onSourceUpdate,this),t.on("resize",this.onSourceUpdate,this),t.on("styleChange",this.onStyleChange,this),t.on("destroy",this.onSourceDestroy,this),t.on("unload",this.onSourceUnload,this),t.on("updateMipmaps",this.onUpdateMipmaps,this),this.managedTextures.push(t)),this.onSourceUpdate(t),this.updateStyle(t,!1),r}onStyleChange(t){this.updateStyle(t,!1)}updateStyle(t,e){let r=this._gl,s=this.getGlSource(t);r.bindTexture(r.TEXTURE_2D,s.texture),this._boundTextures[this._activeTextureLocation]=t,sc(t.style,r,t.mipLevelCount>1,this._renderer.context.extensions.anisotropicFiltering,"texParameteri",r.TEXTURE_2D,!this._renderer.context.supports.nonPowOf2wrapping&&!t.isPowerOfTwo,e)}onSourceUnload(t){let e=this._glTextures[t.uid];e&&(this.unbind(t),this._glTextures[t.uid]=null,this._gl.deleteTexture(e.texture))}onSourceUpdate(t){let e=this._gl,r=this.getGlSource(t);e.bindTexture(e.TEXTURE_2D,r.texture),this._boundTextures[this._activeTextureLocation]=t,this._uploads[t.uploadMethodId]?this._uploads[t.uploadMethodId].upload(t,r,e,this._renderer.context.webGLVersion):e.texImage2D(e.TEXTURE_2D,0,e.RGBA,t.pixelWidth,t.pixelHeight,0,e.RGBA,e.UNSIGNED_BYTE,null),t.autoGenerateMipmaps&&t.mipLevelCount>1&&this.onUpdateMipmaps(t,!1)}onUpdateMipmaps(t,e=!0){e&&this.bindSource(t,0);let r=this.getGlSource(t);this._gl.generateMipmap(r.target)}onSourceDestroy(t){t.off("destroy",this.onSourceDestroy,this),t.off("update",this.onSourceUpdate,this),t.off("resize",this.onSourceUpdate,this),t.off("unload",this.onSourceUnload,this),t.off("styleChange",this.onStyleChange,this),t.off("updateMipmaps",this.onUpdateMipmaps,this),this.managedTextures.splice(this.managedTextures.indexOf(t),1),this.onSourceUnload(t)}_initSampler(t){let e=this._gl,r=this._gl.createSampler();return this._glSamplers[t._resourceId]=r,sc(t,e,this._boundTextures[this._activeTextureLocation].mipLevelCount>1,this._renderer.context.extensions.anisotropicFiltering,"samplerParameteri",r,!1,!0),this._glSamplers[t._resourceId]}_getGlSampler(t){return this._glSamplers[t._resourceId]||this._initSampler(t)}getGlSource(t){return this._glTextures[t.uid]||this._initSource(t)}generateCanvas(t){let{pixels:e,width:r,height:s}=this.getPixels(t),o=N.get().createCanvas();o.width=r,o.height=s;let n=o.getContext("2d");if(n){let a=n.createImageData(r,s);a.data.set(e),n.putImageData(a,0,0)}return o}getPixels(t){let e=t.source.resolution,r=t.frame,s=Math.max(Math.round(r.width*e),1),o=Math.max(Math.round(r.height*e),1),n=new Uint8Array(cT*s*o),a=this._renderer,h=a.renderTarget.getRenderTarget(t),c=a.renderTarget.getGpuRenderTarget(h),l=a.gl;return l.bindFramebuffer(l.FRAMEBUFFER,c.resolveTargetFramebuffer),l.readPixels(Math.round(r.x*e),Math.round(r.y*e),s,o,l.RGBA,l.UNSIGNED_BYTE,n),{pixels:new Uint8ClampedArray(n.buffer),width:s,height:o}}destroy(){this.managedTextures.slice().forEach(t=>this.onSourceDestroy(t)),this.managedTextures=null,this._renderer=null}};Vs.extension={type:[x.WebGLSystem],name:"texture"}});var Mb={};fc(Mb,{WebGLRenderer:()=>oc});var uT,dT,fT,Eb,Pb,Cb,oc,Ab=f(()=>{w();s_();o_();n_();ts();Rl();we();l_();d_();g_();b_();y_();v_();T_();M_();R_();K_();eb();nb();wb();uT=[...vn,Ns,__,u_,Fs,Vs,Hs,Us,Ws,zs,Os,ob,Ls,Ds],dT=[...Tn],fT=[Is,Gs,Rs],Eb=[],Pb=[],Cb=[];I.handleByNamedList(x.WebGLSystem,Eb);I.handleByNamedList(x.WebGLPipes,Pb);I.handleByNamedList(x.WebGLPipesAdaptor,Cb);I.add(...uT,...dT,...fT);oc=class extends Le{constructor(){let t={name:"webgl",type:vt.WEBGL,systems:Eb,renderPipes:Pb,renderPipeAdaptors:Cb};super(t)}}});w();var cg={extension:{type:x.Environment,name:"browser",priority:-1},test:()=>!0,load:async()=>{await Promise.resolve().then(()=>(lg(),kv))}};w();var dg={extension:{type:x.Environment,name:"webworker",priority:0},test:()=>typeof self<"u"&&self.WorkerGlobalScope!==void 0,load:async()=>{await Promise.resolve().then(()=>(ug(),Fv))}};w();Eo();_o();w();w();var dl=[];I.handleByNamedList(x.Environment,dl);async function gg(i){if(i)for(let t=0;t<dl.length;t++){let e=dl[t];if(e.value.test()){await e.value.load();return}}}pt();ts();var fn;function bg(i){return fn!==void 0||(fn=(()=>{let t={stencil:!0,failIfMajorPerformanceCaveat:i??Le.defaultOptions.failIfMajorPerformanceCaveat};try{if(!N.get().getWebGLRenderingContext())return!1;let r=N.get().createCanvas().getContext("webgl",t),s=!!r?.getContextAttributes()?.stencil;if(r){let o=r.getExtension("WEBGL_lose_context");o&&o.loseContext()}return r=null,s}catch{return!1}})()),fn}pt();var pn;async function yg(i={}){return pn!==void 0||(pn=await(async()=>{if(!N.get().getNavigator().gpu)return!1;try{return await(await navigator.gpu.requestAdapter(i)).requestDevice(),!0}catch{return!1}})()),pn}ts();var Bb=["webgl","webgpu","canvas"];async function Rb(i){let t=[];i.preference?(t.push(i.preference),Bb.forEach(o=>{o!==i.preference&&t.push(o)})):t=Bb.slice();let e;await gg(i.manageImports??!0);let r={};for(let o=0;o<t.length;o++){let n=t[o];if(n==="webgpu"&&await yg()){let{WebGPURenderer:a}=await Promise.resolve().then(()=>(i_(),r_));e=a,r={...i,...i.webgpu};break}else if(n==="webgl"&&bg(i.failIfMajorPerformanceCaveat??Le.defaultOptions.failIfMajorPerformanceCaveat)){let{WebGLRenderer:a}=await Promise.resolve().then(()=>(Ab(),Mb));e=a,r={...i,...i.webgl};break}else if(n==="canvas")throw r={...i},new Error("CanvasRenderer is not yet implemented")}if(delete r.webgpu,delete r.webgl,!e)throw new Error("No available renderer for the current environment");let s=new e;return await s.init(r),s}Dt();mt();var Gb=class nc{constructor(...t){this.stage=new V,t[0]!==void 0&&U(D,"Application constructor options are deprecated, please use Application.init() instead.")}async init(t){t={...t},this.renderer=await Rb(t),nc._plugins.forEach(e=>{e.init.call(this,t)})}render(){this.renderer.render({container:this.stage})}get canvas(){return this.renderer.canvas}get view(){return U(D,"Application.view is deprecated, please use Application.canvas instead."),this.renderer.canvas}get screen(){return this.renderer.screen}destroy(t=!1,e=!1){let r=nc._plugins.slice(0);r.reverse(),r.forEach(s=>{s.destroy.call(this)}),this.stage.destroy(e),this.stage=null,this.renderer.destroy(t),this.renderer=null}};Gb._plugins=[];var zn=Gb;I.handleByList(x.Application,zn._plugins);Js();mt();Vt();Dt();var Wn=class extends V{constructor(t,e){let{text:r,resolution:s,style:o,anchor:n,width:a,height:h,roundPixels:c,...l}=t;super({...l}),this.batched=!0,this.resolution=null,this._didTextUpdate=!0,this._roundPixels=0,this._bounds=new it,this._boundsDirty=!0,this._styleClass=e,this.text=r??"",this.style=o,this.resolution=s??null,this.allowChildren=!1,this._anchor=new St({_onUpdate:()=>{this.onViewUpdate()}}),n&&(this.anchor=n),this.roundPixels=c??!1,a&&(this.width=a),h&&(this.height=h)}get anchor(){return this._anchor}set anchor(t){typeof t=="number"?this._anchor.set(t):this._anchor.copyFrom(t)}get roundPixels(){return!!this._roundPixels}set roundPixels(t){this._roundPixels=t?1:0}set text(t){t=t.toString(),this._text!==t&&(this._text=t,this.onViewUpdate())}get text(){return this._text}get style(){return this._style}set style(t){t=t||{},this._style?.off("update",this.onViewUpdate,this),t instanceof this._styleClass?this._style=t:this._style=new this._styleClass(t),this._style.on("update",this.onViewUpdate,this),this.onViewUpdate()}get bounds(){return this._boundsDirty&&(this._updateBounds(),this._boundsDirty=!1),this._bounds}get width(){return Math.abs(this.scale.x)*this.bounds.width}set width(t){this._setWidth(t,this.bounds.width)}get height(){return Math.abs(this.scale.y)*this.bounds.height}set height(t){this._setHeight(t,this.bounds.height)}getSize(t){return t||(t={}),t.width=Math.abs(this.scale.x)*this.bounds.width,t.height=Math.abs(this.scale.y)*this.bounds.height,t}setSize(t,e){let r,s;typeof t!="object"?(r=t,s=e??t):(r=t.width,s=t.height??t.width),r!==void 0&&this._setWidth(r,this.bounds.width),s!==void 0&&this._setHeight(s,this.bounds.height)}addBounds(t){let e=this.bounds;t.addFrame(e.minX,e.minY,e.maxX,e.maxY)}containsPoint(t){let e=this.bounds.maxX,r=this.bounds.maxY,s=-e*this.anchor.x,o=0;return t.x>=s&&t.x<=s+e&&(o=-r*this.anchor.y,t.y>=o&&t.y<=o+r)}onViewUpdate(){if(this._didChangeId+=4096,this._boundsDirty=!0,this.didViewUpdate)return;this.didViewUpdate=!0,this._didTextUpdate=!0;let t=this.renderGroup||this.parentRenderGroup;t&&t.onChildViewUpdate(this)}_getKey(){return`${this.text}:${this._style.styleKey}`}destroy(t=!1){super.destroy(t),this.owner=null,this._bounds=null,this._anchor=null,(typeof t=="boolean"?t:t?.style)&&this._style.destroy(t),this._style=null,this._text=null}};function Ib(i,t){let e=i[0]??{};return(typeof e=="string"||i[1])&&(U(D,`use new ${t}({ text: "hi!", style }) instead`),e={text:e,style:i[1]}),e}Ni();Pr();var Xs=class extends Wn{constructor(...t){let e=Ib(t,"Text");super(e,It),this.renderPipeId="text"}_updateBounds(){let t=this._bounds,e=this._style.padding,r=this._anchor,s=kt.measureText(this._text,this._style),{width:o,height:n}=s;t.minX=-r._x*o-e,t.maxX=t.minX+o+e*2,t.minY=-r._y*n-e,t.maxY=t.minY+n+e*2}};tt();La();Dt();Vh();yo();Pr();At();var pT=Ks(Qa(),1);I.add(cg,dg);var mT="#24478e",gT="#ffffff",xT="#3d7af3",_T="0x002ca9";var kb=class{app;var_signal_rows=[];var_signal_rows_container=new V;timeline_zoom;timeline_viewport_width;timeline_viewport_x;row_height;row_gap;timeline_getter;constructor(t,e,r,s,o,n){this.app=new zn,this.timeline_zoom=t,this.timeline_viewport_width=e,this.timeline_viewport_x=r,this.row_height=s,this.row_gap=o,this.app.stage.addChild(this.var_signal_rows_container),this.timeline_getter=n}async init(t){await this.app.init({background:mT,antialias:!0,resizeTo:t}),t.appendChild(this.app.canvas)}async resize(t,e){this.timeline_viewport_width=t,await this.redraw_all_rows(),this.app.queueResize()}destroy(){let t={removeView:!0},e={children:!0,texture:!0,textureSource:!0,context:!0};this.app.destroy(t,e)}get_timeline_zoom(){return this.timeline_zoom}get_timeline_viewport_width(){return this.timeline_viewport_width}get_timeline_viewport_x(){return this.timeline_viewport_x}async redraw_all_rows(){await Promise.all(this.var_signal_rows.map(async t=>{let e=await this.timeline_getter(t.signal_ref_index,this.timeline_zoom,this.timeline_viewport_width,this.timeline_viewport_x,this.row_height,t.var_format);t.redraw(e)}))}async redraw_row(t){let e=this.var_signal_rows[t];if(typeof e<"u"){let r=await this.timeline_getter(e.signal_ref_index,this.timeline_zoom,this.timeline_viewport_width,this.timeline_viewport_x,this.row_height,e.var_format);e.redraw(r)}}async set_var_format(t,e){let r=this.var_signal_rows[t];typeof r<"u"&&(r.set_var_format(e),this.redraw_row(t))}async zoom_or_pan(t,e,r){if(e)this.timeline_viewport_x+=Math.sign(t)*20;else{let o=r/this.timeline_viewport_width,n=this.timeline_viewport_width*this.timeline_zoom,a=this.timeline_zoom-Math.sign(t)*this.timeline_zoom*.5,h=this.timeline_viewport_width*a;if(h<this.timeline_viewport_width)this.timeline_zoom=1,this.timeline_viewport_x=0;else{let c=h-n;this.timeline_viewport_x+=c*o,this.timeline_zoom=a}}let s=this.timeline_viewport_width*this.timeline_zoom;this.timeline_viewport_x<0?this.timeline_viewport_x=0:this.timeline_viewport_x+this.timeline_viewport_width>s&&(this.timeline_viewport_x=s-this.timeline_viewport_width),this.redraw_all_rows()}remove_var(t){typeof this.var_signal_rows[t]<"u"&&this.var_signal_rows[t].destroy()}push_var(t,e,r){new ac(t,r,e,this.app,this.var_signal_rows,this.var_signal_rows_container,this.row_height,this.row_gap)}pop_var(){this.remove_var(this.var_signal_rows.length-1)}clear_vars(){this.var_signal_rows.slice().reverse().forEach(t=>t.destroy())}request_timeline_redraw(){}},ac=class{signal_ref_index;var_format;timeline;app;owner;index_in_owner;rows_container;row_height;row_gap;row_height_with_gap;row_container=new V;row_container_background;signal_blocks_container=new V;label_style=new It({align:"center",fill:gT,fontSize:16,fontFamily:'"Courier New", monospace'});constructor(t,e,r,s,o,n,a,h){this.signal_ref_index=t,this.var_format=e,this.timeline=r,this.app=s,this.row_height=a,this.row_gap=h,this.row_height_with_gap=a+h,this.index_in_owner=o.length,this.owner=o,this.owner.push(this),this.rows_container=n,this.row_container.y=this.index_in_owner*this.row_height_with_gap,this.rows_container.addChild(this.row_container),this.row_container_background=new re,this.row_container_background.texture=E.WHITE,this.row_container_background.tint=_T,this.row_container_background.height=this.row_height,this.row_container.addChild(this.row_container_background),this.row_container.addChild(this.signal_blocks_container),this.draw()}set_var_format(t){this.var_format=t}redraw(t){this.timeline=t,this.draw()}draw(){this?.app?.screen?.width!==void 0&&(this?.row_container_background?._texture?.orig?.width!==void 0&&(this.row_container_background.width=this.app.screen.width),this.signal_blocks_container.removeChildren(),this.timeline.blocks.forEach(t=>{let e=new V;e.x=t.x,this.signal_blocks_container.addChild(e);let r=2,s=new nr().rect(r/2,0,t.width-r,t.height).fill(xT);if(e.addChild(s),t.label!==void 0){let o=new Xs;o.text=t.label.text,o.style=this.label_style,o.x=t.label.x,o.y=t.label.y,e.addChild(o)}}))}decrement_index(){this.index_in_owner--,this.row_container.y-=this.row_height_with_gap}destroy(){this.owner.splice(this.index_in_owner,1),this.rows_container.removeChildAt(this.index_in_owner),this.row_container.destroy(!0),this.owner.slice(this.index_in_owner).forEach(t=>t.decrement_index())}};export{kb as PixiController};
+`))}var tb=f(()=>{wn();Lt();kl();Q_()});var Ws,eb=f(()=>{w();tb();Ws=class{constructor(t){this._cache={},this._uniformGroupSyncHash={},this._renderer=t,this.gl=null,this._cache={}}contextChange(t){this.gl=t}updateUniformGroup(t,e,r){let s=this._renderer.shader._getProgramData(e);(!t.isStatic||t._dirtyId!==s.uniformDirtyGroups[t.uid])&&(s.uniformDirtyGroups[t.uid]=t._dirtyId,this._getUniformSyncFunction(t,e)(s.uniformData,t.uniforms,this._renderer,r))}_getUniformSyncFunction(t,e){return this._uniformGroupSyncHash[t._signature]?.[e._key]||this._createUniformSyncFunction(t,e)}_createUniformSyncFunction(t,e){let r=this._uniformGroupSyncHash[t._signature]||(this._uniformGroupSyncHash[t._signature]={}),s=this._getSignature(t,e._uniformData,"u");return this._cache[s]||(this._cache[s]=this._generateUniformsSync(t,e._uniformData)),r[e._key]=this._cache[s],r[e._key]}_generateUniformsSync(t,e){return J_(t,e)}_getSignature(t,e,r){let s=t.uniforms,o=[`${r}-`];for(let n in s)o.push(n),e[n]&&o.push(e[n].type);return o.join("-")}destroy(){this._renderer=null,this._cache=null}};Ws.extension={type:[x.WebGLSystem],name:"uniformGroup"}});function rb(i){let t={};return t.normal=[i.ONE,i.ONE_MINUS_SRC_ALPHA],t.add=[i.ONE,i.ONE],t.multiply=[i.DST_COLOR,i.ONE_MINUS_SRC_ALPHA,i.ONE,i.ONE_MINUS_SRC_ALPHA],t.screen=[i.ONE,i.ONE_MINUS_SRC_COLOR,i.ONE,i.ONE_MINUS_SRC_ALPHA],t.none=[0,0],t["normal-npm"]=[i.SRC_ALPHA,i.ONE_MINUS_SRC_ALPHA,i.ONE,i.ONE_MINUS_SRC_ALPHA],t["add-npm"]=[i.SRC_ALPHA,i.ONE,i.ONE,i.ONE],t["screen-npm"]=[i.SRC_ALPHA,i.ONE_MINUS_SRC_COLOR,i.ONE,i.ONE_MINUS_SRC_ALPHA],t.erase=[i.ZERO,i.ONE_MINUS_SRC_ALPHA],t}var ib=f(()=>{"use strict"});var iT,sT,oT,nT,aT,hT,sb,ob,nb=f(()=>{w();oe();ib();iT=0,sT=1,oT=2,nT=3,aT=4,hT=5,sb=class ec{constructor(){this.gl=null,this.stateId=0,this.polygonOffset=0,this.blendMode="none",this._blendEq=!1,this.map=[],this.map[iT]=this.setBlend,this.map[sT]=this.setOffset,this.map[oT]=this.setCullFace,this.map[nT]=this.setDepthTest,this.map[aT]=this.setFrontFace,this.map[hT]=this.setDepthMask,this.checks=[],this.defaultState=bt.for2d()}contextChange(t){this.gl=t,this.blendModesMap=rb(t),this.reset()}set(t){if(t=t||this.defaultState,this.stateId!==t.data){let e=this.stateId^t.data,r=0;for(;e;)e&1&&this.map[r].call(this,!!(t.data&1<<r)),e=e>>1,r++;this.stateId=t.data}for(let e=0;e<this.checks.length;e++)this.checks[e](this,t)}forceState(t){t=t||this.defaultState;for(let e=0;e<this.map.length;e++)this.map[e].call(this,!!(t.data&1<<e));for(let e=0;e<this.checks.length;e++)this.checks[e](this,t);this.stateId=t.data}setBlend(t){this._updateCheck(ec._checkBlendMode,t),this.gl[t?"enable":"disable"](this.gl.BLEND)}setOffset(t){this._updateCheck(ec._checkPolygonOffset,t),this.gl[t?"enable":"disable"](this.gl.POLYGON_OFFSET_FILL)}setDepthTest(t){this.gl[t?"enable":"disable"](this.gl.DEPTH_TEST)}setDepthMask(t){this.gl.depthMask(t)}setCullFace(t){this.gl[t?"enable":"disable"](this.gl.CULL_FACE)}setFrontFace(t){this.gl.frontFace(this.gl[t?"CW":"CCW"])}setBlendMode(t){if(this.blendModesMap[t]||(t="normal"),t===this.blendMode)return;this.blendMode=t;let e=this.blendModesMap[t],r=this.gl;e.length===2?r.blendFunc(e[0],e[1]):r.blendFuncSeparate(e[0],e[1],e[2],e[3]),e.length===6?(this._blendEq=!0,r.blendEquationSeparate(e[4],e[5])):this._blendEq&&(this._blendEq=!1,r.blendEquationSeparate(r.FUNC_ADD,r.FUNC_ADD))}setPolygonOffset(t,e){this.gl.polygonOffset(t,e)}reset(){this.gl.pixelStorei(this.gl.UNPACK_FLIP_Y_WEBGL,!1),this.forceState(this.defaultState),this._blendEq=!0,this.blendMode="",this.setBlendMode("normal")}_updateCheck(t,e){let r=this.checks.indexOf(t);e&&r===-1?this.checks.push(t):!e&&r!==-1&&this.checks.splice(r,1)}static _checkBlendMode(t,e){t.setBlendMode(e.blendMode)}static _checkPolygonOffset(t,e){t.setPolygonOffset(1,e.polygonOffset)}destroy(){this.gl=null,this.checks.length=0}};sb.extension={type:[x.WebGLSystem],name:"state"};ob=sb});var Ln,ab=f(()=>{$l();Ln=class{constructor(t){this.target=Xl.TEXTURE_2D,this.texture=t,this.width=-1,this.height=-1,this.type=K.UNSIGNED_BYTE,this.internalFormat=Gn.RGBA,this.format=Gn.RGBA,this.samplerType=0}}});var hb,lb=f(()=>{"use strict";hb={id:"buffer",upload(i,t,e){t.width===i.width||t.height===i.height?e.texSubImage2D(e.TEXTURE_2D,0,0,0,i.width,i.height,t.format,t.type,i.resource):e.texImage2D(t.target,0,t.internalFormat,i.width,i.height,0,t.format,t.type,i.resource),t.width=i.width,t.height=i.height}}});var lT,cb,ub=f(()=>{"use strict";lT={"bc1-rgba-unorm":!0,"bc1-rgba-unorm-srgb":!0,"bc2-rgba-unorm":!0,"bc2-rgba-unorm-srgb":!0,"bc3-rgba-unorm":!0,"bc3-rgba-unorm-srgb":!0,"bc4-r-unorm":!0,"bc4-r-snorm":!0,"bc5-rg-unorm":!0,"bc5-rg-snorm":!0,"bc6h-rgb-ufloat":!0,"bc6h-rgb-float":!0,"bc7-rgba-unorm":!0,"bc7-rgba-unorm-srgb":!0,"etc2-rgb8unorm":!0,"etc2-rgb8unorm-srgb":!0,"etc2-rgb8a1unorm":!0,"etc2-rgb8a1unorm-srgb":!0,"etc2-rgba8unorm":!0,"etc2-rgba8unorm-srgb":!0,"eac-r11unorm":!0,"eac-r11snorm":!0,"eac-rg11unorm":!0,"eac-rg11snorm":!0,"astc-4x4-unorm":!0,"astc-4x4-unorm-srgb":!0,"astc-5x4-unorm":!0,"astc-5x4-unorm-srgb":!0,"astc-5x5-unorm":!0,"astc-5x5-unorm-srgb":!0,"astc-6x5-unorm":!0,"astc-6x5-unorm-srgb":!0,"astc-6x6-unorm":!0,"astc-6x6-unorm-srgb":!0,"astc-8x5-unorm":!0,"astc-8x5-unorm-srgb":!0,"astc-8x6-unorm":!0,"astc-8x6-unorm-srgb":!0,"astc-8x8-unorm":!0,"astc-8x8-unorm-srgb":!0,"astc-10x5-unorm":!0,"astc-10x5-unorm-srgb":!0,"astc-10x6-unorm":!0,"astc-10x6-unorm-srgb":!0,"astc-10x8-unorm":!0,"astc-10x8-unorm-srgb":!0,"astc-10x10-unorm":!0,"astc-10x10-unorm-srgb":!0,"astc-12x10-unorm":!0,"astc-12x10-unorm-srgb":!0,"astc-12x12-unorm":!0,"astc-12x12-unorm-srgb":!0},cb={id:"compressed",upload(i,t,e){e.pixelStorei(e.UNPACK_ALIGNMENT,4);let r=i.pixelWidth,s=i.pixelHeight,o=!!lT[i.format];for(let n=0;n<i.resource.length;n++){let a=i.resource[n];o?e.compressedTexImage2D(e.TEXTURE_2D,n,t.internalFormat,r,s,0,a):e.texImage2D(e.TEXTURE_2D,n,t.internalFormat,r,s,0,t.format,t.type,a),r=Math.max(r>>1,1),s=Math.max(s>>1,1)}}}});var Nn,rc=f(()=>{"use strict";Nn={id:"image",upload(i,t,e,r){let s=i.alphaMode==="premultiply-alpha-on-upload";e.pixelStorei(e.UNPACK_PREMULTIPLY_ALPHA_WEBGL,s);let o=t.width,n=t.height,a=i.pixelWidth,h=i.pixelHeight,c=i.resourceWidth,l=i.resourceHeight;c<a||l<h?((o!==a||n!==h)&&e.texImage2D(t.target,0,t.internalFormat,a,h,0,t.format,t.type,null),r===2?e.texSubImage2D(e.TEXTURE_2D,0,0,0,c,l,t.format,t.type,i.resource):e.texSubImage2D(t.target,0,0,0,t.format,t.type,i.resource)):o===a&&n===h?e.texSubImage2D(e.TEXTURE_2D,0,0,0,i.width,i.height,t.format,t.type,i.resource):r===2?e.texImage2D(t.target,0,t.internalFormat,a,h,0,t.format,t.type,i.resource):e.texImage2D(t.target,0,t.internalFormat,t.format,t.type,i.resource),t.width=a,t.height=h}}});var db,fb=f(()=>{rc();db={id:"video",upload(i,t,e,r){if(!i.isValid){e.texImage2D(t.target,0,t.internalFormat,1,1,0,t.format,t.type,null);return}Nn.upload(i,t,e,r)}}});var ic,pb,Hn,mb,gb=f(()=>{"use strict";ic={linear:9729,nearest:9728},pb={linear:{linear:9987,nearest:9985},nearest:{linear:9986,nearest:9984}},Hn={"clamp-to-edge":33071,repeat:10497,"mirror-repeat":33648},mb={never:512,less:513,equal:514,"less-equal":515,greater:516,"not-equal":517,"greater-equal":518,always:519}});function sc(i,t,e,r,s,o,n,a){let h=o;if(!a||i.addressModeU!=="repeat"||i.addressModeV!=="repeat"||i.addressModeW!=="repeat"){let c=Hn[n?"clamp-to-edge":i.addressModeU],l=Hn[n?"clamp-to-edge":i.addressModeV],u=Hn[n?"clamp-to-edge":i.addressModeW];t[s](h,t.TEXTURE_WRAP_S,c),t[s](h,t.TEXTURE_WRAP_T,l),t.TEXTURE_WRAP_R&&t[s](h,t.TEXTURE_WRAP_R,u)}if((!a||i.magFilter!=="linear")&&t[s](h,t.TEXTURE_MAG_FILTER,ic[i.magFilter]),e){if(!a||i.mipmapFilter!=="linear"){let c=pb[i.minFilter][i.mipmapFilter];t[s](h,t.TEXTURE_MIN_FILTER,c)}}else t[s](h,t.TEXTURE_MIN_FILTER,ic[i.minFilter]);if(r&&i.maxAnisotropy>1){let c=Math.min(i.maxAnisotropy,t.getParameter(r.MAX_TEXTURE_MAX_ANISOTROPY_EXT));t[s](h,r.TEXTURE_MAX_ANISOTROPY_EXT,c)}i.compare&&t[s](h,t.TEXTURE_COMPARE_FUNC,mb[i.compare])}var xb=f(()=>{gb()});function _b(i){return{r8unorm:i.RED,r8snorm:i.RED,r8uint:i.RED,r8sint:i.RED,r16uint:i.RED,r16sint:i.RED,r16float:i.RED,rg8unorm:i.RG,rg8snorm:i.RG,rg8uint:i.RG,rg8sint:i.RG,r32uint:i.RED,r32sint:i.RED,r32float:i.RED,rg16uint:i.RG,rg16sint:i.RG,rg16float:i.RG,rgba8unorm:i.RGBA,"rgba8unorm-srgb":i.RGBA,rgba8snorm:i.RGBA,rgba8uint:i.RGBA,rgba8sint:i.RGBA,bgra8unorm:i.RGBA,"bgra8unorm-srgb":i.RGBA,rgb9e5ufloat:i.RGB,rgb10a2unorm:i.RGBA,rg11b10ufloat:i.RGB,rg32uint:i.RG,rg32sint:i.RG,rg32float:i.RG,rgba16uint:i.RGBA,rgba16sint:i.RGBA,rgba16float:i.RGBA,rgba32uint:i.RGBA,rgba32sint:i.RGBA,rgba32float:i.RGBA,stencil8:i.STENCIL_INDEX8,depth16unorm:i.DEPTH_COMPONENT,depth24plus:i.DEPTH_COMPONENT,"depth24plus-stencil8":i.DEPTH_STENCIL,depth32float:i.DEPTH_COMPONENT,"depth32float-stencil8":i.DEPTH_STENCIL}}var bb=f(()=>{"use strict"});function yb(i,t){let e={},r=i.RGBA;return i instanceof N.get().getWebGLRenderingContext()?t.srgb&&(e={"rgba8unorm-srgb":t.srgb.SRGB8_ALPHA8_EXT,"bgra8unorm-srgb":t.srgb.SRGB8_ALPHA8_EXT}):(e={"rgba8unorm-srgb":i.SRGB8_ALPHA8,"bgra8unorm-srgb":i.SRGB8_ALPHA8},r=i.RGBA8),{r8unorm:i.R8,r8snorm:i.R8_SNORM,r8uint:i.R8UI,r8sint:i.R8I,r16uint:i.R16UI,r16sint:i.R16I,r16float:i.R16F,rg8unorm:i.RG8,rg8snorm:i.RG8_SNORM,rg8uint:i.RG8UI,rg8sint:i.RG8I,r32uint:i.R32UI,r32sint:i.R32I,r32float:i.R32F,rg16uint:i.RG16UI,rg16sint:i.RG16I,rg16float:i.RG16F,rgba8unorm:i.RGBA,...e,rgba8snorm:i.RGBA8_SNORM,rgba8uint:i.RGBA8UI,rgba8sint:i.RGBA8I,bgra8unorm:r,rgb9e5ufloat:i.RGB9_E5,rgb10a2unorm:i.RGB10_A2,rg11b10ufloat:i.R11F_G11F_B10F,rg32uint:i.RG32UI,rg32sint:i.RG32I,rg32float:i.RG32F,rgba16uint:i.RGBA16UI,rgba16sint:i.RGBA16I,rgba16float:i.RGBA16F,rgba32uint:i.RGBA32UI,rgba32sint:i.RGBA32I,rgba32float:i.RGBA32F,stencil8:i.STENCIL_INDEX8,depth16unorm:i.DEPTH_COMPONENT16,depth24plus:i.DEPTH_COMPONENT24,"depth24plus-stencil8":i.DEPTH24_STENCIL8,depth32float:i.DEPTH_COMPONENT32F,"depth32float-stencil8":i.DEPTH32F_STENCIL8,...t.s3tc?{"bc1-rgba-unorm":t.s3tc.COMPRESSED_RGBA_S3TC_DXT1_EXT,"bc2-rgba-unorm":t.s3tc.COMPRESSED_RGBA_S3TC_DXT3_EXT,"bc3-rgba-unorm":t.s3tc.COMPRESSED_RGBA_S3TC_DXT5_EXT}:{},...t.s3tc_sRGB?{"bc1-rgba-unorm-srgb":t.s3tc_sRGB.COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT,"bc2-rgba-unorm-srgb":t.s3tc_sRGB.COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT,"bc3-rgba-unorm-srgb":t.s3tc_sRGB.COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT}:{},...t.rgtc?{"bc4-r-unorm":t.rgtc.COMPRESSED_RED_RGTC1_EXT,"bc4-r-snorm":t.rgtc.COMPRESSED_SIGNED_RED_RGTC1_EXT,"bc5-rg-unorm":t.rgtc.COMPRESSED_RED_GREEN_RGTC2_EXT,"bc5-rg-snorm":t.rgtc.COMPRESSED_SIGNED_RED_GREEN_RGTC2_EXT}:{},...t.bptc?{"bc6h-rgb-float":t.bptc.COMPRESSED_RGB_BPTC_SIGNED_FLOAT_EXT,"bc6h-rgb-ufloat":t.bptc.COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT_EXT,"bc7-rgba-unorm":t.bptc.COMPRESSED_RGBA_BPTC_UNORM_EXT,"bc7-rgba-unorm-srgb":t.bptc.COMPRESSED_SRGB_ALPHA_BPTC_UNORM_EXT}:{},...t.etc?{"etc2-rgb8unorm":t.etc.COMPRESSED_RGB8_ETC2,"etc2-rgb8unorm-srgb":t.etc.COMPRESSED_SRGB8_ETC2,"etc2-rgb8a1unorm":t.etc.COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2,"etc2-rgb8a1unorm-srgb":t.etc.COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2,"etc2-rgba8unorm":t.etc.COMPRESSED_RGBA8_ETC2_EAC,"etc2-rgba8unorm-srgb":t.etc.COMPRESSED_SRGB8_ALPHA8_ETC2_EAC,"eac-r11unorm":t.etc.COMPRESSED_R11_EAC,"eac-rg11unorm":t.etc.COMPRESSED_SIGNED_RG11_EAC}:{},...t.astc?{"astc-4x4-unorm":t.astc.COMPRESSED_RGBA_ASTC_4x4_KHR,"astc-4x4-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_4x4_KHR,"astc-5x4-unorm":t.astc.COMPRESSED_RGBA_ASTC_5x4_KHR,"astc-5x4-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_5x4_KHR,"astc-5x5-unorm":t.astc.COMPRESSED_RGBA_ASTC_5x5_KHR,"astc-5x5-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_5x5_KHR,"astc-6x5-unorm":t.astc.COMPRESSED_RGBA_ASTC_6x5_KHR,"astc-6x5-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_6x5_KHR,"astc-6x6-unorm":t.astc.COMPRESSED_RGBA_ASTC_6x6_KHR,"astc-6x6-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_6x6_KHR,"astc-8x5-unorm":t.astc.COMPRESSED_RGBA_ASTC_8x5_KHR,"astc-8x5-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_8x5_KHR,"astc-8x6-unorm":t.astc.COMPRESSED_RGBA_ASTC_8x6_KHR,"astc-8x6-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_8x6_KHR,"astc-8x8-unorm":t.astc.COMPRESSED_RGBA_ASTC_8x8_KHR,"astc-8x8-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_8x8_KHR,"astc-10x5-unorm":t.astc.COMPRESSED_RGBA_ASTC_10x5_KHR,"astc-10x5-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_10x5_KHR,"astc-10x6-unorm":t.astc.COMPRESSED_RGBA_ASTC_10x6_KHR,"astc-10x6-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_10x6_KHR,"astc-10x8-unorm":t.astc.COMPRESSED_RGBA_ASTC_10x8_KHR,"astc-10x8-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_10x8_KHR,"astc-10x10-unorm":t.astc.COMPRESSED_RGBA_ASTC_10x10_KHR,"astc-10x10-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_10x10_KHR,"astc-12x10-unorm":t.astc.COMPRESSED_RGBA_ASTC_12x10_KHR,"astc-12x10-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_12x10_KHR,"astc-12x12-unorm":t.astc.COMPRESSED_RGBA_ASTC_12x12_KHR,"astc-12x12-unorm-srgb":t.astc.COMPRESSED_SRGB8_ALPHA8_ASTC_12x12_KHR}:{}}}var vb=f(()=>{pt()});function Tb(i){return{r8unorm:i.UNSIGNED_BYTE,r8snorm:i.BYTE,r8uint:i.UNSIGNED_BYTE,r8sint:i.BYTE,r16uint:i.UNSIGNED_SHORT,r16sint:i.SHORT,r16float:i.HALF_FLOAT,rg8unorm:i.UNSIGNED_BYTE,rg8snorm:i.BYTE,rg8uint:i.UNSIGNED_BYTE,rg8sint:i.BYTE,r32uint:i.UNSIGNED_INT,r32sint:i.INT,r32float:i.FLOAT,rg16uint:i.UNSIGNED_SHORT,rg16sint:i.SHORT,rg16float:i.HALF_FLOAT,rgba8unorm:i.UNSIGNED_BYTE,"rgba8unorm-srgb":i.UNSIGNED_BYTE,rgba8snorm:i.BYTE,rgba8uint:i.UNSIGNED_BYTE,rgba8sint:i.BYTE,bgra8unorm:i.UNSIGNED_BYTE,"bgra8unorm-srgb":i.UNSIGNED_BYTE,rgb9e5ufloat:i.UNSIGNED_INT_5_9_9_9_REV,rgb10a2unorm:i.UNSIGNED_INT_2_10_10_10_REV,rg11b10ufloat:i.UNSIGNED_INT_10F_11F_11F_REV,rg32uint:i.UNSIGNED_INT,rg32sint:i.INT,rg32float:i.FLOAT,rgba16uint:i.UNSIGNED_SHORT,rgba16sint:i.SHORT,rgba16float:i.HALF_FLOAT,rgba32uint:i.UNSIGNED_INT,rgba32sint:i.INT,rgba32float:i.FLOAT,stencil8:i.UNSIGNED_BYTE,depth16unorm:i.UNSIGNED_SHORT,depth24plus:i.UNSIGNED_INT,"depth24plus-stencil8":i.UNSIGNED_INT_24_8,depth32float:i.FLOAT,"depth32float-stencil8":i.FLOAT_32_UNSIGNED_INT_24_8_REV}}var Sb=f(()=>{"use strict"});var cT,Vs,wb=f(()=>{pt();w();tt();ab();lb();ub();rc();fb();xb();bb();vb();Sb();cT=4,Vs=class{constructor(t){this.managedTextures=[],this._glTextures=Object.create(null),this._glSamplers=Object.create(null),this._boundTextures=[],this._activeTextureLocation=-1,this._boundSamplers=Object.create(null),this._uploads={image:Nn,buffer:hb,video:db,compressed:cb},this._useSeparateSamplers=!1,this._renderer=t}contextChange(t){this._gl=t,this._mapFormatToInternalFormat||(this._mapFormatToInternalFormat=yb(t,this._renderer.context.extensions),this._mapFormatToType=Tb(t),this._mapFormatToFormat=_b(t)),this._glTextures=Object.create(null),this._glSamplers=Object.create(null),this._boundSamplers=Object.create(null);for(let e=0;e<16;e++)this.bind(E.EMPTY,e)}initSource(t){this.bind(t)}bind(t,e=0){let r=t.source;t?(this.bindSource(r,e),this._useSeparateSamplers&&this._bindSampler(r.style,e)):(this.bindSource(null,e),this._useSeparateSamplers&&this._bindSampler(null,e))}bindSource(t,e=0){let r=this._gl;if(t._touched=this._renderer.textureGC.count,this._boundTextures[e]!==t){this._boundTextures[e]=t,this._activateLocation(e),t=t||E.EMPTY.source;let s=this.getGlSource(t);r.bindTexture(s.target,s.texture)}}_bindSampler(t,e=0){let r=this._gl;if(!t){this._boundSamplers[e]=null,r.bindSampler(e,null);return}let s=this._getGlSampler(t);this._boundSamplers[e]!==s&&(this._boundSamplers[e]=s,r.bindSampler(e,s))}unbind(t){let e=t.source,r=this._boundTextures,s=this._gl;for(let o=0;o<r.length;o++)if(r[o]===e){this._activateLocation(o);let n=this.getGlSource(e);s.bindTexture(n.target,null),r[o]=null}}_activateLocation(t){this._activeTextureLocation!==t&&(this._activeTextureLocation=t,this._gl.activeTexture(this._gl.TEXTURE0+t))}_initSource(t){let e=this._gl,r=new Ln(e.createTexture());if(r.type=this._mapFormatToType[t.format],r.internalFormat=this._mapFormatToInternalFormat[t.format],r.format=this._mapFormatToFormat[t.format],t.autoGenerateMipmaps&&(this._renderer.context.supports.nonPowOf2mipmaps||t.isPowerOfTwo)){let s=Math.max(t.width,t.height);t.mipLevelCount=Math.floor(Math.log2(s))+1}return this._glTextures[t.uid]=r,this.managedTextures.includes(t)||(t.on("update",this.onSourceUpdate,this),t.on("resize",this.onSourceUpdate,this),t.on("styleChange",this.onStyleChange,this),t.on("destroy",this.onSourceDestroy,this),t.on("unload",this.onSourceUnload,this),t.on("updateMipmaps",this.onUpdateMipmaps,this),this.managedTextures.push(t)),this.onSourceUpdate(t),this.updateStyle(t,!1),r}onStyleChange(t){this.updateStyle(t,!1)}updateStyle(t,e){let r=this._gl,s=this.getGlSource(t);r.bindTexture(r.TEXTURE_2D,s.texture),this._boundTextures[this._activeTextureLocation]=t,sc(t.style,r,t.mipLevelCount>1,this._renderer.context.extensions.anisotropicFiltering,"texParameteri",r.TEXTURE_2D,!this._renderer.context.supports.nonPowOf2wrapping&&!t.isPowerOfTwo,e)}onSourceUnload(t){let e=this._glTextures[t.uid];e&&(this.unbind(t),this._glTextures[t.uid]=null,this._gl.deleteTexture(e.texture))}onSourceUpdate(t){let e=this._gl,r=this.getGlSource(t);e.bindTexture(e.TEXTURE_2D,r.texture),this._boundTextures[this._activeTextureLocation]=t,this._uploads[t.uploadMethodId]?this._uploads[t.uploadMethodId].upload(t,r,e,this._renderer.context.webGLVersion):e.texImage2D(e.TEXTURE_2D,0,e.RGBA,t.pixelWidth,t.pixelHeight,0,e.RGBA,e.UNSIGNED_BYTE,null),t.autoGenerateMipmaps&&t.mipLevelCount>1&&this.onUpdateMipmaps(t,!1)}onUpdateMipmaps(t,e=!0){e&&this.bindSource(t,0);let r=this.getGlSource(t);this._gl.generateMipmap(r.target)}onSourceDestroy(t){t.off("destroy",this.onSourceDestroy,this),t.off("update",this.onSourceUpdate,this),t.off("resize",this.onSourceUpdate,this),t.off("unload",this.onSourceUnload,this),t.off("styleChange",this.onStyleChange,this),t.off("updateMipmaps",this.onUpdateMipmaps,this),this.managedTextures.splice(this.managedTextures.indexOf(t),1),this.onSourceUnload(t)}_initSampler(t){let e=this._gl,r=this._gl.createSampler();return this._glSamplers[t._resourceId]=r,sc(t,e,this._boundTextures[this._activeTextureLocation].mipLevelCount>1,this._renderer.context.extensions.anisotropicFiltering,"samplerParameteri",r,!1,!0),this._glSamplers[t._resourceId]}_getGlSampler(t){return this._glSamplers[t._resourceId]||this._initSampler(t)}getGlSource(t){return this._glTextures[t.uid]||this._initSource(t)}generateCanvas(t){let{pixels:e,width:r,height:s}=this.getPixels(t),o=N.get().createCanvas();o.width=r,o.height=s;let n=o.getContext("2d");if(n){let a=n.createImageData(r,s);a.data.set(e),n.putImageData(a,0,0)}return o}getPixels(t){let e=t.source.resolution,r=t.frame,s=Math.max(Math.round(r.width*e),1),o=Math.max(Math.round(r.height*e),1),n=new Uint8Array(cT*s*o),a=this._renderer,h=a.renderTarget.getRenderTarget(t),c=a.renderTarget.getGpuRenderTarget(h),l=a.gl;return l.bindFramebuffer(l.FRAMEBUFFER,c.resolveTargetFramebuffer),l.readPixels(Math.round(r.x*e),Math.round(r.y*e),s,o,l.RGBA,l.UNSIGNED_BYTE,n),{pixels:new Uint8ClampedArray(n.buffer),width:s,height:o}}destroy(){this.managedTextures.slice().forEach(t=>this.onSourceDestroy(t)),this.managedTextures=null,this._renderer=null}};Vs.extension={type:[x.WebGLSystem],name:"texture"}});var Mb={};fc(Mb,{WebGLRenderer:()=>oc});var uT,dT,fT,Eb,Pb,Cb,oc,Ab=f(()=>{w();s_();o_();n_();ts();Rl();we();l_();d_();g_();b_();y_();v_();T_();M_();R_();K_();eb();nb();wb();uT=[...vn,Ns,__,u_,Fs,Vs,Hs,Us,Ws,zs,Os,ob,Ls,Ds],dT=[...Tn],fT=[Is,Gs,Rs],Eb=[],Pb=[],Cb=[];I.handleByNamedList(x.WebGLSystem,Eb);I.handleByNamedList(x.WebGLPipes,Pb);I.handleByNamedList(x.WebGLPipesAdaptor,Cb);I.add(...uT,...dT,...fT);oc=class extends Le{constructor(){let t={name:"webgl",type:vt.WEBGL,systems:Eb,renderPipes:Pb,renderPipeAdaptors:Cb};super(t)}}});w();var cg={extension:{type:x.Environment,name:"browser",priority:-1},test:()=>!0,load:async()=>{await Promise.resolve().then(()=>(lg(),kv))}};w();var dg={extension:{type:x.Environment,name:"webworker",priority:0},test:()=>typeof self<"u"&&self.WorkerGlobalScope!==void 0,load:async()=>{await Promise.resolve().then(()=>(ug(),Fv))}};w();Eo();_o();w();w();var dl=[];I.handleByNamedList(x.Environment,dl);async function gg(i){if(i)for(let t=0;t<dl.length;t++){let e=dl[t];if(e.value.test()){await e.value.load();return}}}pt();ts();var fn;function bg(i){return fn!==void 0||(fn=(()=>{let t={stencil:!0,failIfMajorPerformanceCaveat:i??Le.defaultOptions.failIfMajorPerformanceCaveat};try{if(!N.get().getWebGLRenderingContext())return!1;let r=N.get().createCanvas().getContext("webgl",t),s=!!r?.getContextAttributes()?.stencil;if(r){let o=r.getExtension("WEBGL_lose_context");o&&o.loseContext()}return r=null,s}catch{return!1}})()),fn}pt();var pn;async function yg(i={}){return pn!==void 0||(pn=await(async()=>{if(!N.get().getNavigator().gpu)return!1;try{return await(await navigator.gpu.requestAdapter(i)).requestDevice(),!0}catch{return!1}})()),pn}ts();var Bb=["webgl","webgpu","canvas"];async function Rb(i){let t=[];i.preference?(t.push(i.preference),Bb.forEach(o=>{o!==i.preference&&t.push(o)})):t=Bb.slice();let e;await gg(i.manageImports??!0);let r={};for(let o=0;o<t.length;o++){let n=t[o];if(n==="webgpu"&&await yg()){let{WebGPURenderer:a}=await Promise.resolve().then(()=>(i_(),r_));e=a,r={...i,...i.webgpu};break}else if(n==="webgl"&&bg(i.failIfMajorPerformanceCaveat??Le.defaultOptions.failIfMajorPerformanceCaveat)){let{WebGLRenderer:a}=await Promise.resolve().then(()=>(Ab(),Mb));e=a,r={...i,...i.webgl};break}else if(n==="canvas")throw r={...i},new Error("CanvasRenderer is not yet implemented")}if(delete r.webgpu,delete r.webgl,!e)throw new Error("No available renderer for the current environment");let s=new e;return await s.init(r),s}Dt();mt();var Gb=class nc{constructor(...t){this.stage=new V,t[0]!==void 0&&U(D,"Application constructor options are deprecated, please use Application.init() instead.")}async init(t){t={...t},this.renderer=await Rb(t),nc._plugins.forEach(e=>{e.init.call(this,t)})}render(){this.renderer.render({container:this.stage})}get canvas(){return this.renderer.canvas}get view(){return U(D,"Application.view is deprecated, please use Application.canvas instead."),this.renderer.canvas}get screen(){return this.renderer.screen}destroy(t=!1,e=!1){let r=nc._plugins.slice(0);r.reverse(),r.forEach(s=>{s.destroy.call(this)}),this.stage.destroy(e),this.stage=null,this.renderer.destroy(t),this.renderer=null}};Gb._plugins=[];var zn=Gb;I.handleByList(x.Application,zn._plugins);Js();mt();Vt();Dt();var Wn=class extends V{constructor(t,e){let{text:r,resolution:s,style:o,anchor:n,width:a,height:h,roundPixels:c,...l}=t;super({...l}),this.batched=!0,this.resolution=null,this._didTextUpdate=!0,this._roundPixels=0,this._bounds=new it,this._boundsDirty=!0,this._styleClass=e,this.text=r??"",this.style=o,this.resolution=s??null,this.allowChildren=!1,this._anchor=new St({_onUpdate:()=>{this.onViewUpdate()}}),n&&(this.anchor=n),this.roundPixels=c??!1,a&&(this.width=a),h&&(this.height=h)}get anchor(){return this._anchor}set anchor(t){typeof t=="number"?this._anchor.set(t):this._anchor.copyFrom(t)}get roundPixels(){return!!this._roundPixels}set roundPixels(t){this._roundPixels=t?1:0}set text(t){t=t.toString(),this._text!==t&&(this._text=t,this.onViewUpdate())}get text(){return this._text}get style(){return this._style}set style(t){t=t||{},this._style?.off("update",this.onViewUpdate,this),t instanceof this._styleClass?this._style=t:this._style=new this._styleClass(t),this._style.on("update",this.onViewUpdate,this),this.onViewUpdate()}get bounds(){return this._boundsDirty&&(this._updateBounds(),this._boundsDirty=!1),this._bounds}get width(){return Math.abs(this.scale.x)*this.bounds.width}set width(t){this._setWidth(t,this.bounds.width)}get height(){return Math.abs(this.scale.y)*this.bounds.height}set height(t){this._setHeight(t,this.bounds.height)}getSize(t){return t||(t={}),t.width=Math.abs(this.scale.x)*this.bounds.width,t.height=Math.abs(this.scale.y)*this.bounds.height,t}setSize(t,e){let r,s;typeof t!="object"?(r=t,s=e??t):(r=t.width,s=t.height??t.width),r!==void 0&&this._setWidth(r,this.bounds.width),s!==void 0&&this._setHeight(s,this.bounds.height)}addBounds(t){let e=this.bounds;t.addFrame(e.minX,e.minY,e.maxX,e.maxY)}containsPoint(t){let e=this.bounds.maxX,r=this.bounds.maxY,s=-e*this.anchor.x,o=0;return t.x>=s&&t.x<=s+e&&(o=-r*this.anchor.y,t.y>=o&&t.y<=o+r)}onViewUpdate(){if(this._didChangeId+=4096,this._boundsDirty=!0,this.didViewUpdate)return;this.didViewUpdate=!0,this._didTextUpdate=!0;let t=this.renderGroup||this.parentRenderGroup;t&&t.onChildViewUpdate(this)}_getKey(){return`${this.text}:${this._style.styleKey}`}destroy(t=!1){super.destroy(t),this.owner=null,this._bounds=null,this._anchor=null,(typeof t=="boolean"?t:t?.style)&&this._style.destroy(t),this._style=null,this._text=null}};function Ib(i,t){let e=i[0]??{};return(typeof e=="string"||i[1])&&(U(D,`use new ${t}({ text: "hi!", style }) instead`),e={text:e,style:i[1]}),e}Ni();Pr();var Xs=class extends Wn{constructor(...t){let e=Ib(t,"Text");super(e,It),this.renderPipeId="text"}_updateBounds(){let t=this._bounds,e=this._style.padding,r=this._anchor,s=kt.measureText(this._text,this._style),{width:o,height:n}=s;t.minX=-r._x*o-e,t.maxX=t.minX+o+e*2,t.minY=-r._y*n-e,t.maxY=t.minY+n+e*2}};tt();La();Dt();Vh();yo();Pr();At();var pT=Ks(Qa(),1);I.add(cg,dg);
+const Application = zn, Text = Xs, Graphics = nr, Container = V, TextStyle = It, Sprite = re, Texture = E;
+
+const color_dark_slate_blue = '#24478e'
+
+const color_white = '#ffffff'
+
+const color_slate_blue = '#3d7af3'
+
+const color_cursor = '#ffd700'
+
+const color_marker = '#ff8c00'
+
+const color_analog_line = '#7fffd4'
+
+const color_busy = '#8fa8db'
+
+const color_bit_line = '#7fffd4'
+
+const color_bit_unknown = '#ff3333'
+
+const color_bit_high_impedance = '#ffd27f'
+
+const color_tooltip_background = '#000000'
+
+const color_annotation = '#6a5acd'
+
+const color_diff_mismatch = '#ff3333'
+
+const color_dark_violet_with_x = '0x002ca9'
+
+export class PixiController {
+    app
+
+    var_signal_rows = [];
+    var_signal_rows_container = new Container();
+    timeline_ruler;
+    annotation_rows_drawing;
+    diff_mismatches_drawing;
+    time_markers;
+
+    timeline_zoom;
+    timeline_viewport_width;
+    timeline_viewport_x;
+    row_height;
+    row_gap;
+    ruler_height;
+    timeline_getter;
+    ruler_getter;
+    on_cursor_change;
+    last_time = 0;
+    cursor = undefined;
+    markers = [];
+    annotation_rows = [];
+    diff_mismatches = [];
+
+    constructor(
+        timeline_zoom,
+        timeline_viewport_width,
+        timeline_viewport_x,
+        row_height,
+        row_gap,
+        ruler_height,
+        timeline_getter,
+        ruler_getter,
+        on_cursor_change,
+    ) {
+        this.app = new Application();
+
+        this.timeline_zoom = timeline_zoom;
+        this.timeline_viewport_width = timeline_viewport_width;
+        this.timeline_viewport_x = timeline_viewport_x;
+        this.row_height = row_height;
+        this.row_gap = row_gap;
+        this.ruler_height = ruler_height;
+        this.timeline_ruler = new TimelineRulerDrawing(this.app, ruler_height);
+        this.app.stage.addChild(this.timeline_ruler.container);
+        this.var_signal_rows_container.y = ruler_height + row_gap;
+        this.app.stage.addChild(this.var_signal_rows_container);
+        this.annotation_rows_drawing = new AnnotationRowsDrawing(this.app, row_height, row_gap);
+        this.app.stage.addChild(this.annotation_rows_drawing.container);
+        this.diff_mismatches_drawing = new DiffMismatchesDrawing(this.app, ruler_height);
+        this.app.stage.addChild(this.diff_mismatches_drawing.container);
+        this.time_markers = new TimeMarkersDrawing(this.app, ruler_height);
+        this.app.stage.addChild(this.time_markers.container);
+        this.timeline_getter = timeline_getter;
+        this.ruler_getter = ruler_getter;
+        this.on_cursor_change = on_cursor_change;
+    }
+
+    async init(parent_element) {
+        await this.app.init({ background: color_dark_slate_blue, antialias: true, resizeTo: parent_element });
+        parent_element.appendChild(this.app.canvas);
+
+        this.app.stage.eventMode = 'static';
+        this.app.stage.hitArea = this.app.screen;
+        this.app.stage.on('pointerdown', event => {
+            if (event.button === 0 && this.last_time > 0) {
+                this.on_cursor_change(this.x_to_time(event.global.x));
+            }
+        });
+    }
+
+    async resize(width, _height) {
+
+        this.timeline_viewport_width = width;
+        await this.redraw_all_rows();
+
+        this.app.queueResize();
+    }
+
+    destroy() {
+        const rendererDestroyOptions = {
+            removeView: true
+        }
+        const options = {
+            children: true,
+            texture: true,
+            textureSource: true,
+            context: true,
+        }
+        this.app.destroy(rendererDestroyOptions, options);
+    }
+
+    get_timeline_zoom() {
+        return this.timeline_zoom;
+    }
+
+    get_timeline_viewport_width() {
+        return this.timeline_viewport_width;
+    }
+
+    get_timeline_viewport_x() {
+        return this.timeline_viewport_x;
+    }
+
+    async redraw_ruler() {
+        const ruler = await this.ruler_getter(
+            this.timeline_zoom,
+            this.timeline_viewport_width,
+            this.timeline_viewport_x,
+        );
+        this.timeline_ruler.redraw(ruler);
+        this.last_time = ruler.last_time;
+        this.redraw_annotation_rows();
+        this.redraw_diff_mismatches();
+        this.redraw_time_markers();
+    }
+
+    time_to_x(time) {
+        const timeline_width = this.timeline_viewport_width * this.timeline_zoom;
+        return time / this.last_time * timeline_width - this.timeline_viewport_x;
+    }
+
+    x_to_time(x) {
+        const timeline_width = this.timeline_viewport_width * this.timeline_zoom;
+        const time = (x + this.timeline_viewport_x) / timeline_width * this.last_time;
+        return Math.min(Math.max(time, 0), this.last_time);
+    }
+
+    set_cursor(time) {
+        this.cursor = time;
+        this.redraw_time_markers();
+    }
+
+    set_markers(markers) {
+        this.markers = markers;
+        this.redraw_time_markers();
+    }
+
+    set_diff_mismatches(diff_mismatches) {
+        this.diff_mismatches = diff_mismatches;
+        this.redraw_diff_mismatches();
+    }
+
+    set_annotation_rows(annotation_rows) {
+        this.annotation_rows = annotation_rows;
+        this.redraw_annotation_rows();
+    }
+
+    redraw_annotation_rows() {
+        const row_height_with_gap = this.row_height + this.row_gap;
+        this.annotation_rows_drawing.container.y =
+            this.ruler_height + this.row_gap + this.var_signal_rows.length * row_height_with_gap;
+        if (this.last_time === 0) {
+            return;
+        }
+        const rows = this.annotation_rows.map(row => ({
+            annotations: row.annotations.map(annotation => ({
+                start_x: this.time_to_x(annotation.start),
+                end_x: this.time_to_x(annotation.end),
+                label: annotation.label,
+                color: annotation.color ?? color_annotation,
+            })),
+        }));
+        this.annotation_rows_drawing.redraw(rows);
+    }
+
+    redraw_diff_mismatches() {
+        if (this.last_time === 0) {
+            return;
+        }
+        const mismatches = this.diff_mismatches.map(mismatch => ({
+            start_x: this.time_to_x(mismatch.start),
+            end_x: this.time_to_x(mismatch.end),
+        }));
+        this.diff_mismatches_drawing.redraw(mismatches);
+    }
+
+    redraw_time_markers() {
+        if (this.last_time === 0) {
+            return;
+        }
+        const cursor_x = this.cursor === undefined ? undefined : this.time_to_x(this.cursor);
+        const markers = this.markers.map(marker => ({ name: marker.name, x: this.time_to_x(marker.time) }));
+        this.time_markers.redraw(cursor_x, markers);
+    }
+
+    async redraw_all_rows() {
+        const ruler_redrawn = this.redraw_ruler();
+        await Promise.all(this.var_signal_rows.map(async row => {
+            const timeline = await this.timeline_getter(
+                row,
+                this.timeline_zoom,
+                this.timeline_viewport_width,
+                this.timeline_viewport_x,
+                this.row_height,
+            );
+            row.redraw(timeline);
+        }))
+        await ruler_redrawn;
+    }
+
+    async redraw_row(index) {
+        const row = this.var_signal_rows[index];
+        if (typeof row !== 'undefined') {
+            const timeline = await this.timeline_getter(
+                row,
+                this.timeline_zoom,
+                this.timeline_viewport_width,
+                this.timeline_viewport_x,
+                this.row_height,
+            );
+            row.redraw(timeline);
+        }
+    }
+
+    async set_var_format(index, var_format) {
+        const row = this.var_signal_rows[index];
+        if (typeof row !== 'undefined') {
+            row.set_var_format(var_format);
+            this.redraw_row(index);
+        }
+    }
+
+    async set_render_mode(index, render_mode) {
+        const row = this.var_signal_rows[index];
+        if (typeof row !== 'undefined') {
+            row.set_render_mode(render_mode);
+            this.redraw_row(index);
+        }
+    }
+
+    async set_var_decoder(index, decoder_name) {
+        const row = this.var_signal_rows[index];
+        if (typeof row !== 'undefined' && row.decoder_name !== decoder_name) {
+            row.set_var_decoder(decoder_name);
+            this.redraw_row(index);
+        }
+    }
+
+    async zoom_or_pan(wheel_delta_y, shift_key, offset_x) {
+        if (shift_key) {
+            this.timeline_viewport_x += Math.sign(wheel_delta_y) * 20;
+        } else {
+            const offset_x_ratio = offset_x / this.timeline_viewport_width;
+            const old_timeline_width = this.timeline_viewport_width * this.timeline_zoom;
+            const new_zoom = this.timeline_zoom - Math.sign(wheel_delta_y) * this.timeline_zoom * 0.5;
+            const new_timeline_width = this.timeline_viewport_width * new_zoom;
+            if (new_timeline_width < this.timeline_viewport_width) {
+                this.timeline_zoom = 1;
+                this.timeline_viewport_x = 0;
+            } else {
+                const timeline_width_difference = new_timeline_width - old_timeline_width;
+                this.timeline_viewport_x += timeline_width_difference * offset_x_ratio;
+                this.timeline_zoom = new_zoom;
+            }
+        }
+        const timeline_width = this.timeline_viewport_width * this.timeline_zoom;
+        if (this.timeline_viewport_x < 0) {
+            this.timeline_viewport_x = 0;
+        } else if (this.timeline_viewport_x + this.timeline_viewport_width > timeline_width) {
+            this.timeline_viewport_x = timeline_width - this.timeline_viewport_width;
+        }
+        this.redraw_all_rows();
+    }
+
+    scroll_to_time(time) {
+        if (this.last_time === 0) {
+            return;
+        }
+        const x = this.time_to_x(time);
+        if (x >= 0 && x <= this.timeline_viewport_width) {
+            return;
+        }
+        const timeline_width = this.timeline_viewport_width * this.timeline_zoom;
+        this.timeline_viewport_x = time / this.last_time * timeline_width - this.timeline_viewport_width / 2;
+        if (this.timeline_viewport_x < 0) {
+            this.timeline_viewport_x = 0;
+        } else if (this.timeline_viewport_x + this.timeline_viewport_width > timeline_width) {
+            this.timeline_viewport_x = timeline_width - this.timeline_viewport_width;
+        }
+        this.redraw_all_rows();
+    }
+
+    remove_var(index) {
+        if (typeof this.var_signal_rows[index] !== 'undefined') {
+            this.var_signal_rows[index].destroy();
+        }
+        this.redraw_annotation_rows();
+    }
+
+    push_var(
+        waveform_id,
+        signal_ref_index,
+        full_var_name,
+        timeline,
+        var_format,
+        render_mode,
+        decoder_name,
+    ) {
+        new VarSignalRow(
+            waveform_id,
+            signal_ref_index,
+            full_var_name,
+            var_format,
+            render_mode,
+            decoder_name,
+            timeline,
+            this.app,
+            this.var_signal_rows,
+            this.var_signal_rows_container,
+            this.row_height,
+            this.row_gap,
+        )
+        this.redraw_annotation_rows();
+    }
+
+    pop_var() {
+        this.remove_var(this.var_signal_rows.length - 1);
+    }
+
+    clear_vars() {
+        this.var_signal_rows.slice().reverse().forEach(row => row.destroy());
+        this.redraw_annotation_rows();
+    }
+
+    request_timeline_redraw() {
+
+    }
+}
+
+class TimelineRulerDrawing {
+    app;
+    ruler = { ticks: [], last_time: 0 };
+    ruler_height;
+    container = new Container();
+    background;
+    ticks_container = new Container();
+
+    ruler_label_style = new TextStyle({
+        fill: color_white,
+        fontSize: 12,
+        fontFamily: '"Courier New", monospace',
+    });
+
+    constructor(app, ruler_height) {
+        this.app = app;
+        this.ruler_height = ruler_height;
+
+        this.background = new Sprite();
+        this.background.texture = Texture.WHITE;
+        this.background.tint = color_dark_violet_with_x;
+        this.background.height = ruler_height;
+        this.container.addChild(this.background);
+
+        this.container.addChild(this.ticks_container);
+    }
+
+    redraw(ruler) {
+        this.ruler = ruler;
+        this.draw();
+    }
+
+    draw() {
+
+        if (this?.app?.screen?.width === undefined) {
+            return;
+        }
+
+        if (this?.background?._texture?.orig?.width !== undefined) {
+            this.background.width = this.app.screen.width;
+        }
+
+        this.ticks_container.removeChildren();
+        const major_tick_height = this.ruler_height / 2;
+        const minor_tick_height = this.ruler_height / 5;
+        const ticks = new Graphics();
+        this.ruler.ticks.forEach(tick => {
+            const tick_height = tick.is_major ? major_tick_height : minor_tick_height;
+            ticks
+                .moveTo(tick.x, this.ruler_height - tick_height)
+                .lineTo(tick.x, this.ruler_height);
+
+            if (tick.label !== undefined) {
+                const label = new Text();
+                label.text = tick.label;
+                label.style = this.ruler_label_style;
+                label.x = tick.x + 3;
+                label.y = 2;
+                this.ticks_container.addChild(label);
+            }
+        });
+        ticks.stroke({ width: 1, color: color_white });
+        this.ticks_container.addChild(ticks);
+    }
+}
+
+class TimeMarkersDrawing {
+    app;
+    ruler_height;
+    container = new Container();
+    marker_label_style = new TextStyle({
+        fill: color_marker,
+        fontSize: 12,
+        fontFamily: '"Courier New", monospace',
+    });
+
+    constructor(app, ruler_height) {
+        this.app = app;
+        this.ruler_height = ruler_height;
+    }
+
+    redraw(cursor_x, markers) {
+
+        if (this?.app?.screen?.height === undefined) {
+            return;
+        }
+        this.container.removeChildren();
+        const height = this.app.screen.height;
+
+        const marker_lines = new Graphics();
+        markers.forEach(marker => {
+            marker_lines.moveTo(marker.x, 0).lineTo(marker.x, height);
+
+            const label = new Text();
+            label.text = marker.name;
+            label.style = this.marker_label_style;
+            label.x = marker.x + 3;
+            label.y = this.ruler_height / 2;
+            this.container.addChild(label);
+        });
+        marker_lines.stroke({ width: 1, color: color_marker });
+        this.container.addChild(marker_lines);
+
+        if (cursor_x !== undefined) {
+            const cursor_line = new Graphics()
+                .moveTo(cursor_x, 0)
+                .lineTo(cursor_x, height)
+                .stroke({ width: 2, color: color_cursor });
+            this.container.addChild(cursor_line);
+        }
+    }
+}
+
+class DiffMismatchesDrawing {
+    app;
+    ruler_height;
+    container = new Container();
+
+    constructor(app, ruler_height) {
+        this.app = app;
+        this.ruler_height = ruler_height;
+    }
+
+    redraw(mismatches) {
+
+        if (this?.app?.screen?.height === undefined) {
+            return;
+        }
+        this.container.removeChildren();
+        const screen_width = this.app.screen.width;
+        const height = this.app.screen.height;
+
+        const mismatch_areas = new Graphics();
+        mismatches.forEach(mismatch => {
+            if (mismatch.end_x < 0 || mismatch.start_x > screen_width) {
+                return;
+            }
+
+            const start_x = Math.max(mismatch.start_x, 0);
+            const end_x = Math.min(mismatch.end_x, screen_width);
+            mismatch_areas.rect(start_x, this.ruler_height, Math.max(end_x - start_x, 1), height - this.ruler_height);
+        });
+        mismatch_areas.fill({ color: color_diff_mismatch, alpha: 0.25 });
+        this.container.addChild(mismatch_areas);
+    }
+}
+
+class AnnotationRowsDrawing {
+    app;
+    row_height;
+    row_gap;
+    container = new Container();
+    label_style = new TextStyle({
+        fill: color_white,
+        fontSize: 14,
+        fontFamily: '"Courier New", monospace',
+    });
+
+    constructor(app, row_height, row_gap) {
+        this.app = app;
+        this.row_height = row_height;
+        this.row_gap = row_gap;
+    }
+
+    redraw(rows) {
+
+        if (this?.app?.screen?.width === undefined) {
+            return;
+        }
+        this.container.removeChildren();
+        const screen_width = this.app.screen.width;
+
+        rows.forEach((row, row_index) => {
+            const row_container = new Container();
+            row_container.y = row_index * (this.row_height + this.row_gap);
+            this.container.addChild(row_container);
+
+            const background = new Sprite();
+            background.texture = Texture.WHITE;
+            background.tint = color_dark_violet_with_x;
+            background.width = screen_width;
+            background.height = this.row_height;
+            row_container.addChild(background);
+
+            row.annotations.forEach(annotation => {
+
+                const start_x = Math.max(annotation.start_x, 0);
+                const end_x = Math.min(annotation.end_x, screen_width);
+                const width = end_x - start_x;
+                if (width < 1) {
+                    return;
+                }
+                const gap_between_annotations = width > 4 ? 2 : 0;
+                const block = new Graphics()
+                    .rect(start_x + gap_between_annotations / 2, 0, width - gap_between_annotations, this.row_height)
+                    .fill(annotation.color);
+                row_container.addChild(block);
+
+                const label = new Text({ text: annotation.label, style: this.label_style });
+                if (label.width + 4 <= width) {
+                    label.x = start_x + (width - label.width) / 2;
+                    label.y = (this.row_height - label.height) / 2;
+                    row_container.addChild(label);
+                } else {
+                    label.destroy();
+                }
+            });
+        });
+    }
+}
+
+class VarSignalRow {
+    waveform_id;
+    signal_ref_index;
+    full_var_name;
+    var_format;
+    render_mode;
+    decoder_name;
+    timeline;
+    app;
+    owner;
+    index_in_owner;
+    rows_container;
+    row_height;
+    row_gap;
+    row_height_with_gap;
+    row_container = new Container();
+    row_container_background;
+    signal_blocks_container = new Container();
+    label_style = new TextStyle({
+        align: "center",
+        fill: color_white,
+        fontSize: 16,
+        fontFamily: '"Courier New", monospace',
+    });
+    analog_label_style = new TextStyle({
+        fill: color_white,
+        fontSize: 10,
+        fontFamily: '"Courier New", monospace',
+    });
+    tooltip_style = new TextStyle({
+        fill: color_white,
+        fontSize: 12,
+        fontFamily: '"Courier New", monospace',
+    });
+
+    constructor(
+        waveform_id,
+        signal_ref_index,
+        full_var_name,
+        var_format,
+        render_mode,
+        decoder_name,
+        timeline,
+        app,
+        owner,
+        rows_container,
+        row_height,
+        row_gap,
+    ) {
+        this.waveform_id = waveform_id;
+        this.signal_ref_index = signal_ref_index;
+        this.full_var_name = full_var_name;
+        this.var_format = var_format;
+        this.render_mode = render_mode;
+        this.decoder_name = decoder_name;
+        this.timeline = timeline;
+        this.app = app;
+
+        this.row_height = row_height;
+        this.row_gap = row_gap;
+        this.row_height_with_gap = row_height + row_gap;
+
+        this.index_in_owner = owner.length;
+        this.owner = owner;
+        this.owner.push(this);
+
+        this.rows_container = rows_container;
+
+        this.row_container.y = this.index_in_owner * this.row_height_with_gap;
+        this.rows_container.addChild(this.row_container);
+
+        this.row_container_background = new Sprite();
+        this.row_container_background.texture = Texture.WHITE;
+        this.row_container_background.tint = color_dark_violet_with_x;
+        this.row_container_background.height = this.row_height;
+        this.row_container.addChild(this.row_container_background);
+
+        this.row_container.addChild(this.signal_blocks_container);
+
+        this.draw();
+    }
+
+    set_var_format(var_format) {
+        this.var_format = var_format;
+    }
+
+    set_render_mode(render_mode) {
+        this.render_mode = render_mode;
+    }
+
+    set_var_decoder(decoder_name) {
+        this.decoder_name = decoder_name;
+    }
+
+    redraw(timeline) {
+        this.timeline = timeline;
+        this.draw();
+    }
+
+    draw() {
+
+        if (this?.app?.screen?.width === undefined) {
+            return;
+        }
+
+        if (this?.row_container_background?._texture?.orig?.width !== undefined) {
+            this.row_container_background.width = this.app.screen.width;
+        }
+
+        this.signal_blocks_container.removeChildren();
+        if (this.timeline.analog !== undefined && this.timeline.analog !== null) {
+            this.draw_analog(this.timeline.analog);
+            return;
+        }
+        if (this.timeline.bit_blocks.length > 0) {
+            this.draw_bits(this.timeline.bit_blocks);
+            return;
+        }
+        this.timeline.blocks.forEach(timeline_block => {
+
+            const signal_block = new Container();
+            signal_block.x = timeline_block.x;
+            this.signal_blocks_container.addChild(signal_block);
+
+            const gap_between_blocks = timeline_block.is_busy ? 0 : 2;
+            const background_color = timeline_block.is_busy ? color_busy : (timeline_block.color ?? color_slate_blue);
+            const background = new Graphics()
+                .rect(gap_between_blocks / 2, 0, timeline_block.width - gap_between_blocks, timeline_block.height)
+                .fill(background_color);
+            signal_block.addChild(background);
+
+            if (timeline_block.is_unknown) {
+                const unknown_area = new Graphics();
+                this.draw_hatch(unknown_area, gap_between_blocks / 2, timeline_block.width - gap_between_blocks / 2, 0, timeline_block.height);
+                unknown_area.stroke({ width: 1, color: color_bit_unknown });
+                signal_block.addChild(unknown_area);
+            }
+
+            if (timeline_block.label !== undefined) {
+                const label = new Text();
+                label.text = timeline_block.label.text;
+                label.style = this.label_style;
+                label.x = timeline_block.label.x;
+                label.y = timeline_block.label.y;
+                signal_block.addChild(label);
+            }
+
+            if (timeline_block.tooltip !== undefined && timeline_block.tooltip !== null) {
+                this.add_tooltip(signal_block, timeline_block.tooltip);
+            }
+        });
+    }
+
+    add_tooltip(signal_block, text) {
+        const tooltip = new Container();
+        const tooltip_text = new Text({ text, style: this.tooltip_style });
+        tooltip_text.x = 4;
+        tooltip_text.y = 2;
+        const tooltip_background = new Graphics()
+            .rect(0, 0, tooltip_text.width + 8, tooltip_text.height + 4)
+            .fill({ color: color_tooltip_background, alpha: 0.8 });
+        tooltip.addChild(tooltip_background, tooltip_text);
+
+        signal_block.eventMode = 'static';
+        signal_block.on('pointerover', () => signal_block.addChild(tooltip));
+        signal_block.on('pointerout', () => signal_block.removeChild(tooltip));
+    }
+
+    draw_analog(analog) {
+        if (analog.columns.length === 0) {
+            return;
+        }
+
+        const y_padding = 4;
+        const y_range = analog.y_max - analog.y_min;
+        const to_y = (value) => {
+            if (y_range === 0) {
+                return this.row_height / 2;
+            }
+            return y_padding + (analog.y_max - value) / y_range * (this.row_height - 2 * y_padding);
+        };
+        const interpolated = this.render_mode === 'AnalogInterpolated';
+
+        const line = new Graphics();
+        let previous_y = undefined;
+        analog.columns.forEach(column => {
+            const first_y = to_y(column.first);
+            if (previous_y === undefined) {
+                line.moveTo(column.x, first_y);
+            } else if (interpolated) {
+                line.lineTo(column.x, first_y);
+            } else {
+                line.lineTo(column.x, previous_y).lineTo(column.x, first_y);
+            }
+
+            if (column.min !== column.max) {
+                line
+                    .moveTo(column.x, to_y(column.max))
+                    .lineTo(column.x, to_y(column.min))
+                    .moveTo(column.x, to_y(column.last));
+            }
+            previous_y = to_y(column.last);
+        });
+        const last_column = analog.columns[analog.columns.length - 1];
+        const end_x = Math.min(analog.end_x, this.app.screen.width);
+        if (previous_y !== undefined && last_column.x < end_x) {
+            line.lineTo(end_x, previous_y);
+        }
+        line.stroke({ width: 1, color: color_analog_line });
+        this.signal_blocks_container.addChild(line);
+
+        const y_max_label = new Text();
+        y_max_label.text = String(analog.y_max);
+        y_max_label.style = this.analog_label_style;
+        y_max_label.x = 2;
+        y_max_label.y = 0;
+        this.signal_blocks_container.addChild(y_max_label);
+
+        const y_min_label = new Text();
+        y_min_label.text = String(analog.y_min);
+        y_min_label.style = this.analog_label_style;
+        y_min_label.x = 2;
+        y_min_label.y = this.row_height - 12;
+        this.signal_blocks_container.addChild(y_min_label);
+    }
+
+    draw_bits(bit_blocks) {
+        const y_padding = 6;
+        const high_y = y_padding;
+        const low_y = this.row_height - y_padding;
+        const middle_y = this.row_height / 2;
+
+        const line = new Graphics();
+        const high_impedance_line = new Graphics();
+        const unknown_area = new Graphics();
+        const busy_area = new Graphics();
+        let previous_y = undefined;
+        bit_blocks.forEach(block => {
+            const block_end_x = block.x + block.width;
+            if (block.is_busy) {
+                busy_area.rect(block.x, high_y, block.width, low_y - high_y);
+
+                const state_y = { 'High': high_y, 'Low': low_y, 'HighImpedance': middle_y, 'Unknown': undefined };
+                previous_y = state_y[block.state];
+                return;
+            }
+            this.draw_bit_block_value(block);
+            switch (block.state) {
+                case 'High':
+                case 'Low': {
+                    const y = block.state === 'High' ? high_y : low_y;
+                    if (previous_y !== undefined && previous_y !== y) {
+                        line.moveTo(block.x, previous_y).lineTo(block.x, y);
+                    }
+                    if (block.color !== undefined && block.color !== null) {
+                        const colored_line = new Graphics()
+                            .moveTo(block.x, y)
+                            .lineTo(block_end_x, y)
+                            .stroke({ width: 2, color: block.color });
+                        this.signal_blocks_container.addChild(colored_line);
+                    } else {
+                        line.moveTo(block.x, y).lineTo(block_end_x, y);
+                    }
+                    previous_y = y;
+                    break;
+                }
+                case 'HighImpedance': {
+                    if (previous_y !== undefined && previous_y !== middle_y) {
+                        line.moveTo(block.x, previous_y).lineTo(block.x, middle_y);
+                    }
+                    high_impedance_line.moveTo(block.x, middle_y).lineTo(block_end_x, middle_y);
+                    previous_y = middle_y;
+                    break;
+                }
+                case 'Unknown': {
+                    unknown_area.rect(block.x, high_y, block.width, low_y - high_y);
+                    this.draw_hatch(unknown_area, block.x, block_end_x, high_y, low_y);
+                    previous_y = undefined;
+                    break;
+                }
+            }
+        });
+        busy_area.fill(color_busy);
+        unknown_area.stroke({ width: 1, color: color_bit_unknown });
+        line.stroke({ width: 2, color: color_bit_line });
+        high_impedance_line.stroke({ width: 2, color: color_bit_high_impedance });
+        this.signal_blocks_container.addChild(busy_area);
+        this.signal_blocks_container.addChild(unknown_area);
+        this.signal_blocks_container.addChild(line);
+        this.signal_blocks_container.addChild(high_impedance_line);
+    }
+
+    draw_bit_block_value(block) {
+        const has_label = block.label !== undefined && block.label !== null;
+        const has_tooltip = block.tooltip !== undefined && block.tooltip !== null;
+        if (!has_label && !has_tooltip) {
+            return;
+        }
+        const value_container = new Container();
+        value_container.x = block.x;
+        this.signal_blocks_container.addChild(value_container);
+        if (has_label) {
+            const label = new Text();
+            label.text = block.label.text;
+            label.style = this.label_style;
+            label.x = block.label.x;
+            label.y = block.label.y;
+            value_container.addChild(label);
+        }
+        if (has_tooltip) {
+
+            const hover_area = new Graphics()
+                .rect(0, 0, block.width, this.row_height)
+                .fill({ color: color_tooltip_background, alpha: 0 });
+            value_container.addChild(hover_area);
+            this.add_tooltip(value_container, block.tooltip);
+        }
+    }
+
+    draw_hatch(graphics, start_x, end_x, top_y, bottom_y) {
+        const hatch_gap = 6;
+        const height = bottom_y - top_y;
+        for (let line_x = start_x - height; line_x < end_x; line_x += hatch_gap) {
+            const start_t = Math.max(0, (start_x - line_x) / height);
+            const end_t = Math.min(1, (end_x - line_x) / height);
+            if (start_t < end_t) {
+                graphics
+                    .moveTo(line_x + start_t * height, bottom_y - start_t * height)
+                    .lineTo(line_x + end_t * height, bottom_y - end_t * height);
+            }
+        }
+    }
+
+    decrement_index() {
+        this.index_in_owner--;
+        this.row_container.y -= this.row_height_with_gap;
+    }
+
+    destroy() {
+        this.owner.splice(this.index_in_owner, 1);
+        this.rows_container.removeChildAt(this.index_in_owner);
+        this.row_container.destroy(true);
+        this.owner.slice(this.index_in_owner).forEach(row => row.decrement_index());
+    }
+}
@@ -0,0 +1,244 @@
+import { transpile } from '@bytecodealliance/jco'
+import * as cli from '@bytecodealliance/preview2-shim/cli'
+import * as clocks from '@bytecodealliance/preview2-shim/clocks'
+import * as filesystem from '@bytecodealliance/preview2-shim/filesystem'
+import * as io from '@bytecodealliance/preview2-shim/io'
+import * as random from '@bytecodealliance/preview2-shim/random'
+
+type DecoderPath = string;
+type DecoderName = string;
+type AddedDecodersCount = number;
+type RemovedDecodersCount = number;
+// Serialized `shared::VarFormat`
+type VarFormat = 'ASCII' | 'Binary' | 'BinaryWithGroups' | 'Hexadecimal' | 'Octal' | 'Signed' | 'Unsigned';
+
+// Deserialized to `shared::DecodedValue`
+type DecodedValue = {
+    text: string,
+    color?: string,
+    tooltip?: string,
+    is_unknown: boolean,
+}
+
+// Exports of the `decoder` interface from `src-tauri/wit/decoder` transpiled by jco
+type DecoderV1Exports = {
+    init: () => void,
+    name: () => string,
+    formatSignalValue: (value: string) => string,
+}
+
+// Exports of the `decoder` interface from `src-tauri/wit/decoder_v2` transpiled by jco
+type DecoderV2Exports = {
+    init: () => void,
+    name: () => string,
+    decodeSignalValue: (value: {
+        varName: string,
+        bitWidth: number,
+        rawBits: string,
+        formattedValue: string,
+        format: string,
+        time: bigint,
+    }) => {
+        label: string,
+        color?: string,
+        tooltip?: string,
+        unknown: boolean,
+    },
+}
+
+// Serialized `shared::LogEntry`
+type LogEntry = {
+    timestamp: number,
+    severity: 'Info' | 'Warning' | 'Error',
+    source: string,
+    message: string,
+}
+
+type Decoder =
+    { version: 1, name: DecoderName, path: DecoderPath, exports: DecoderV1Exports, is_failed: boolean } |
+    { version: 2, name: DecoderName, path: DecoderPath, exports: DecoderV2Exports, is_failed: boolean }
+
+const DECODER_V2_HOST = 'component:decoder-v2/host';
+
+// WASI interfaces are shimmed, components get no env vars and can't access files
+const WASI_SHIM = {
+    '@bytecodealliance/preview2-shim/cli': cli,
+    '@bytecodealliance/preview2-shim/clocks': clocks,
+    '@bytecodealliance/preview2-shim/filesystem': filesystem,
+    '@bytecodealliance/preview2-shim/io': io,
+    '@bytecodealliance/preview2-shim/random': random,
+}
+
+const decoders: Array<Decoder> = [];
+
+// Sync with `shared::MAX_LOG_ENTRIES`
+const MAX_LOG_ENTRIES = 10_000;
+// Sync with `SEND_DELAY` in `src-tauri/src/logs.rs`
+const LOG_ENTRIES_SEND_DELAY_MS = 100;
+
+const log_entries: Array<LogEntry> = [];
+// Defined while a batch waits to be sent
+let pending_log_entries: Array<LogEntry> | undefined;
+let log_entries_listener: ((log_entries: Array<LogEntry>) => void) | undefined;
+
+// Sync with `logs::log` in `src-tauri`
+function log(severity: LogEntry['severity'], source: string, message: string) {
+    if (severity === 'Info') {
+        console.log(`${source}: ${message}`);
+    } else {
+        console.error(`${source}: ${message}`);
+    }
+    const log_entry = { timestamp: Date.now(), severity, source, message };
+    if (log_entries.length === MAX_LOG_ENTRIES) {
+        log_entries.shift();
+    }
+    log_entries.push(log_entry);
+
+    if (pending_log_entries !== undefined) {
+        pending_log_entries.push(log_entry);
+        return
+    }
+    pending_log_entries = [log_entry];
+    setTimeout(() => {
+        const new_log_entries = pending_log_entries ?? [];
+        pending_log_entries = undefined;
+        if (log_entries_listener !== undefined) {
+            log_entries_listener(new_log_entries);
+        }
+    }, LOG_ENTRIES_SEND_DELAY_MS);
+}
+
+export function logs(): Array<LogEntry> {
+    return log_entries.slice()
+}
+
+export function listen_log_entries(on_log_entries: (log_entries: Array<LogEntry>) => void) {
+    log_entries_listener = on_log_entries;
+}
+
+// Sync with `component_manager::decoders::add_decoders` in `src-tauri`
+export async function add_decoders(decoder_paths: Array<DecoderPath>): Promise<AddedDecodersCount> {
+    let added_decoders_count = 0;
+    for (const decoder_path of decoder_paths) {
+        try {
+            const decoder = await instantiate_decoder(decoder_path);
+            log('Info', 'Decoder', `'${decoder.name}' added`);
+            decoders.push(decoder);
+            added_decoders_count += 1;
+        } catch (error) {
+            log('Error', 'Decoder', `Failed to add '${decoder_path}': ${error}`);
+        }
+    }
+    return added_decoders_count
+}
+
+export function remove_all_decoders(): RemovedDecodersCount {
+    return decoders.splice(0).length
+}
+
+export function decoder_names(): Array<DecoderName> {
+    return decoders
+        .filter(decoder => !decoder.is_failed)
+        .map(decoder => decoder.name)
+}
+
+// Returns `undefined` when no such decoder has been added or the decoder has failed,
+// the caller then uses the formatted value unchanged
+export function decode_signal_value(
+    decoder_name: DecoderName,
+    var_name: string,
+    raw_bits: string,
+    formatted_value: string,
+    var_format: VarFormat,
+    time: bigint,
+): DecodedValue | undefined {
+    const decoder = decoders.find(decoder => decoder.name === decoder_name && !decoder.is_failed);
+    if (decoder === undefined) {
+        return undefined
+    }
+    try {
+        if (decoder.version === 1) {
+            return { text: decoder.exports.formatSignalValue(formatted_value), is_unknown: false }
+        }
+        const decoded_value = decoder.exports.decodeSignalValue({
+            varName: var_name,
+            bitWidth: raw_bits.length,
+            rawBits: raw_bits,
+            formattedValue: formatted_value,
+            format: wit_var_format(var_format),
+            time,
+        });
+        return {
+            text: decoded_value.label,
+            color: decoded_value.color,
+            tooltip: decoded_value.tooltip,
+            is_unknown: decoded_value.unknown,
+        }
+    } catch (error) {
+        // A component can't be called again after a trap
+        log('Error', 'Decoder', `'${decoder.name}' disabled: ${error}`);
+        decoder.is_failed = true;
+        return undefined
+    }
+}
+
+async function instantiate_decoder(path: DecoderPath): Promise<Decoder> {
+    const response = await fetch(path);
+    if (!response.ok) {
+        throw new Error(`Failed to fetch '${path}': ${response.status} ${response.statusText}`)
+    }
+    const component_bytes = new Uint8Array(await response.arrayBuffer());
+
+    // `async` instantiation generates a module without imports,
+    // so it can be loaded from a blob and linked with the imports passed below
+    const { files } = await transpile(component_bytes, {
+        name: 'decoder',
+        instantiation: 'async',
+    });
+    const file = (name: string) => {
+        const content = files[name];
+        if (content === undefined) {
+            throw new Error(`Transpiled component file '${name}' not found`)
+        }
+        return content
+    };
+    const module_url = URL.createObjectURL(new Blob([file('decoder.js')], { type: 'text/javascript' }));
+    let transpiled_module;
+    try {
+        transpiled_module = await import(module_url);
+    } finally {
+        URL.revokeObjectURL(module_url);
+    }
+
+    const host = { log: (message: string) => log('Info', 'Decoder', message) };
+    const instance = await transpiled_module.instantiate(
+        (core_module_name: string) => WebAssembly.compile(file(core_module_name)),
+        {
+            ...WASI_SHIM,
+            'component:decoder/host': host,
+            [DECODER_V2_HOST]: host,
+        },
+    );
+
+    // A v2 decoder doesn't have to import the v2 host interface, so the version is detected from the exports
+    if (typeof instance.decoder?.decodeSignalValue === 'function') {
+        const exports: DecoderV2Exports = instance.decoder;
+        exports.init();
+        return { version: 2, name: exports.name(), path, exports, is_failed: false }
+    }
+    const exports: DecoderV1Exports = instance.decoder;
+    exports.init();
+    return { version: 1, name: exports.name(), path, exports, is_failed: false }
+}
+
+function wit_var_format(var_format: VarFormat): string {
+    switch (var_format) {
+        case 'ASCII': return 'ascii'
+        case 'Binary': return 'binary'
+        case 'BinaryWithGroups': return 'binary-with-groups'
+        case 'Hexadecimal': return 'hexadecimal'
+        case 'Octal': return 'octal'
+        case 'Signed': return 'signed'
+        case 'Unsigned': return 'unsigned'
+    }
+}
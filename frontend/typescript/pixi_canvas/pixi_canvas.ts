@@ -9,24 +9,90 @@ const color_white = '#ffffff'   // oklch(100% 3.5594404384177905e-8 105.88)
 // const color_slate_blue = 'SlateBlue'
 const color_slate_blue = '#3d7af3'  // oklch(60.45% 0.194 262.26)
 
+const color_cursor = '#ffd700'
+
+const color_marker = '#ff8c00'
+
+const color_analog_line = '#7fffd4'
+
+const color_busy = '#8fa8db'
+
+const color_bit_line = '#7fffd4'
+
+const color_bit_unknown = '#ff3333'
+
+const color_bit_high_impedance = '#ffd27f'
+
+const color_tooltip_background = '#000000'
+
+const color_annotation = '#6a5acd'
+
+const color_diff_mismatch = '#ff3333'
+
 // const color_dark_violet_with_x = '0x550099'   // oklch(37.6% 0.201 299.56)
 const color_dark_violet_with_x = '0x002ca9'   // oklch(37.6% 0.201 263.53)
 
 // @TODO sync with Rust and `tauri_glue.ts`
 type Timeline = {
-    blocks: Array<TimelineBlock>
+    blocks: Array<TimelineBlock>,
+    bit_blocks: Array<BitBlock>,
+    analog: AnalogTimeline | undefined,
 }
 type TimelineBlock = {
     x: number,
     width: number,
     height: number,
     label: TimeLineBlockLabel | undefined,
+    is_busy: boolean,
+    color: string | undefined,
+    tooltip: string | undefined,
+    is_unknown: boolean,
 }
 type TimeLineBlockLabel = {
     text: string,
     x: number,
     y: number,
 }
+type BitBlock = {
+    x: number,
+    width: number,
+    state: BitState,
+    is_busy: boolean,
+    label: TimeLineBlockLabel | undefined,
+    color: string | undefined,
+    tooltip: string | undefined,
+}
+type BitState = 'Low' | 'High' | 'Unknown' | 'HighImpedance';
+type AnalogTimeline = {
+    columns: Array<AnalogColumn>,
+    y_min: number,
+    y_max: number,
+    end_x: number,
+}
+type AnalogColumn = {
+    x: number,
+    first: number,
+    last: number,
+    min: number,
+    max: number,
+}
+
+// @TODO sync with Rust and `tauri_glue.ts`
+type TimelineRuler = {
+    ticks: Array<TimelineTick>,
+    last_time: number,
+}
+type TimelineTick = {
+    x: number,
+    is_major: boolean,
+    label: string | undefined,
+}
+
+// @TODO sync with Rust
+type Marker = {
+    name: string,
+    time: number,
+}
 
 // @TODO sync with Rust
 enum VarFormat {
@@ -39,27 +105,73 @@ enum VarFormat {
     Unsigned,
 }
 
+// @TODO sync with Rust
+type RenderMode = 'DigitalBus' | 'AnalogStep' | 'AnalogInterpolated';
+
+// @TODO sync with Rust
+type AnnotationRow = {
+    name: string,
+    annotations: Array<Annotation>,
+}
+
+// @TODO sync with Rust
+type Annotation = {
+    start: number,
+    end: number,
+    label: string,
+    color: string | undefined | null,
+}
+
+// @TODO sync with Rust
+type TimeRange = {
+    start: number,
+    end: number,
+}
+
+type DecoderName = string;
+type FullVarName = string;
+type WaveformId = number;
+
 type TimelineGetter = (
-    signal_ref_index: number, 
+    var_signal_row: VarSignalRow,
     timeline_zoom: number, 
     timeline_viewport_width: number, 
     timeline_viewport_x: number, 
     row_height: number, 
-    var_format: VarFormat
 ) => Promise<Timeline>;
 
+type TimelineRulerGetter = (
+    timeline_zoom: number, 
+    timeline_viewport_width: number, 
+    timeline_viewport_x: number, 
+) => Promise<TimelineRuler>;
+
+type CursorChangeHandler = (time: number) => void;
+
 export class PixiController {
     app: Application
     // -- FastWave-specific --
     var_signal_rows: Array<VarSignalRow> = [];
     var_signal_rows_container = new Container();
+    timeline_ruler: TimelineRulerDrawing;
+    annotation_rows_drawing: AnnotationRowsDrawing;
+    diff_mismatches_drawing: DiffMismatchesDrawing;
+    time_markers: TimeMarkersDrawing;
     // @TODO reset `timeline_*` on file unload?
     timeline_zoom: number;
     timeline_viewport_width: number; 
     timeline_viewport_x: number;
     row_height: number;
     row_gap: number;
+    ruler_height: number;
     timeline_getter: TimelineGetter;
+    ruler_getter: TimelineRulerGetter;
+    on_cursor_change: CursorChangeHandler;
+    last_time = 0;
+    cursor: number | undefined = undefined;
+    markers: Array<Marker> = [];
+    annotation_rows: Array<AnnotationRow> = [];
+    diff_mismatches: Array<TimeRange> = [];
 
     constructor(
         timeline_zoom: number,
@@ -67,7 +179,10 @@ export class PixiController {
         timeline_viewport_x: number,
         row_height: number, 
         row_gap: number, 
-        timeline_getter: TimelineGetter
+        ruler_height: number,
+        timeline_getter: TimelineGetter,
+        ruler_getter: TimelineRulerGetter,
+        on_cursor_change: CursorChangeHandler,
     ) {
         this.app = new Application();
         // -- FastWave-specific --
@@ -76,13 +191,33 @@ export class PixiController {
         this.timeline_viewport_x = timeline_viewport_x;
         this.row_height = row_height;
         this.row_gap = row_gap;
+        this.ruler_height = ruler_height;
+        this.timeline_ruler = new TimelineRulerDrawing(this.app, ruler_height);
+        this.app.stage.addChild(this.timeline_ruler.container);
+        this.var_signal_rows_container.y = ruler_height + row_gap;
         this.app.stage.addChild(this.var_signal_rows_container);
+        this.annotation_rows_drawing = new AnnotationRowsDrawing(this.app, row_height, row_gap);
+        this.app.stage.addChild(this.annotation_rows_drawing.container);
+        this.diff_mismatches_drawing = new DiffMismatchesDrawing(this.app, ruler_height);
+        this.app.stage.addChild(this.diff_mismatches_drawing.container);
+        this.time_markers = new TimeMarkersDrawing(this.app, ruler_height);
+        this.app.stage.addChild(this.time_markers.container);
         this.timeline_getter = timeline_getter;
+        this.ruler_getter = ruler_getter;
+        this.on_cursor_change = on_cursor_change;
     }
 
     async init(parent_element: HTMLElement) {
         await this.app.init({ background: color_dark_slate_blue, antialias: true, resizeTo: parent_element });
         parent_element.appendChild(this.app.canvas);
+        // -- FastWave-specific --
+        this.app.stage.eventMode = 'static';
+        this.app.stage.hitArea = this.app.screen;
+        this.app.stage.on('pointerdown', event => {
+            if (event.button === 0 && this.last_time > 0) {
+                this.on_cursor_change(this.x_to_time(event.global.x));
+            }
+        });
     }
 
     // Default automatic Pixi resizing according to the parent is not reliable 
@@ -122,30 +257,113 @@ export class PixiController {
 
     // -- FastWave-specific --
 
+    async redraw_ruler() {
+        const ruler = await this.ruler_getter(
+            this.timeline_zoom,
+            this.timeline_viewport_width,
+            this.timeline_viewport_x,
+        );
+        this.timeline_ruler.redraw(ruler);
+        this.last_time = ruler.last_time;
+        this.redraw_annotation_rows();
+        this.redraw_diff_mismatches();
+        this.redraw_time_markers();
+    }
+
+    time_to_x(time: number): number {
+        const timeline_width = this.timeline_viewport_width * this.timeline_zoom;
+        return time / this.last_time * timeline_width - this.timeline_viewport_x;
+    }
+
+    x_to_time(x: number): number {
+        const timeline_width = this.timeline_viewport_width * this.timeline_zoom;
+        const time = (x + this.timeline_viewport_x) / timeline_width * this.last_time;
+        return Math.min(Math.max(time, 0), this.last_time);
+    }
+
+    set_cursor(time: number | undefined) {
+        this.cursor = time;
+        this.redraw_time_markers();
+    }
+
+    set_markers(markers: Array<Marker>) {
+        this.markers = markers;
+        this.redraw_time_markers();
+    }
+
+    set_diff_mismatches(diff_mismatches: Array<TimeRange>) {
+        this.diff_mismatches = diff_mismatches;
+        this.redraw_diff_mismatches();
+    }
+
+    set_annotation_rows(annotation_rows: Array<AnnotationRow>) {
+        this.annotation_rows = annotation_rows;
+        this.redraw_annotation_rows();
+    }
+
+    // Annotation rows are drawn below all var rows
+    redraw_annotation_rows() {
+        const row_height_with_gap = this.row_height + this.row_gap;
+        this.annotation_rows_drawing.container.y = 
+            this.ruler_height + this.row_gap + this.var_signal_rows.length * row_height_with_gap;
+        if (this.last_time === 0) {
+            return;
+        }
+        const rows = this.annotation_rows.map(row => ({
+            annotations: row.annotations.map(annotation => ({
+                start_x: this.time_to_x(annotation.start),
+                end_x: this.time_to_x(annotation.end),
+                label: annotation.label,
+                color: annotation.color ?? color_annotation,
+            })),
+        }));
+        this.annotation_rows_drawing.redraw(rows);
+    }
+
+    redraw_diff_mismatches() {
+        if (this.last_time === 0) {
+            return;
+        }
+        const mismatches = this.diff_mismatches.map(mismatch => ({
+            start_x: this.time_to_x(mismatch.start),
+            end_x: this.time_to_x(mismatch.end),
+        }));
+        this.diff_mismatches_drawing.redraw(mismatches);
+    }
+
+    redraw_time_markers() {
+        if (this.last_time === 0) {
+            return;
+        }
+        const cursor_x = this.cursor === undefined ? undefined : this.time_to_x(this.cursor);
+        const markers = this.markers.map(marker => ({ name: marker.name, x: this.time_to_x(marker.time) }));
+        this.time_markers.redraw(cursor_x, markers);
+    }
+
     async redraw_all_rows() {
+        const ruler_redrawn = this.redraw_ruler();
         await Promise.all(this.var_signal_rows.map(async row => { 
             const timeline = await this.timeline_getter(
-                row.signal_ref_index, 
+                row,
                 this.timeline_zoom,
                 this.timeline_viewport_width, 
                 this.timeline_viewport_x,
                 this.row_height, 
-                row.var_format
             );
             row.redraw(timeline);
         }))
+        await ruler_redrawn;
     }
 
     async redraw_row(index: number) {
         const row = this.var_signal_rows[index];
         if (typeof row !== 'undefined') {
             const timeline = await this.timeline_getter(
-                row.signal_ref_index, 
+                row,
                 this.timeline_zoom,
                 this.timeline_viewport_width, 
                 this.timeline_viewport_x, 
                 this.row_height, 
-                row.var_format
             );
             row.redraw(timeline);
         }
@@ -159,6 +377,22 @@ export class PixiController {
         }
     }
 
+    async set_render_mode(index: number, render_mode: RenderMode) {
+        const row = this.var_signal_rows[index];
+        if (typeof row !== 'undefined') {
+            row.set_render_mode(render_mode);
+            this.redraw_row(index);
+        }
+    }
+
+    async set_var_decoder(index: number, decoder_name: DecoderName | undefined) {
+        const row = this.var_signal_rows[index];
+        if (typeof row !== 'undefined' && row.decoder_name !== decoder_name) {
+            row.set_var_decoder(decoder_name);
+            this.redraw_row(index);
+        }
+    }
+
     async zoom_or_pan(wheel_delta_y: number, shift_key: boolean, offset_x: number) {
         if (shift_key) {
             this.timeline_viewport_x += Math.sign(wheel_delta_y) * 20;
@@ -185,16 +419,48 @@ export class PixiController {
         this.redraw_all_rows();
     }
 
+    // The viewport is centered on `time` when it's outside of the viewport
+    scroll_to_time(time: number) {
+        if (this.last_time === 0) {
+            return;
+        }
+        const x = this.time_to_x(time);
+        if (x >= 0 && x <= this.timeline_viewport_width) {
+            return;
+        }
+        const timeline_width = this.timeline_viewport_width * this.timeline_zoom;
+        this.timeline_viewport_x = time / this.last_time * timeline_width - this.timeline_viewport_width / 2;
+        if (this.timeline_viewport_x < 0) {
+            this.timeline_viewport_x = 0;
+        } else if (this.timeline_viewport_x + this.timeline_viewport_width > timeline_width) {
+            this.timeline_viewport_x = timeline_width - this.timeline_viewport_width;
+        }
+        this.redraw_all_rows();
+    }
+
     remove_var(index: number) {
         if (typeof this.var_signal_rows[index] !== 'undefined') {
             this.var_signal_rows[index].destroy();
         }
+        this.redraw_annotation_rows();
     }
 
-    push_var(signal_ref_index: number, timeline: Timeline, var_format: VarFormat) {
+    push_var(
+        waveform_id: WaveformId,
+        signal_ref_index: number,
+        full_var_name: FullVarName,
+        timeline: Timeline,
+        var_format: VarFormat,
+        render_mode: RenderMode,
+        decoder_name: DecoderName | undefined,
+    ) {
         new VarSignalRow(
+            waveform_id,
             signal_ref_index,
+            full_var_name,
             var_format,
+            render_mode,
+            decoder_name,
             timeline,
             this.app,
             this.var_signal_rows,
@@ -202,6 +468,7 @@ export class PixiController {
             this.row_height,
             this.row_gap,
         )
+        this.redraw_annotation_rows();
     }
 
     pop_var() {
@@ -210,6 +477,7 @@ export class PixiController {
 
     clear_vars() {
         this.var_signal_rows.slice().reverse().forEach(row => row.destroy());
+        this.redraw_annotation_rows();
     }
 
     request_timeline_redraw() {
@@ -217,9 +485,224 @@ export class PixiController {
     }
 }
 
+class TimelineRulerDrawing {
+    app: Application;
+    ruler: TimelineRuler = { ticks: [], last_time: 0 };
+    ruler_height: number;
+    container = new Container();
+    background: Sprite;
+    ticks_container = new Container();
+    // Sync with `MIN_MAJOR_TICK_SPACING` in `shared/src/timeline_ruler.rs`
+    ruler_label_style = new TextStyle({
+        fill: color_white,
+        fontSize: 12,
+        fontFamily: '"Courier New", monospace',
+    });
+
+    constructor(app: Application, ruler_height: number) {
+        this.app = app;
+        this.ruler_height = ruler_height;
+
+        this.background = new Sprite();
+        this.background.texture = Texture.WHITE;
+        this.background.tint = color_dark_violet_with_x;
+        this.background.height = ruler_height;
+        this.container.addChild(this.background);
+
+        this.container.addChild(this.ticks_container);
+    }
+
+    redraw(ruler: TimelineRuler) {
+        this.ruler = ruler;
+        this.draw();
+    }
+
+    draw() {
+        // Screen can be null when the canvas is being recreated (see `VarSignalRow.draw`)
+        if (this?.app?.screen?.width === undefined) {
+            return;
+        }
+        // Workaround for "TypeError: Cannot read properties of null (reading 'orig')"
+        if (this?.background?._texture?.orig?.width !== undefined) {
+            this.background.width = this.app.screen.width;
+        }
+
+        this.ticks_container.removeChildren();
+        const major_tick_height = this.ruler_height / 2;
+        const minor_tick_height = this.ruler_height / 5;
+        const ticks = new Graphics();
+        this.ruler.ticks.forEach(tick => {
+            const tick_height = tick.is_major ? major_tick_height : minor_tick_height;
+            ticks
+                .moveTo(tick.x, this.ruler_height - tick_height)
+                .lineTo(tick.x, this.ruler_height);
+
+            if (tick.label !== undefined) {
+                const label = new Text();
+                label.text = tick.label;
+                label.style = this.ruler_label_style;
+                label.x = tick.x + 3;
+                label.y = 2;
+                this.ticks_container.addChild(label);
+            }
+        });
+        ticks.stroke({ width: 1, color: color_white });
+        this.ticks_container.addChild(ticks);
+    }
+}
+
+class TimeMarkersDrawing {
+    app: Application;
+    ruler_height: number;
+    container = new Container();
+    marker_label_style = new TextStyle({
+        fill: color_marker,
+        fontSize: 12,
+        fontFamily: '"Courier New", monospace',
+    });
+
+    constructor(app: Application, ruler_height: number) {
+        this.app = app;
+        this.ruler_height = ruler_height;
+    }
+
+    redraw(cursor_x: number | undefined, markers: Array<{ name: string, x: number }>) {
+        // Screen can be null when the canvas is being recreated (see `VarSignalRow.draw`)
+        if (this?.app?.screen?.height === undefined) {
+            return;
+        }
+        this.container.removeChildren();
+        const height = this.app.screen.height;
+
+        const marker_lines = new Graphics();
+        markers.forEach(marker => {
+            marker_lines.moveTo(marker.x, 0).lineTo(marker.x, height);
+
+            const label = new Text();
+            label.text = marker.name;
+            label.style = this.marker_label_style;
+            label.x = marker.x + 3;
+            label.y = this.ruler_height / 2;
+            this.container.addChild(label);
+        });
+        marker_lines.stroke({ width: 1, color: color_marker });
+        this.container.addChild(marker_lines);
+
+        if (cursor_x !== undefined) {
+            const cursor_line = new Graphics()
+                .moveTo(cursor_x, 0)
+                .lineTo(cursor_x, height)
+                .stroke({ width: 2, color: color_cursor });
+            this.container.addChild(cursor_line);
+        }
+    }
+}
+
+class DiffMismatchesDrawing {
+    app: Application;
+    ruler_height: number;
+    container = new Container();
+
+    constructor(app: Application, ruler_height: number) {
+        this.app = app;
+        this.ruler_height = ruler_height;
+    }
+
+    redraw(mismatches: Array<{ start_x: number, end_x: number }>) {
+        // Screen can be null when the canvas is being recreated (see `VarSignalRow.draw`)
+        if (this?.app?.screen?.height === undefined) {
+            return;
+        }
+        this.container.removeChildren();
+        const screen_width = this.app.screen.width;
+        const height = this.app.screen.height;
+
+        const mismatch_areas = new Graphics();
+        mismatches.forEach(mismatch => {
+            if (mismatch.end_x < 0 || mismatch.start_x > screen_width) {
+                return;
+            }
+            // Only the visible part is drawn, short mismatches are at least 1px wide to stay visible
+            const start_x = Math.max(mismatch.start_x, 0);
+            const end_x = Math.min(mismatch.end_x, screen_width);
+            mismatch_areas.rect(start_x, this.ruler_height, Math.max(end_x - start_x, 1), height - this.ruler_height);
+        });
+        mismatch_areas.fill({ color: color_diff_mismatch, alpha: 0.25 });
+        this.container.addChild(mismatch_areas);
+    }
+}
+
+class AnnotationRowsDrawing {
+    app: Application;
+    row_height: number;
+    row_gap: number;
+    container = new Container();
+    label_style = new TextStyle({
+        fill: color_white,
+        fontSize: 14,
+        fontFamily: '"Courier New", monospace',
+    });
+
+    constructor(app: Application, row_height: number, row_gap: number) {
+        this.app = app;
+        this.row_height = row_height;
+        this.row_gap = row_gap;
+    }
+
+    redraw(rows: Array<{ annotations: Array<{ start_x: number, end_x: number, label: string, color: string }> }>) {
+        // Screen can be null when the canvas is being recreated (see `VarSignalRow.draw`)
+        if (this?.app?.screen?.width === undefined) {
+            return;
+        }
+        this.container.removeChildren();
+        const screen_width = this.app.screen.width;
+
+        rows.forEach((row, row_index) => {
+            const row_container = new Container();
+            row_container.y = row_index * (this.row_height + this.row_gap);
+            this.container.addChild(row_container);
+
+            const background = new Sprite();
+            background.texture = Texture.WHITE;
+            background.tint = color_dark_violet_with_x;
+            background.width = screen_width;
+            background.height = this.row_height;
+            row_container.addChild(background);
+
+            row.annotations.forEach(annotation => {
+                // Only the visible part of the annotation is drawn
+                const start_x = Math.max(annotation.start_x, 0);
+                const end_x = Math.min(annotation.end_x, screen_width);
+                const width = end_x - start_x;
+                if (width < 1) {
+                    return;
+                }
+                const gap_between_annotations = width > 4 ? 2 : 0;
+                const block = new Graphics()
+                    .rect(start_x + gap_between_annotations / 2, 0, width - gap_between_annotations, this.row_height)
+                    .fill(annotation.color);
+                row_container.addChild(block);
+
+                const label = new Text({ text: annotation.label, style: this.label_style });
+                if (label.width + 4 <= width) {
+                    label.x = start_x + (width - label.width) / 2;
+                    label.y = (this.row_height - label.height) / 2;
+                    row_container.addChild(label);
+                } else {
+                    label.destroy();
+                }
+            });
+        });
+    }
+}
+
 class VarSignalRow {
+    waveform_id: WaveformId;
     signal_ref_index: number;
+    full_var_name: FullVarName;
     var_format: VarFormat;
+    render_mode: RenderMode;
+    decoder_name: DecoderName | undefined;
     timeline: Timeline;
     app: Application;
     owner: Array<VarSignalRow>;
@@ -237,10 +720,24 @@ class VarSignalRow {
         fontSize: 16,
         fontFamily: '"Courier New", monospace',
     });    
+    analog_label_style = new TextStyle({
+        fill: color_white,
+        fontSize: 10,
+        fontFamily: '"Courier New", monospace',
+    });
+    tooltip_style = new TextStyle({
+        fill: color_white,
+        fontSize: 12,
+        fontFamily: '"Courier New", monospace',
+    });
 
     constructor(
+        waveform_id: WaveformId,
         signal_ref_index: number,
+        full_var_name: FullVarName,
         var_format: VarFormat,
+        render_mode: RenderMode,
+        decoder_name: DecoderName | undefined,
         timeline: Timeline,
         app: Application,
         owner: Array<VarSignalRow>, 
@@ -248,8 +745,12 @@ class VarSignalRow {
         row_height: number,
         row_gap: number,
     ) {
+        this.waveform_id = waveform_id;
         this.signal_ref_index = signal_ref_index;
+        this.full_var_name = full_var_name;
         this.var_format = var_format;
+        this.render_mode = render_mode;
+        this.decoder_name = decoder_name;
         this.timeline = timeline;
         this.app = app;
 
@@ -284,6 +785,14 @@ class VarSignalRow {
         this.var_format = var_format;
     }
 
+    set_render_mode(render_mode: RenderMode) {
+        this.render_mode = render_mode;
+    }
+
+    set_var_decoder(decoder_name: DecoderName | undefined) {
+        this.decoder_name = decoder_name;
+    }
+
     redraw(timeline: Timeline) {
         this.timeline = timeline;
         this.draw();
@@ -302,6 +811,14 @@ class VarSignalRow {
 
         // @TODO optimize by reusing a pool of blocks instead or removing all children on every redraw?
         this.signal_blocks_container.removeChildren();
+        if (this.timeline.analog !== undefined && this.timeline.analog !== null) {
+            this.draw_analog(this.timeline.analog);
+            return;
+        }
+        if (this.timeline.bit_blocks.length > 0) {
+            this.draw_bits(this.timeline.bit_blocks);
+            return;
+        }
         this.timeline.blocks.forEach(timeline_block => {
             // signal_block
             const signal_block = new Container();
@@ -309,12 +826,22 @@ class VarSignalRow {
             this.signal_blocks_container.addChild(signal_block);
 
             // background
-            const gap_between_blocks = 2;
+            // Busy blocks (merged dense changes) are drawn without gaps to form a continuous area
+            const gap_between_blocks = timeline_block.is_busy ? 0 : 2;
+            const background_color = timeline_block.is_busy ? color_busy : (timeline_block.color ?? color_slate_blue);
             const background = new Graphics()
                 .rect(gap_between_blocks / 2, 0, timeline_block.width - gap_between_blocks, timeline_block.height)
-                .fill(color_slate_blue);
+                .fill(background_color);
             signal_block.addChild(background);
 
+            // unknown value (marked by a decoder)
+            if (timeline_block.is_unknown) {
+                const unknown_area = new Graphics();
+                this.draw_hatch(unknown_area, gap_between_blocks / 2, timeline_block.width - gap_between_blocks / 2, 0, timeline_block.height);
+                unknown_area.stroke({ width: 1, color: color_bit_unknown });
+                signal_block.addChild(unknown_area);
+            }
+
             // label
             if (timeline_block.label !== undefined) {
                 const label = new Text();
@@ -324,7 +851,195 @@ class VarSignalRow {
                 label.y = timeline_block.label.y;
                 signal_block.addChild(label);
             }
+
+            // tooltip
+            if (timeline_block.tooltip !== undefined && timeline_block.tooltip !== null) {
+                this.add_tooltip(signal_block, timeline_block.tooltip);
+            }
+        });
+    }
+
+    add_tooltip(signal_block: Container, text: string) {
+        const tooltip = new Container();
+        const tooltip_text = new Text({ text, style: this.tooltip_style });
+        tooltip_text.x = 4;
+        tooltip_text.y = 2;
+        const tooltip_background = new Graphics()
+            .rect(0, 0, tooltip_text.width + 8, tooltip_text.height + 4)
+            .fill({ color: color_tooltip_background, alpha: 0.8 });
+        tooltip.addChild(tooltip_background, tooltip_text);
+
+        signal_block.eventMode = 'static';
+        signal_block.on('pointerover', () => signal_block.addChild(tooltip));
+        signal_block.on('pointerout', () => signal_block.removeChild(tooltip));
+    }
+
+    draw_analog(analog: AnalogTimeline) {
+        if (analog.columns.length === 0) {
+            return;
+        }
+        // Auto-scale values to the row height
+        const y_padding = 4;
+        const y_range = analog.y_max - analog.y_min;
+        const to_y = (value: number) => {
+            if (y_range === 0) {
+                return this.row_height / 2;
+            }
+            return y_padding + (analog.y_max - value) / y_range * (this.row_height - 2 * y_padding);
+        };
+        const interpolated = this.render_mode === 'AnalogInterpolated';
+
+        const line = new Graphics();
+        let previous_y: number | undefined = undefined;
+        analog.columns.forEach(column => {
+            const first_y = to_y(column.first);
+            if (previous_y === undefined) {
+                line.moveTo(column.x, first_y);
+            } else if (interpolated) {
+                line.lineTo(column.x, first_y);
+            } else {
+                line.lineTo(column.x, previous_y).lineTo(column.x, first_y);
+            }
+            // Changes merged into one pixel column are drawn as a vertical min-max line
+            if (column.min !== column.max) {
+                line
+                    .moveTo(column.x, to_y(column.max))
+                    .lineTo(column.x, to_y(column.min))
+                    .moveTo(column.x, to_y(column.last));
+            }
+            previous_y = to_y(column.last);
+        });
+        const last_column = analog.columns[analog.columns.length - 1];
+        const end_x = Math.min(analog.end_x, this.app.screen.width);
+        if (previous_y !== undefined && last_column.x < end_x) {
+            line.lineTo(end_x, previous_y);
+        }
+        line.stroke({ width: 1, color: color_analog_line });
+        this.signal_blocks_container.addChild(line);
+
+        // Y range
+        const y_max_label = new Text();
+        y_max_label.text = String(analog.y_max);
+        y_max_label.style = this.analog_label_style;
+        y_max_label.x = 2;
+        y_max_label.y = 0;
+        this.signal_blocks_container.addChild(y_max_label);
+
+        const y_min_label = new Text();
+        y_min_label.text = String(analog.y_min);
+        y_min_label.style = this.analog_label_style;
+        y_min_label.x = 2;
+        y_min_label.y = this.row_height - 12;
+        this.signal_blocks_container.addChild(y_min_label);
+    }
+
+    draw_bits(bit_blocks: Array<BitBlock>) {
+        const y_padding = 6;
+        const high_y = y_padding;
+        const low_y = this.row_height - y_padding;
+        const middle_y = this.row_height / 2;
+
+        const line = new Graphics();
+        const high_impedance_line = new Graphics();
+        const unknown_area = new Graphics();
+        const busy_area = new Graphics();
+        let previous_y: number | undefined = undefined;
+        bit_blocks.forEach(block => {
+            const block_end_x = block.x + block.width;
+            if (block.is_busy) {
+                busy_area.rect(block.x, high_y, block.width, low_y - high_y);
+                // The line continues with the state of the last merged change
+                const state_y = { 'High': high_y, 'Low': low_y, 'HighImpedance': middle_y, 'Unknown': undefined };
+                previous_y = state_y[block.state];
+                return;
+            }
+            this.draw_bit_block_value(block);
+            switch (block.state) {
+                case 'High':
+                case 'Low': {
+                    const y = block.state === 'High' ? high_y : low_y;
+                    if (previous_y !== undefined && previous_y !== y) {
+                        line.moveTo(block.x, previous_y).lineTo(block.x, y);
+                    }
+                    if (block.color !== undefined && block.color !== null) {
+                        const colored_line = new Graphics()
+                            .moveTo(block.x, y)
+                            .lineTo(block_end_x, y)
+                            .stroke({ width: 2, color: block.color });
+                        this.signal_blocks_container.addChild(colored_line);
+                    } else {
+                        line.moveTo(block.x, y).lineTo(block_end_x, y);
+                    }
+                    previous_y = y;
+                    break;
+                }
+                case 'HighImpedance': {
+                    if (previous_y !== undefined && previous_y !== middle_y) {
+                        line.moveTo(block.x, previous_y).lineTo(block.x, middle_y);
+                    }
+                    high_impedance_line.moveTo(block.x, middle_y).lineTo(block_end_x, middle_y);
+                    previous_y = middle_y;
+                    break;
+                }
+                case 'Unknown': {
+                    unknown_area.rect(block.x, high_y, block.width, low_y - high_y);
+                    this.draw_hatch(unknown_area, block.x, block_end_x, high_y, low_y);
+                    previous_y = undefined;
+                    break;
+                }
+            }
         });
+        busy_area.fill(color_busy);
+        unknown_area.stroke({ width: 1, color: color_bit_unknown });
+        line.stroke({ width: 2, color: color_bit_line });
+        high_impedance_line.stroke({ width: 2, color: color_bit_high_impedance });
+        this.signal_blocks_container.addChild(busy_area);
+        this.signal_blocks_container.addChild(unknown_area);
+        this.signal_blocks_container.addChild(line);
+        this.signal_blocks_container.addChild(high_impedance_line);
+    }
+
+    // Formatted or decoded value of the bit drawn over its line
+    draw_bit_block_value(block: BitBlock) {
+        const has_label = block.label !== undefined && block.label !== null;
+        const has_tooltip = block.tooltip !== undefined && block.tooltip !== null;
+        if (!has_label && !has_tooltip) {
+            return;
+        }
+        const value_container = new Container();
+        value_container.x = block.x;
+        this.signal_blocks_container.addChild(value_container);
+        if (has_label) {
+            const label = new Text();
+            label.text = block.label!.text;
+            label.style = this.label_style;
+            label.x = block.label!.x;
+            label.y = block.label!.y;
+            value_container.addChild(label);
+        }
+        if (has_tooltip) {
+            // Invisible area to hover over
+            const hover_area = new Graphics()
+                .rect(0, 0, block.width, this.row_height)
+                .fill({ color: color_tooltip_background, alpha: 0 });
+            value_container.addChild(hover_area);
+            this.add_tooltip(value_container, block.tooltip!);
+        }
+    }
+
+    // Diagonal lines clipped to the given area
+    draw_hatch(graphics: Graphics, start_x: number, end_x: number, top_y: number, bottom_y: number) {
+        const hatch_gap = 6;
+        const height = bottom_y - top_y;
+        for (let line_x = start_x - height; line_x < end_x; line_x += hatch_gap) {
+            const start_t = Math.max(0, (start_x - line_x) / height);
+            const end_t = Math.min(1, (end_x - line_x) / height);
+            if (start_t < end_t) {
+                graphics
+                    .moveTo(line_x + start_t * height, bottom_y - start_t * height)
+                    .lineTo(line_x + end_t * height, bottom_y - end_t * height);
+            }
+        }
     }
 
     decrement_index() {
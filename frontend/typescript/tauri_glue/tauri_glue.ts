@@ -11,11 +11,19 @@ type FilePath = string;
 type JavascriptCode = string;
 type WellenHierarchy = unknown;
 type Timeline = unknown;
+type TimelineRuler = unknown;
 type VarFormat = unknown;
+type RenderMode = unknown;
+type Time = number;
+type WaveformId = number;
+type WaveformInfo = unknown;
+type VarDiff = unknown;
 
 type AddedDecodersCount = number;
 type RemovedDecodersCount = number;
+type DecodersCacheStats = unknown;
 type DecoderPath = string;
+type DecoderName = string;
 
 type AddedDiagramConnectorsCount = number;
 type RemovedDiagramConnectorsCount = number;
@@ -23,44 +31,134 @@ type DiagramConnectorPath = string;
 type DiagramConnectorName = string;
 type ComponentId = string;
 
+type AddedProtocolDecodersCount = number;
+type RemovedProtocolDecodersCount = number;
+type ProtocolDecoderPath = string;
+type ProtocolDecoderName = string;
+type FullVarName = string;
+type AnnotationRow = unknown;
+
+type ComponentKind = 'Decoder' | 'DiagramConnector' | 'ProtocolDecoder';
+type ComponentIndex = number;
+type ComponentInfo = unknown;
+
+type LogEntry = unknown;
+
 type FileTreeItem = unknown;
 
 export async function show_window(): Promise<void> {
     return await invoke("show_window");
 }
 
-export async function pick_and_load_waveform(): Promise<Filename | undefined> {
+export async function pick_and_load_waveform(): Promise<WaveformInfo | undefined> {
     return await invoke("pick_and_load_waveform");
 }
 
+export async function load_waveform_from_path(path: FilePath): Promise<WaveformInfo> {
+    return await invoke("load_waveform_from_path", { path });
+}
+
+export async function waveform_paths_from_args(): Promise<Array<FilePath>> {
+    return await invoke("waveform_paths_from_args");
+}
+
+export async function recent_files(): Promise<Array<FilePath>> {
+    return await invoke("recent_files");
+}
+
+export async function listen_dropped_waveform_files(on_drop: (path: FilePath) => void) {
+    return await listen("waveform_file_dropped", (path: any) => on_drop(path.payload));
+}
+
+export async function reload_waveform(waveform_id: WaveformId): Promise<Filename> {
+    return await invoke("reload_waveform", { waveform_id });
+}
+
+export async function listen_waveform_file_changes(on_change: (waveform_id: WaveformId) => void) {
+    return await listen("waveform_file_changed", (waveform_id: any) => on_change(waveform_id.payload));
+}
+
+export async function unload_waveform(waveform_id: WaveformId): Promise<void> {
+    return await invoke("unload_waveform", { waveform_id });
+}
+
+export async function cancel_waveform_loading(waveform_id: WaveformId): Promise<void> {
+    return await invoke("cancel_waveform_loading", { waveform_id });
+}
+
+export async function listen_waveform_loading_status(on_status: (status: any) => void) {
+    return await listen("waveform_loading_status", (status) => on_status(status.payload));
+}
+
 export async function load_file_with_selected_vars(): Promise<JavascriptCode | undefined> {
     return await invoke("load_file_with_selected_vars");
 }
 
-export async function get_hierarchy(): Promise<WellenHierarchy> {
-    return await invoke("get_hierarchy");
+export async function get_hierarchy(waveform_id: WaveformId): Promise<WellenHierarchy> {
+    return await invoke("get_hierarchy", { waveform_id });
 }
 
 export async function load_signal_and_get_timeline(
+    waveform_id: WaveformId,
     signal_ref_index: number,
+    var_name: FullVarName,
     timeline_zoom: number,
     timeline_viewport_width: number,
     timeline_viewport_x: number,
     block_height: number,
     var_format: VarFormat,
+    render_mode: RenderMode,
+    decoder_name: DecoderName | undefined,
 ): Promise<Timeline> {
     return await invoke("load_signal_and_get_timeline", {
+        waveform_id,
         signal_ref_index,
+        var_name,
         timeline_zoom,
         timeline_viewport_width,
         timeline_viewport_x,
         block_height,
-        var_format
+        var_format,
+        render_mode,
+        decoder_name
+    });
+}
+
+export async function get_timeline_ruler(
+    timeline_zoom: number,
+    timeline_viewport_width: number,
+    timeline_viewport_x: number,
+): Promise<TimelineRuler> {
+    return await invoke("get_timeline_ruler", {
+        timeline_zoom,
+        timeline_viewport_width,
+        timeline_viewport_x,
     });
 }
 
-export async function unload_signal(signal_ref_index: number): Promise<void> {
-    return await invoke("unload_signal", { signal_ref_index });
+export async function get_value_at_time(
+    waveform_id: WaveformId,
+    signal_ref_index: number,
+    time: Time,
+    var_format: VarFormat,
+): Promise<string | undefined> {
+    return await invoke("get_value_at_time", { waveform_id, signal_ref_index, time, var_format });
+}
+
+export async function get_values_at_time(
+    waveform_id: WaveformId,
+    signal_ref_indices_and_var_formats: Array<[number, VarFormat]>,
+    time: Time,
+): Promise<Array<string | undefined>> {
+    return await invoke("get_values_at_time", { waveform_id, signal_ref_indices_and_var_formats, time });
+}
+
+export async function unload_signal(waveform_id: WaveformId, signal_ref_index: number): Promise<void> {
+    return await invoke("unload_signal", { waveform_id, signal_ref_index });
+}
+
+export async function diff_waveforms(waveform_id_a: WaveformId, waveform_id_b: WaveformId): Promise<Array<VarDiff>> {
+    return await invoke("diff_waveforms", { waveform_id_a, waveform_id_b });
 }
 
 export async function send_char(c : string): Promise<void> {
@@ -75,6 +173,14 @@ export async function remove_all_decoders(): Promise<RemovedDecodersCount> {
     return await invoke("remove_all_decoders");
 }
 
+export async function decoder_names(): Promise<Array<DecoderName>> {
+    return await invoke("decoder_names");
+}
+
+export async function decoders_cache_stats(): Promise<DecodersCacheStats> {
+    return await invoke("decoders_cache_stats");
+}
+
 export async function add_diagram_connectors(diagram_connector_paths: Array<DiagramConnectorPath>): Promise<AddedDiagramConnectorsCount> {
     return await invoke("add_diagram_connectors", { diagram_connector_paths });
 }
@@ -87,6 +193,14 @@ export async function listen_diagram_connectors_messages(on_message: (message: a
     return await listen("diagram_connector_message", (message) => on_message(message.payload));
 }
 
+export async function listen_disabled_components(on_disabled: (disabled_component: any) => void) {
+    return await listen("component_disabled", (disabled_component) => on_disabled(disabled_component.payload));
+}
+
+export async function listen_reloaded_components(on_reloaded: (reloaded_component: any) => void) {
+    return await listen("component_reloaded", (reloaded_component) => on_reloaded(reloaded_component.payload));
+}
+
 export async function listen_term_update(on_message: (message: any) => void) {
     return await listen("term_content", (message) => on_message(message.payload));
 }
@@ -95,7 +209,47 @@ export async function notify_diagram_connector_text_change(diagram_connector: Di
     return await invoke("notify_diagram_connector_text_change", { diagram_connector, component_id, text });
 }
 
-export async function open_konata_file() {
+export async function add_protocol_decoders(protocol_decoder_paths: Array<ProtocolDecoderPath>): Promise<AddedProtocolDecodersCount> {
+    return await invoke("add_protocol_decoders", { protocol_decoder_paths });
+}
+
+export async function remove_all_protocol_decoders(): Promise<RemovedProtocolDecodersCount> {
+    return await invoke("remove_all_protocol_decoders");
+}
+
+export async function decode_protocol(
+    waveform_id: WaveformId,
+    protocol_decoder_name: ProtocolDecoderName,
+    input_var_names: Array<FullVarName>,
+): Promise<Array<AnnotationRow>> {
+    return await invoke("decode_protocol", { waveform_id, protocol_decoder_name, input_var_names });
+}
+
+export async function components(): Promise<Array<ComponentInfo>> {
+    return await invoke("components");
+}
+
+export async function set_component_enabled(kind: ComponentKind, index: ComponentIndex, is_enabled: boolean): Promise<boolean> {
+    return await invoke("set_component_enabled", { kind, index, is_enabled });
+}
+
+export async function remove_component(kind: ComponentKind, index: ComponentIndex): Promise<boolean> {
+    return await invoke("remove_component", { kind, index });
+}
+
+export async function move_component(kind: ComponentKind, old_index: ComponentIndex, new_index: ComponentIndex): Promise<boolean> {
+    return await invoke("move_component", { kind, old_index, new_index });
+}
+
+export async function logs(): Promise<Array<LogEntry>> {
+    return await invoke("logs");
+}
+
+export async function listen_log_entries(on_log_entries: (log_entries: Array<LogEntry>) => void) {
+    return await listen("log_entries", (log_entries: any) => on_log_entries(log_entries.payload));
+}
+
+export async function open_konata_file(): Promise<void> {
     return await invoke("open_konata_file");
 }
 
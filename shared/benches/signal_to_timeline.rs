@@ -0,0 +1,115 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use futures_util::FutureExt;
+use shared::{RenderMode, VarFormat};
+use std::fmt::Write;
+use std::hint::black_box;
+use wellen::GetItem;
+
+const CHANGE_COUNT: u64 = 1_000_000;
+const TIME_STEP: u64 = 10;
+const TIMELINE_ZOOM: f64 = 1000.;
+const TIMELINE_VIEWPORT_WIDTH: u32 = 1920;
+const ROW_HEIGHT: u32 = 40;
+
+/// 32-bit counter incremented every `TIME_STEP`
+fn synthetic_waveform() -> (wellen::simple::Waveform, wellen::SignalRef) {
+    let mut vcd = String::from(
+        "$timescale 1ns $end\n\
+        $scope module top $end\n\
+        $var wire 32 ! counter [31:0] $end\n\
+        $upscope $end\n\
+        $enddefinitions $end\n",
+    );
+    for change in 0..CHANGE_COUNT {
+        writeln!(vcd, "#{}\nb{change:b} !", change * TIME_STEP).unwrap();
+    }
+    writeln!(vcd, "#{}", CHANGE_COUNT * TIME_STEP).unwrap();
+
+    let mut waveform = shared::wellen_helpers::read_from_bytes(vcd.into_bytes()).unwrap();
+    let hierarchy = waveform.hierarchy();
+    let var_ref = hierarchy.lookup_var(&["top"], &"counter").unwrap();
+    let signal_ref = hierarchy.get(var_ref).signal_ref();
+    waveform.load_signals(&[signal_ref]);
+    (waveform, signal_ref)
+}
+
+fn viewport_positions() -> [(&'static str, i32); 3] {
+    let timeline_width = TIMELINE_VIEWPORT_WIDTH as f64 * TIMELINE_ZOOM;
+    let last_viewport_x = timeline_width as i32 - TIMELINE_VIEWPORT_WIDTH as i32;
+    [
+        ("start", 0),
+        ("middle", last_viewport_x / 2),
+        ("end", last_viewport_x),
+    ]
+}
+
+fn bench_signal_to_timeline(criterion: &mut Criterion) {
+    let (waveform, signal_ref) = synthetic_waveform();
+    let signal = waveform.get_signal(signal_ref).unwrap();
+    let time_table = waveform.time_table();
+
+    let mut group = criterion.benchmark_group("signal_to_timeline");
+    for (position, timeline_viewport_x) in viewport_positions() {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(position),
+            &timeline_viewport_x,
+            |bencher, timeline_viewport_x| {
+                bencher.iter(|| {
+                    shared::signal_to_timeline(
+                        signal,
+                        time_table,
+                        TIMELINE_ZOOM,
+                        TIMELINE_VIEWPORT_WIDTH,
+                        black_box(*timeline_viewport_x),
+                        ROW_HEIGHT,
+                        VarFormat::Hexadecimal,
+                        RenderMode::DigitalBus,
+                        |input| Box::pin(async { input.formatted_value.into() }),
+                    )
+                    .now_or_never()
+                    .unwrap()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_changes_in_time_range(criterion: &mut Criterion) {
+    let (waveform, signal_ref) = synthetic_waveform();
+    let signal = waveform.get_signal(signal_ref).unwrap();
+    let time_table = waveform.time_table();
+    let last_time = *time_table.last().unwrap();
+    let range_duration = last_time / TIMELINE_ZOOM as u64;
+
+    let mut group = criterion.benchmark_group("changes_in_time_range");
+    for (position, start_time) in [
+        ("start", 0),
+        ("middle", last_time / 2),
+        ("end", last_time - range_duration),
+    ] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(position),
+            &start_time,
+            |bencher, start_time| {
+                bencher.iter(|| {
+                    shared::iter_changes_in_time_range(
+                        signal,
+                        time_table,
+                        black_box(*start_time),
+                        *start_time + range_duration,
+                    )
+                    .count()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_signal_to_timeline,
+    bench_changes_in_time_range
+);
+criterion_main!(benches);
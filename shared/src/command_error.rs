@@ -0,0 +1,74 @@
+use crate::*;
+use std::fmt;
+
+/// Error returned by platform commands, shown to the user as a notification
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "serde")]
+pub enum CommandError {
+    NoWaveformLoaded,
+    /// The waveform has been unloaded or its loading failed
+    WaveformNotFound {
+        waveform_id: WaveformId,
+    },
+    WaveformLoadingFailed {
+        path: String,
+        reason: String,
+    },
+    /// The signal doesn't exist in the loaded waveform
+    SignalNotFound {
+        signal_ref_index: usize,
+    },
+    FileReadingFailed {
+        path: String,
+        reason: String,
+    },
+    ProtocolDecodingFailed {
+        protocol_decoder_name: String,
+        reason: String,
+    },
+    /// Konata couldn't be started or it didn't open the file
+    KonataFileOpeningFailed {
+        path: String,
+        reason: String,
+    },
+    /// Error not returned by a command, e.g. a failed call to the backend
+    Unexpected {
+        reason: String,
+    },
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoWaveformLoaded => write!(f, "No waveform loaded"),
+            Self::WaveformNotFound { waveform_id } => {
+                write!(f, "Waveform {waveform_id} not found")
+            }
+            Self::WaveformLoadingFailed { path, reason } => {
+                write!(f, "Failed to load waveform '{path}': {reason}")
+            }
+            Self::SignalNotFound { signal_ref_index } => {
+                write!(
+                    f,
+                    "Signal {signal_ref_index} not found in the loaded waveform"
+                )
+            }
+            Self::FileReadingFailed { path, reason } => {
+                write!(f, "Failed to read file '{path}': {reason}")
+            }
+            Self::ProtocolDecodingFailed {
+                protocol_decoder_name,
+                reason,
+            } => write!(
+                f,
+                "Protocol decoder '{protocol_decoder_name}' failed: {reason}"
+            ),
+            Self::KonataFileOpeningFailed { path, reason } => {
+                write!(f, "Failed to open '{path}' in Konata: {reason}")
+            }
+            Self::Unexpected { reason } => write!(f, "Unexpected error: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for CommandError {}
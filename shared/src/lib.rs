@@ -4,16 +4,41 @@ use std::path::PathBuf;
 mod var_format;
 pub use var_format::VarFormat;
 
+mod render_mode;
+pub use render_mode::RenderMode;
+
 mod signal_to_timeline;
-pub use signal_to_timeline::signal_to_timeline;
+pub use signal_to_timeline::{signal_to_timeline, DecoderInput};
+
+mod timeline_ruler;
+use timeline_ruler::timescale_factor_and_exponent;
+pub use timeline_ruler::{format_time, timeline_ruler};
+
+mod time_axis;
+pub use time_axis::TimeAxis;
+
+mod time_range;
+pub use time_range::{changes_in_time_range, iter_changes_in_time_range, last_change_idx_at_time};
+
+mod value_at_time;
+pub use value_at_time::{next_change_time, value_at_time};
+
+mod waveform_diff;
+pub use waveform_diff::{diff_waveforms, TimeRange, VarDiff};
+
+mod command_error;
+pub use command_error::CommandError;
 
 pub mod term;
+pub mod waveform_commands;
 pub mod wellen_helpers;
 
 #[derive(Serialize, Deserialize, Debug, Default)]
 #[serde(crate = "serde")]
 pub struct Timeline {
     pub blocks: Vec<TimelineBlock>,
+    pub bit_blocks: Vec<BitBlock>,
+    pub analog: Option<AnalogTimeline>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Default)]
@@ -23,6 +48,13 @@ pub struct TimelineBlock {
     pub width: u32,
     pub height: u32,
     pub label: Option<TimeLineBlockLabel>,
+    /// Merged changes too dense to be drawn separately
+    pub is_busy: bool,
+    /// CSS color set by a decoder, e.g. `#ff8c00`
+    pub color: Option<String>,
+    pub tooltip: Option<String>,
+    /// Drawn like a value with unknown bits
+    pub is_unknown: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Default)]
@@ -33,6 +65,253 @@ pub struct TimeLineBlockLabel {
     pub y: u32,
 }
 
+/// Part of a single-bit signal with one state
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(crate = "serde")]
+pub struct BitBlock {
+    pub x: i32,
+    pub width: u32,
+    pub state: BitState,
+    /// Merged changes too dense to be drawn separately
+    pub is_busy: bool,
+    /// Value formatted by `VarFormat` and optionally decoded, placed like in `TimelineBlock`
+    pub label: Option<TimeLineBlockLabel>,
+    /// CSS color of the bit line set by a decoder
+    pub color: Option<String>,
+    pub tooltip: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+#[serde(crate = "serde")]
+pub enum BitState {
+    #[default]
+    Low,
+    High,
+    /// `x`, `u`, `w` or `-`
+    Unknown,
+    /// `z`
+    HighImpedance,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(crate = "serde")]
+pub struct AnalogTimeline {
+    pub columns: Vec<AnalogColumn>,
+    pub y_min: f64,
+    pub y_max: f64,
+    /// Where the signal ends, may be outside of the viewport
+    pub end_x: i32,
+}
+
+/// All changes within one pixel column
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy)]
+#[serde(crate = "serde")]
+pub struct AnalogColumn {
+    pub x: i32,
+    pub first: f64,
+    pub last: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(crate = "serde")]
+pub struct TimelineRuler {
+    pub ticks: Vec<TimelineTick>,
+    pub last_time: wellen::Time,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(crate = "serde")]
+pub struct TimelineTick {
+    pub x: i32,
+    pub is_major: bool,
+    pub label: Option<String>,
+}
+
+/// Block label text formatted by `VarFormat` and optionally decoded by a decoder
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(crate = "serde")]
+pub struct DecodedValue {
+    pub text: String,
+    /// CSS color, e.g. `#ff8c00`
+    pub color: Option<String>,
+    pub tooltip: Option<String>,
+    pub is_unknown: bool,
+}
+
+impl From<String> for DecodedValue {
+    fn from(text: String) -> Self {
+        Self {
+            text,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy)]
+#[serde(crate = "serde")]
+pub struct DecodersCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub len: usize,
+    pub capacity: usize,
+}
+
+/// Extra timeline row with transactions decoded by a protocol decoder
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "serde")]
+pub struct AnnotationRow {
+    pub name: String,
+    pub annotations: Vec<Annotation>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "serde")]
+pub struct Annotation {
+    pub start: wellen::Time,
+    pub end: wellen::Time,
+    pub label: String,
+    /// CSS color, e.g. `#ff8c00`
+    pub color: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "serde")]
+pub enum ComponentKind {
+    Decoder,
+    DiagramConnector,
+    ProtocolDecoder,
+}
+
+impl ComponentKind {
+    pub fn as_static_str(&self) -> &'static str {
+        match self {
+            ComponentKind::Decoder => "Decoder",
+            ComponentKind::DiagramConnector => "Diagram Connector",
+            ComponentKind::ProtocolDecoder => "Protocol Decoder",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(crate = "serde")]
+pub enum ComponentStatus {
+    #[default]
+    Enabled,
+    /// Disabled by the user
+    Disabled,
+    /// Disabled by the host after a trap or after running out of fuel
+    Failed { reason: String },
+}
+
+/// Loaded component, `index` is its position among components of the same `kind`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "serde")]
+pub struct ComponentInfo {
+    pub kind: ComponentKind,
+    pub index: usize,
+    pub name: String,
+    pub path: String,
+    pub status: ComponentStatus,
+}
+
+/// Component disabled by the host after a trap or after running out of fuel
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "serde")]
+pub struct DisabledComponent {
+    pub kind: ComponentKind,
+    pub name: String,
+    pub reason: String,
+}
+
+// Older log entries are dropped
+pub const MAX_LOG_ENTRIES: usize = 10_000;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(crate = "serde")]
+pub enum LogSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl LogSeverity {
+    pub fn as_static_str(&self) -> &'static str {
+        match self {
+            LogSeverity::Info => "Info",
+            LogSeverity::Warning => "Warning",
+            LogSeverity::Error => "Error",
+        }
+    }
+}
+
+/// Entry of the in-app log, e.g. a message logged by a component or a backend error
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "serde")]
+pub struct LogEntry {
+    /// Milliseconds since the Unix epoch
+    pub timestamp: u64,
+    pub severity: LogSeverity,
+    /// What logged the entry, e.g. `Decoder` or `Waveform`
+    pub source: String,
+    pub message: String,
+}
+
+/// Identifies one of the waveforms loaded at once, ids of unloaded waveforms aren't reused
+pub type WaveformId = usize;
+
+/// Waveform added by a loading, its value changes may be still being parsed
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "serde")]
+pub struct WaveformInfo {
+    pub id: WaveformId,
+    pub filename: String,
+}
+
+/// Value changes are parsed in the background after the hierarchy has been loaded
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "serde")]
+pub enum WaveformLoadingStatus {
+    Loading {
+        waveform_id: WaveformId,
+        filename: String,
+        loaded_bytes: u64,
+        /// `0` when unknown, e.g. for FST files
+        total_bytes: u64,
+    },
+    Loaded {
+        waveform_id: WaveformId,
+        filename: String,
+    },
+    Cancelled {
+        waveform_id: WaveformId,
+        filename: String,
+    },
+    Failed {
+        waveform_id: WaveformId,
+        error: CommandError,
+    },
+}
+
+impl WaveformLoadingStatus {
+    pub fn waveform_id(&self) -> WaveformId {
+        match self {
+            Self::Loading { waveform_id, .. }
+            | Self::Loaded { waveform_id, .. }
+            | Self::Cancelled { waveform_id, .. }
+            | Self::Failed { waveform_id, .. } => *waveform_id,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "serde")]
+pub struct Marker {
+    pub name: String,
+    pub time: wellen::Time,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(crate = "serde")]
 pub enum DiagramConnectorMessage {
@@ -0,0 +1,32 @@
+use moonlight::*;
+
+#[derive(Default, Clone, Copy, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(crate = "serde")]
+pub enum RenderMode {
+    #[default]
+    DigitalBus,
+    AnalogStep,
+    AnalogInterpolated,
+}
+
+impl RenderMode {
+    pub fn as_static_str(&self) -> &'static str {
+        match self {
+            RenderMode::DigitalBus => "Bus",
+            RenderMode::AnalogStep => "Step",
+            RenderMode::AnalogInterpolated => "Line",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            RenderMode::DigitalBus => RenderMode::AnalogStep,
+            RenderMode::AnalogStep => RenderMode::AnalogInterpolated,
+            RenderMode::AnalogInterpolated => RenderMode::DigitalBus,
+        }
+    }
+
+    pub fn is_analog(&self) -> bool {
+        !matches!(self, RenderMode::DigitalBus)
+    }
+}
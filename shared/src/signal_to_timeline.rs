@@ -2,13 +2,21 @@ use crate::*;
 use future::BoxFuture;
 use wellen::SignalValue;
 
-// @TODO remove once https://github.com/rust-lang/rust/issues/89976 is resolved
-// (`error: implementation of `FnOnce` is not general enough`)
-fn type_hint<F>(f: F) -> F
-where
-    F: for<'a> FnMut((u32, SignalValue<'a>)) -> (f64, SignalValue<'a>),
-{
-    f
+// Narrower blocks are merged into one busy block
+const MIN_BLOCK_WIDTH: u32 = 3;
+
+// Courier New, 16px, sync with `label_style` in `pixi_canvas.rs`
+const LETTER_WIDTH: f64 = 9.61;
+const LETTER_HEIGHT: u32 = 18;
+const LABEL_X_PADDING: u32 = 10;
+
+/// Value of a block passed to `decode_value` in `signal_to_timeline`
+pub struct DecoderInput<'s> {
+    pub value: SignalValue<'s>,
+    /// `value` formatted by the selected `VarFormat`
+    pub formatted_value: String,
+    /// Time of the change that set `value`
+    pub time: wellen::Time,
 }
 
 pub async fn signal_to_timeline<'s>(
@@ -19,14 +27,9 @@ pub async fn signal_to_timeline<'s>(
     timeline_viewport_x: i32,
     block_height: u32,
     var_format: VarFormat,
-    mut format_by_decoders: impl FnMut(String) -> BoxFuture<'s, String>,
+    render_mode: RenderMode,
+    mut decode_value: impl FnMut(DecoderInput<'s>) -> BoxFuture<'s, DecodedValue>,
 ) -> Timeline {
-    const MIN_BLOCK_WIDTH: u32 = 3;
-    // Courier New, 16px, sync with `label_style` in `pixi_canvas.rs`
-    const LETTER_WIDTH: f64 = 9.61;
-    const LETTER_HEIGHT: u32 = 18;
-    const LABEL_X_PADDING: u32 = 10;
-
     let Some(last_time) = time_table.last().copied() else {
         return Timeline::default();
     };
@@ -35,61 +38,351 @@ pub async fn signal_to_timeline<'s>(
     let timeline_viewport_x = timeline_viewport_x as f64;
     let timeline_width = timeline_viewport_width as f64 * timeline_zoom;
 
-    let mut x_value_pairs = signal
-        .iter_changes()
-        .map(type_hint(move |(index, value)| {
-            let index = index as usize;
-            let time = time_table[index] as f64;
-            let x = time / last_time * timeline_width - timeline_viewport_x;
-            (x, value)
-        }))
-        .peekable();
+    let changes = SignalChanges {
+        signal,
+        time_table,
+        time_indices: signal.time_indices(),
+        last_time,
+        timeline_width,
+        timeline_viewport_x,
+    };
+
+    if render_mode.is_analog() {
+        let analog = signal_to_analog_timeline(&changes, timeline_viewport_width, var_format);
+        return Timeline {
+            analog: Some(analog),
+            ..Timeline::default()
+        };
+    }
+
+    if is_single_bit(&changes) {
+        let bit_blocks = signal_to_bit_blocks(
+            &changes,
+            timeline_viewport_width,
+            block_height,
+            var_format,
+            decode_value,
+        )
+        .await;
+        return Timeline {
+            bit_blocks,
+            ..Timeline::default()
+        };
+    }
 
     // @TODO parallelize?
     let mut blocks = Vec::new();
-    while let Some((block_x, value)) = x_value_pairs.next() {
+    let mut change_idx = changes.last_change_idx_at_x(0.);
+    while change_idx < changes.len() {
+        let block_x = changes.x(change_idx);
         if block_x >= (timeline_viewport_width as f64) {
             break;
         }
 
-        let next_block_x = if let Some((next_block_x, _)) = x_value_pairs.peek() {
-            *next_block_x
-        } else {
-            timeline_width - timeline_viewport_x
-        };
-
-        let block_width = (next_block_x - block_x) as u32;
-        if block_width < MIN_BLOCK_WIDTH {
+        let next_block_x = changes.next_x(change_idx);
+        if next_block_x - block_x < MIN_BLOCK_WIDTH as f64 {
+            let last_busy_change_idx = changes.last_busy_change_idx(change_idx);
+            let next_block_x = changes.next_x(last_busy_change_idx);
+            change_idx = last_busy_change_idx + 1;
+            if next_block_x <= 0. {
+                continue;
+            }
+            // Rounded to not leave gaps between neighbouring busy blocks
+            let (block_x, next_block_x) = (block_x.round(), next_block_x.round());
+            blocks.push(TimelineBlock {
+                x: block_x as i32,
+                width: (next_block_x - block_x) as u32,
+                height: block_height,
+                label: None,
+                is_busy: true,
+                ..TimelineBlock::default()
+            });
             continue;
         }
+
+        let value = changes.value(change_idx);
+        let time = changes.time(change_idx);
+        change_idx += 1;
+        let block_width = (next_block_x - block_x) as u32;
         if block_x + (block_width as f64) <= 0. {
             continue;
         }
-
-        // @TODO cache?
-        let mut value = var_format.format(value);
-        value = format_by_decoders(value).await;
-
-        let value_width = (value.chars().count() as f64 * LETTER_WIDTH) as u32;
-        // @TODO Ellipsis instead of hiding?
-        let label = if (value_width + (2 * LABEL_X_PADDING)) <= block_width {
-            Some(TimeLineBlockLabel {
-                text: value,
-                x: (block_width - value_width) / 2,
-                y: (block_height - LETTER_HEIGHT) / 2,
-            })
-        } else {
-            None
+        let Some(value) = value else {
+            continue;
         };
 
+        let formatted_value = var_format.format(value);
+        let DecodedValue {
+            text,
+            color,
+            tooltip,
+            is_unknown,
+        } = decode_value(DecoderInput {
+            value,
+            formatted_value,
+            time,
+        })
+        .await;
+
         let block = TimelineBlock {
             x: block_x as i32,
             width: block_width,
             height: block_height,
-            label,
+            label: block_label(text, block_width, block_height),
+            is_busy: false,
+            color,
+            tooltip,
+            is_unknown,
+        };
+        blocks.push(block);
+    }
+
+    Timeline {
+        blocks,
+        ..Timeline::default()
+    }
+}
+
+// @TODO Ellipsis instead of hiding?
+fn block_label(text: String, block_width: u32, block_height: u32) -> Option<TimeLineBlockLabel> {
+    let value_width = (text.chars().count() as f64 * LETTER_WIDTH) as u32;
+    if (value_width + (2 * LABEL_X_PADDING)) > block_width {
+        return None;
+    }
+    Some(TimeLineBlockLabel {
+        text,
+        x: (block_width - value_width) / 2,
+        y: block_height.saturating_sub(LETTER_HEIGHT) / 2,
+    })
+}
+
+fn is_single_bit(changes: &SignalChanges) -> bool {
+    changes.len() > 0
+        && changes.value(0).is_some_and(|value| {
+            matches!(
+                value,
+                SignalValue::Binary(_, 1)
+                    | SignalValue::FourValue(_, 1)
+                    | SignalValue::NineValue(_, 1)
+            )
+        })
+}
+
+/// Bit values are formatted and decoded like multi-bit values,
+/// the decoded text is drawn as a label over the bit line
+async fn signal_to_bit_blocks<'s>(
+    changes: &SignalChanges<'s, '_>,
+    timeline_viewport_width: u32,
+    block_height: u32,
+    var_format: VarFormat,
+    mut decode_value: impl FnMut(DecoderInput<'s>) -> BoxFuture<'s, DecodedValue>,
+) -> Vec<BitBlock> {
+    let mut blocks = Vec::new();
+    let mut change_idx = changes.last_change_idx_at_x(0.);
+    while change_idx < changes.len() {
+        let block_x = changes.x(change_idx);
+        if block_x >= (timeline_viewport_width as f64) {
+            break;
+        }
+
+        let mut next_block_x = changes.next_x(change_idx);
+        let is_busy = next_block_x - block_x < MIN_BLOCK_WIDTH as f64;
+        if is_busy {
+            change_idx = changes.last_busy_change_idx(change_idx);
+            next_block_x = changes.next_x(change_idx);
+        }
+        // The busy block continues with the state of its last change
+        let value = changes.value(change_idx);
+        let time = changes.time(change_idx);
+        change_idx += 1;
+        if next_block_x <= 0. {
+            continue;
+        }
+        let Some(value) = value else {
+            continue;
+        };
+
+        // Keep coordinates close to the viewport to not overflow when zoomed in
+        let block_x = block_x.max(-1.).round() as i32;
+        let next_block_x = next_block_x
+            .min(timeline_viewport_width as f64 + 1.)
+            .round() as i32;
+        let block_width = (next_block_x - block_x).max(0) as u32;
+
+        let mut block = BitBlock {
+            x: block_x,
+            width: block_width,
+            state: bit_state(value),
+            is_busy,
+            ..BitBlock::default()
         };
+        if !is_busy {
+            let formatted_value = var_format.format(value);
+            let DecodedValue {
+                text,
+                color,
+                tooltip,
+                is_unknown,
+            } = decode_value(DecoderInput {
+                value,
+                formatted_value,
+                time,
+            })
+            .await;
+            if is_unknown {
+                block.state = BitState::Unknown;
+            }
+            block.label = block_label(text, block_width, block_height);
+            block.color = color;
+            block.tooltip = tooltip;
+        }
         blocks.push(block);
     }
+    blocks
+}
+
+fn bit_state(value: SignalValue) -> BitState {
+    match value.to_string().chars().next() {
+        Some('1' | 'h' | 'H') => BitState::High,
+        Some('0' | 'l' | 'L') => BitState::Low,
+        Some('z' | 'Z') => BitState::HighImpedance,
+        _ => BitState::Unknown,
+    }
+}
+
+fn signal_to_analog_timeline(
+    changes: &SignalChanges,
+    timeline_viewport_width: u32,
+    var_format: VarFormat,
+) -> AnalogTimeline {
+    let mut columns = Vec::<AnalogColumn>::new();
+    // Starts with the last change before the viewport to draw the line entering it
+    for change_idx in changes.last_change_idx_at_x(0.)..changes.len() {
+        let Some(value) = changes
+            .value(change_idx)
+            .and_then(|value| analog_value(value, var_format))
+        else {
+            continue;
+        };
+        let x = changes.x(change_idx).floor() as i32;
+        if x < 0 {
+            columns.clear();
+        }
+        match columns.last_mut() {
+            Some(column) if column.x == x => {
+                column.last = value;
+                column.min = column.min.min(value);
+                column.max = column.max.max(value);
+            }
+            _ => columns.push(AnalogColumn {
+                x,
+                first: value,
+                last: value,
+                min: value,
+                max: value,
+            }),
+        }
+        if x >= timeline_viewport_width as i32 {
+            // The first change after the viewport is needed to draw the line leaving it
+            break;
+        }
+    }
+
+    let (y_min, y_max) = columns
+        .iter()
+        .map(|column| (column.min, column.max))
+        .reduce(|(y_min, y_max), (min, max)| (y_min.min(min), y_max.max(max)))
+        .unwrap_or_default();
+
+    AnalogTimeline {
+        columns,
+        y_min,
+        y_max,
+        end_x: (changes.timeline_width - changes.timeline_viewport_x) as i32,
+    }
+}
+
+fn analog_value(value: SignalValue, var_format: VarFormat) -> Option<f64> {
+    match value {
+        SignalValue::Real(value) => Some(value),
+        SignalValue::String(value) => value.trim().parse().ok(),
+        _ => {
+            let bits = value.to_string();
+            let mut number = 0.;
+            for bit in bits.chars() {
+                number = number * 2. + f64::from(bit.to_digit(2)?);
+            }
+            if matches!(var_format, VarFormat::Signed) && bits.starts_with('1') {
+                number -= 2f64.powi(bits.len() as i32);
+            }
+            Some(number)
+        }
+    }
+}
+
+/// Signal changes mapped to x coordinates in the timeline viewport
+struct SignalChanges<'s, 't> {
+    signal: &'s wellen::Signal,
+    time_table: &'t [wellen::Time],
+    time_indices: &'s [wellen::TimeTableIdx],
+    last_time: f64,
+    timeline_width: f64,
+    timeline_viewport_x: f64,
+}
+
+impl<'s, 't> SignalChanges<'s, 't> {
+    fn len(&self) -> usize {
+        self.time_indices.len()
+    }
 
-    Timeline { blocks }
+    fn time(&self, change_idx: usize) -> wellen::Time {
+        self.time_table[self.time_indices[change_idx] as usize]
+    }
+
+    fn x(&self, change_idx: usize) -> f64 {
+        let time = self.time(change_idx) as f64;
+        time / self.last_time * self.timeline_width - self.timeline_viewport_x
+    }
+
+    /// Where the value set by the change ends
+    fn next_x(&self, change_idx: usize) -> f64 {
+        if change_idx + 1 < self.len() {
+            self.x(change_idx + 1)
+        } else {
+            self.timeline_width - self.timeline_viewport_x
+        }
+    }
+
+    /// The last change at or before `x` (or the first change when there is none)
+    fn last_change_idx_at_x(&self, x: f64) -> usize {
+        let time = (x + self.timeline_viewport_x) / self.timeline_width * self.last_time;
+        let time = time.max(0.).floor() as wellen::Time;
+        last_change_idx_at_time(self.time_indices, self.time_table, time).unwrap_or_default()
+    }
+
+    /// The last change of the busy block starting with the change at `change_idx`,
+    /// i.e. the last of the following changes narrower than `MIN_BLOCK_WIDTH`.
+    /// The busy block ends where the value of a wider change starts.
+    fn last_busy_change_idx(&self, change_idx: usize) -> usize {
+        let mut last_busy_change_idx = change_idx;
+        loop {
+            let next_change_idx = last_busy_change_idx + 1;
+            if next_change_idx >= self.len()
+                || self.next_x(next_change_idx) - self.x(next_change_idx) >= MIN_BLOCK_WIDTH as f64
+            {
+                return last_busy_change_idx;
+            }
+            // Changes before the one at `MIN_BLOCK_WIDTH` after the narrow change are narrow as well,
+            // so they are skipped at once to not iterate over all changes in dense areas
+            last_busy_change_idx = self
+                .last_change_idx_at_x(self.x(next_change_idx) + MIN_BLOCK_WIDTH as f64)
+                .saturating_sub(1)
+                .max(next_change_idx);
+        }
+    }
+
+    fn value(&self, change_idx: usize) -> Option<SignalValue<'s>> {
+        let offset = self.signal.get_offset(self.time_indices[change_idx])?;
+        Some(self.signal.get_value_at(&offset, 0))
+    }
 }
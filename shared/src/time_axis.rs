@@ -0,0 +1,81 @@
+use crate::*;
+use wellen::simple::Waveform;
+
+/// Time units and the time range of a waveform.
+///
+/// Waveforms loaded at once share the axis returned by [`TimeAxis::shared`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct TimeAxis {
+    pub timescale: Option<wellen::Timescale>,
+    pub last_time: wellen::Time,
+}
+
+impl TimeAxis {
+    pub fn new(waveform: &Waveform) -> Self {
+        Self {
+            timescale: waveform.hierarchy().timescale(),
+            last_time: waveform.time_table().last().copied().unwrap_or_default(),
+        }
+    }
+
+    /// Axis in the time units of the first waveform long enough for all waveforms
+    pub fn shared(waveform_axes: impl IntoIterator<Item = TimeAxis>) -> Option<Self> {
+        let mut waveform_axes = waveform_axes.into_iter();
+        let mut shared_axis = waveform_axes.next()?;
+        for waveform_axis in waveform_axes {
+            let last_time = shared_axis.time_from(waveform_axis.last_time, waveform_axis);
+            shared_axis.last_time = shared_axis.last_time.max(last_time);
+        }
+        Some(shared_axis)
+    }
+
+    /// Converts `time` of the waveform with `waveform_axis` to this axis
+    pub fn time_from(&self, time: wellen::Time, waveform_axis: TimeAxis) -> wellen::Time {
+        (time as f64 * self.multiplier(waveform_axis)).round() as wellen::Time
+    }
+
+    /// Converts `time` of this axis to the waveform with `waveform_axis`,
+    /// rounded down to not get values changed after `time`
+    pub fn time_to(&self, time: wellen::Time, waveform_axis: TimeAxis) -> wellen::Time {
+        (time as f64 / self.multiplier(waveform_axis)).floor() as wellen::Time
+    }
+
+    /// `signal_to_timeline` maps the waveform's last time to the end of the timeline,
+    /// the returned zoom makes it draw the waveform with `waveform_axis` on this axis instead
+    pub fn waveform_zoom(&self, timeline_zoom: f64, waveform_axis: TimeAxis) -> f64 {
+        if self.last_time == 0 {
+            return timeline_zoom;
+        }
+        let waveform_last_time = waveform_axis.last_time as f64 * self.multiplier(waveform_axis);
+        timeline_zoom * waveform_last_time / self.last_time as f64
+    }
+
+    pub fn timeline_ruler(
+        &self,
+        timeline_zoom: f64,
+        timeline_viewport_width: u32,
+        timeline_viewport_x: i32,
+    ) -> TimelineRuler {
+        // Only the last time of the time table is needed
+        crate::timeline_ruler(
+            &[self.last_time],
+            self.timescale,
+            timeline_zoom,
+            timeline_viewport_width,
+            timeline_viewport_x,
+        )
+    }
+
+    // Waveforms without a timescale are expected to use the same time units
+    fn multiplier(&self, waveform_axis: TimeAxis) -> f64 {
+        let (factor, exponent) = timescale_factor_and_exponent(self.timescale);
+        let (waveform_factor, waveform_exponent) =
+            timescale_factor_and_exponent(waveform_axis.timescale);
+        match (exponent, waveform_exponent) {
+            (Some(exponent), Some(waveform_exponent)) => {
+                waveform_factor / factor * 10f64.powi((waveform_exponent - exponent) as i32)
+            }
+            _ => 1.,
+        }
+    }
+}
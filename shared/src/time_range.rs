@@ -0,0 +1,49 @@
+use std::ops::Range;
+use wellen::SignalValue;
+
+/// Changes of `signal` affecting values between `start_time` and `end_time` (both inclusive),
+/// i.e. the last change at or before `start_time` followed by all changes up to `end_time`.
+///
+/// Both bounds are found by binary search so the cost doesn't depend on how far
+/// from the beginning of the waveform the range is.
+/// The returned range indexes `signal.time_indices()`.
+pub fn changes_in_time_range(
+    signal: &wellen::Signal,
+    time_table: &[wellen::Time],
+    start_time: wellen::Time,
+    end_time: wellen::Time,
+) -> Range<usize> {
+    let time_indices = signal.time_indices();
+    let start = last_change_idx_at_time(time_indices, time_table, start_time).unwrap_or_default();
+    let end = time_indices
+        .partition_point(|time_table_idx| time_table[*time_table_idx as usize] <= end_time);
+    start..end.max(start)
+}
+
+/// Times and values of the changes in [`changes_in_time_range`].
+pub fn iter_changes_in_time_range<'s>(
+    signal: &'s wellen::Signal,
+    time_table: &'s [wellen::Time],
+    start_time: wellen::Time,
+    end_time: wellen::Time,
+) -> impl Iterator<Item = (wellen::Time, SignalValue<'s>)> + 's {
+    let time_indices = signal.time_indices();
+    changes_in_time_range(signal, time_table, start_time, end_time).filter_map(move |change_idx| {
+        let time_table_idx = time_indices[change_idx];
+        let offset = signal.get_offset(time_table_idx)?;
+        let value = signal.get_value_at(&offset, 0);
+        Some((time_table[time_table_idx as usize], value))
+    })
+}
+
+/// Index into `time_indices` of the last change at or before `time`.
+pub fn last_change_idx_at_time(
+    time_indices: &[wellen::TimeTableIdx],
+    time_table: &[wellen::Time],
+    time: wellen::Time,
+) -> Option<usize> {
+    let time_table_end = time_table.partition_point(|table_time| *table_time <= time);
+    time_indices
+        .partition_point(|time_table_idx| (*time_table_idx as usize) < time_table_end)
+        .checked_sub(1)
+}
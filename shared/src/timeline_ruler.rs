@@ -0,0 +1,143 @@
+use crate::*;
+
+// Enough space for a label like `123.45 ns`, sync with `ruler_label_style` in `pixi_canvas.ts`
+const MIN_MAJOR_TICK_SPACING: f64 = 100.;
+const MAX_LABEL_DECIMALS: i32 = 6;
+
+pub fn timeline_ruler(
+    time_table: &[wellen::Time],
+    timescale: Option<wellen::Timescale>,
+    timeline_zoom: f64,
+    timeline_viewport_width: u32,
+    timeline_viewport_x: i32,
+) -> TimelineRuler {
+    let Some(last_time) = time_table.last().copied() else {
+        return TimelineRuler::default();
+    };
+    if last_time == 0 || timeline_viewport_width == 0 {
+        return TimelineRuler {
+            ticks: Vec::new(),
+            last_time,
+        };
+    }
+
+    let (factor, exponent) = timescale_factor_and_exponent(timescale);
+    let has_unit = exponent.is_some();
+    let exponent = exponent.unwrap_or_default();
+
+    let timeline_width = timeline_viewport_width as f64 * timeline_zoom;
+    let time_per_pixel = last_time as f64 / timeline_width;
+    let viewport_x = timeline_viewport_x as f64;
+
+    // "scaled" time = time in `10^exponent` seconds (e.g. `25` with `exponent` `-9` => 25 ns)
+    let start_scaled = (viewport_x * time_per_pixel).max(0.) * factor;
+    let end_scaled = ((viewport_x + timeline_viewport_width as f64) * time_per_pixel)
+        .min(last_time as f64)
+        * factor;
+
+    let (major_step, minor_divisions) = nice_step(MIN_MAJOR_TICK_SPACING * time_per_pixel * factor);
+    let minor_step = major_step / minor_divisions as f64;
+
+    let unit_exponent = if has_unit {
+        display_unit_exponent(end_scaled, exponent)
+    } else {
+        0
+    };
+    let unit_multiplier = 10f64.powi(exponent as i32 - unit_exponent);
+    let label_decimals = label_decimals(major_step * unit_multiplier);
+    let unit_suffix = if has_unit {
+        format!(" {}", unit_symbol(unit_exponent))
+    } else {
+        String::new()
+    };
+
+    let first_minor_index = (start_scaled / minor_step).ceil() as i64;
+    let last_minor_index = (end_scaled / minor_step).floor() as i64;
+
+    let mut ticks = Vec::new();
+    for minor_index in first_minor_index..=last_minor_index {
+        let time_scaled = minor_index as f64 * minor_step;
+        let x = time_scaled / factor / time_per_pixel - viewport_x;
+        let is_major = minor_index % minor_divisions == 0;
+        let label = is_major.then(|| {
+            let value = time_scaled * unit_multiplier;
+            format!("{value:.*}{unit_suffix}", label_decimals)
+        });
+        ticks.push(TimelineTick {
+            x: x.round() as i32,
+            is_major,
+            label,
+        });
+    }
+
+    TimelineRuler { ticks, last_time }
+}
+
+/// Formats `time` with the most suitable unit derived from `timescale`, e.g. `12.5 ns`.
+pub fn format_time(time: wellen::Time, timescale: Option<wellen::Timescale>) -> String {
+    let (factor, exponent) = timescale_factor_and_exponent(timescale);
+    let Some(exponent) = exponent else {
+        return time.to_string();
+    };
+    let time_scaled = time as f64 * factor;
+    let unit_exponent = display_unit_exponent(time_scaled, exponent);
+    let value = time_scaled * 10f64.powi(exponent as i32 - unit_exponent);
+    let value = format!("{value:.3}");
+    let value = value.trim_end_matches('0').trim_end_matches('.');
+    format!("{value} {}", unit_symbol(unit_exponent))
+}
+
+pub(crate) fn timescale_factor_and_exponent(
+    timescale: Option<wellen::Timescale>,
+) -> (f64, Option<i8>) {
+    match timescale {
+        Some(timescale) => (timescale.factor as f64, timescale.unit.to_exponent()),
+        None => (1., None),
+    }
+}
+
+/// Returns a "nice" step (1, 2 or 5 times a power of ten) not smaller than `min_step`
+/// together with the number of minor ticks per that step.
+fn nice_step(min_step: f64) -> (f64, i64) {
+    if min_step <= 0. || !min_step.is_finite() {
+        return (1., 5);
+    }
+    let magnitude = 10f64.powf(min_step.log10().floor());
+    for (mantissa, minor_divisions) in [(1., 5), (2., 4), (5., 5)] {
+        let step = mantissa * magnitude;
+        if step >= min_step {
+            return (step, minor_divisions);
+        }
+    }
+    (10. * magnitude, 5)
+}
+
+fn display_unit_exponent(time_scaled: f64, exponent: i8) -> i32 {
+    if time_scaled < 1. {
+        return (exponent as i32).clamp(-21, 0);
+    }
+    let value_exponent = time_scaled.log10().floor() as i32 + exponent as i32;
+    (value_exponent.div_euclid(3) * 3).clamp(-21, 0)
+}
+
+fn label_decimals(step_in_unit: f64) -> usize {
+    if step_in_unit >= 1. {
+        return 0;
+    }
+    (-step_in_unit.log10())
+        .ceil()
+        .clamp(0., MAX_LABEL_DECIMALS as f64) as usize
+}
+
+fn unit_symbol(unit_exponent: i32) -> &'static str {
+    match unit_exponent {
+        -21 => "zs",
+        -18 => "as",
+        -15 => "fs",
+        -12 => "ps",
+        -9 => "ns",
+        -6 => "us",
+        -3 => "ms",
+        _ => "s",
+    }
+}
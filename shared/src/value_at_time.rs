@@ -0,0 +1,30 @@
+use crate::*;
+
+/// Formatted value of `signal` at `time` - i.e. the value set by the last change at or before `time`.
+pub fn value_at_time(
+    signal: &wellen::Signal,
+    time_table: &[wellen::Time],
+    time: wellen::Time,
+    var_format: VarFormat,
+) -> Option<String> {
+    let time_table_idx = time_table
+        .partition_point(|table_time| *table_time <= time)
+        .checked_sub(1)?;
+    let offset = signal.get_offset(time_table_idx as wellen::TimeTableIdx)?;
+    let value = signal.get_value_at(&offset, 0);
+    Some(var_format.format(value))
+}
+
+/// Time of the first change of `signal` after `time`.
+pub fn next_change_time(
+    signal: &wellen::Signal,
+    time_table: &[wellen::Time],
+    time: wellen::Time,
+) -> Option<wellen::Time> {
+    let first_later_time_table_idx = time_table.partition_point(|table_time| *table_time <= time);
+    let time_indices = signal.time_indices();
+    let change_idx = time_indices
+        .partition_point(|time_table_idx| (*time_table_idx as usize) < first_later_time_table_idx);
+    let time_table_idx = *time_indices.get(change_idx)?;
+    time_table.get(time_table_idx as usize).copied()
+}
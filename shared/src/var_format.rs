@@ -1,6 +1,6 @@
 use moonlight::*;
 
-#[derive(Default, Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Default, Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(crate = "serde")]
 pub enum VarFormat {
     ASCII,
@@ -71,42 +71,12 @@ impl VarFormat {
                         value
                     })
             }
-            VarFormat::Hexadecimal => {
-                let ones_and_zeros = value
-                    .chars()
-                    .rev()
-                    .map(|char| char.to_digit(2).unwrap())
-                    .collect::<Vec<_>>();
-                let mut base = convert_base::Convert::new(2, 16);
-                let output = base.convert::<u32, u32>(&ones_and_zeros);
-                let value: String = output
-                    .into_iter()
-                    .rev()
-                    .map(|number| char::from_digit(number, 16).unwrap())
-                    .collect();
-                value
-            }
-            VarFormat::Octal => {
-                let ones_and_zeros = value
-                    .chars()
-                    .rev()
-                    .map(|char| char.to_digit(2).unwrap())
-                    .collect::<Vec<_>>();
-                let mut base = convert_base::Convert::new(2, 8);
-                let output = base.convert::<u32, u32>(&ones_and_zeros);
-                let value: String = output
-                    .into_iter()
-                    .rev()
-                    .map(|number| char::from_digit(number, 8).unwrap())
-                    .collect();
-                value
-            }
+            VarFormat::Hexadecimal => format_by_digit_groups(&value, 4),
+            VarFormat::Octal => format_by_digit_groups(&value, 3),
             VarFormat::Signed => {
-                let mut ones_and_zeros = value
-                    .chars()
-                    .rev()
-                    .map(|char| char.to_digit(2).unwrap())
-                    .collect::<Vec<_>>();
+                let Some(mut ones_and_zeros) = ones_and_zeros(&value) else {
+                    return "x".to_owned();
+                };
 
                 // https://builtin.com/articles/twos-complement
                 let sign = if ones_and_zeros.last().unwrap() == &0 {
@@ -125,32 +95,114 @@ impl VarFormat {
                     }
                 }
 
-                let mut base = convert_base::Convert::new(2, 10);
-                let output = base.convert::<u32, u32>(&ones_and_zeros);
-                let value_without_sign: String = output
-                    .into_iter()
-                    .rev()
-                    .map(|number| char::from_digit(number, 10).unwrap())
-                    .collect();
+                let value_without_sign = to_decimal(&ones_and_zeros);
                 // @TODO chain `sign` before collecting?
                 let value = sign.to_owned() + &value_without_sign;
                 value
             }
             VarFormat::Unsigned => {
-                let ones_and_zeros = value
-                    .chars()
-                    .rev()
-                    .map(|char| char.to_digit(2).unwrap())
-                    .collect::<Vec<_>>();
-                let mut base = convert_base::Convert::new(2, 10);
-                let output = base.convert::<u32, u32>(&ones_and_zeros);
-                let value: String = output
-                    .into_iter()
-                    .rev()
-                    .map(|number| char::from_digit(number, 10).unwrap())
-                    .collect();
-                value
+                let Some(ones_and_zeros) = ones_and_zeros(&value) else {
+                    return "x".to_owned();
+                };
+                to_decimal(&ones_and_zeros)
             }
         }
     }
 }
+
+/// State of one digit of a four-state (`01xz`) or nine-state (`01xzhuwl-`) value.
+/// Weak `h` and `l` are treated as `1` and `0`.
+#[derive(Clone, Copy, PartialEq)]
+enum Bit {
+    Known(u32),
+    Unknown,
+    HighImpedance,
+}
+
+impl Bit {
+    fn new(char: char) -> Self {
+        match char {
+            '0' | 'l' | 'L' => Bit::Known(0),
+            '1' | 'h' | 'H' => Bit::Known(1),
+            'z' | 'Z' => Bit::HighImpedance,
+            _ => Bit::Unknown,
+        }
+    }
+}
+
+/// Least significant bit first, `None` if any bit is unknown or high-impedance.
+fn ones_and_zeros(value: &str) -> Option<Vec<u32>> {
+    value
+        .chars()
+        .rev()
+        .map(|char| match Bit::new(char) {
+            Bit::Known(one_or_zero) => Some(one_or_zero),
+            Bit::Unknown | Bit::HighImpedance => None,
+        })
+        .collect()
+}
+
+fn to_decimal(ones_and_zeros: &[u32]) -> String {
+    let mut base = convert_base::Convert::new(2, 10);
+    let output = base.convert::<u32, u32>(ones_and_zeros);
+    let value: String = output
+        .into_iter()
+        .rev()
+        .map(|number| char::from_digit(number, 10).unwrap())
+        .collect();
+    without_leading_zeros(value)
+}
+
+/// Each group of `bits_per_digit` bits (starting from the least significant bit) becomes one digit:
+/// - the digit in radix `2^bits_per_digit` when all bits are known
+/// - `z` when all bits are high-impedance
+/// - `x` when all bits are unknown or high-impedance
+/// - `Z` when some bits are known and the rest are high-impedance
+/// - `X` when some bits are known and some unknown
+fn format_by_digit_groups(value: &str, bits_per_digit: usize) -> String {
+    let radix = 1 << bits_per_digit;
+    let bits = value.chars().rev().map(Bit::new).collect::<Vec<_>>();
+    let digits = bits
+        .chunks(bits_per_digit)
+        .rev()
+        .map(|group| {
+            let known_bit_count = group
+                .iter()
+                .filter(|bit| matches!(bit, Bit::Known(_)))
+                .count();
+            let has_unknown_bit = group.contains(&Bit::Unknown);
+            if known_bit_count == group.len() {
+                let number = group.iter().rev().fold(0, |number, bit| match bit {
+                    Bit::Known(one_or_zero) => number * 2 + one_or_zero,
+                    Bit::Unknown | Bit::HighImpedance => number,
+                });
+                char::from_digit(number, radix).unwrap()
+            } else if known_bit_count == 0 {
+                if has_unknown_bit {
+                    'x'
+                } else {
+                    'z'
+                }
+            } else if has_unknown_bit {
+                'X'
+            } else {
+                'Z'
+            }
+        })
+        .collect::<String>();
+    without_leading_zeros(digits)
+}
+
+/// Strips only zeros followed by another digit, so e.g. `0x` (known upper bits, unknown lower bits) stays as is.
+fn without_leading_zeros(digits: String) -> String {
+    let leading_zero_count = digits
+        .as_bytes()
+        .windows(2)
+        .take_while(|pair| pair[0] == b'0' && pair[1].is_ascii_hexdigit())
+        .count();
+    if leading_zero_count == 0 {
+        digits
+    } else {
+        digits[leading_zero_count..].to_owned()
+    }
+}
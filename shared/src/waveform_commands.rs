@@ -0,0 +1,107 @@
+//! Waveform queries shared by the Tauri commands and the browser platform
+
+use crate::*;
+use wellen::simple::Waveform;
+
+pub fn loaded_waveform(waveform: Option<&Waveform>) -> Result<&Waveform, CommandError> {
+    waveform.ok_or(CommandError::NoWaveformLoaded)
+}
+
+pub fn loaded_waveform_mut(waveform: Option<&mut Waveform>) -> Result<&mut Waveform, CommandError> {
+    waveform.ok_or(CommandError::NoWaveformLoaded)
+}
+
+/// Validates `signal_ref_index` received from the frontend
+pub fn signal_ref(
+    waveform: &Waveform,
+    signal_ref_index: usize,
+) -> Result<wellen::SignalRef, CommandError> {
+    let signal_not_found = || CommandError::SignalNotFound { signal_ref_index };
+    if signal_ref_index >= waveform.hierarchy().num_unique_signals() {
+        return Err(signal_not_found());
+    }
+    wellen::SignalRef::from_index(signal_ref_index).ok_or_else(signal_not_found)
+}
+
+/// Loads the signal if needed
+pub fn load_signal(
+    waveform: &mut Waveform,
+    signal_ref_index: usize,
+) -> Result<wellen::SignalRef, CommandError> {
+    let signal_ref = signal_ref(waveform, signal_ref_index)?;
+    waveform.load_signals_multi_threaded(&[signal_ref]);
+    Ok(signal_ref)
+}
+
+pub fn hierarchy(waveform: Option<&Waveform>) -> Result<&wellen::Hierarchy, CommandError> {
+    Ok(loaded_waveform(waveform)?.hierarchy())
+}
+
+pub fn timeline_ruler(
+    waveform: Option<&Waveform>,
+    timeline_zoom: f64,
+    timeline_viewport_width: u32,
+    timeline_viewport_x: i32,
+) -> Result<TimelineRuler, CommandError> {
+    let waveform = loaded_waveform(waveform)?;
+    Ok(crate::timeline_ruler(
+        waveform.time_table(),
+        waveform.hierarchy().timescale(),
+        timeline_zoom,
+        timeline_viewport_width,
+        timeline_viewport_x,
+    ))
+}
+
+pub fn value_at_time(
+    waveform: Option<&mut Waveform>,
+    signal_ref_index: usize,
+    time: wellen::Time,
+    var_format: VarFormat,
+) -> Result<Option<String>, CommandError> {
+    let waveform = loaded_waveform_mut(waveform)?;
+    let signal_ref = load_signal(waveform, signal_ref_index)?;
+    let signal = waveform
+        .get_signal(signal_ref)
+        .ok_or(CommandError::SignalNotFound { signal_ref_index })?;
+    Ok(crate::value_at_time(
+        signal,
+        waveform.time_table(),
+        time,
+        var_format,
+    ))
+}
+
+pub fn values_at_time(
+    waveform: Option<&mut Waveform>,
+    signal_ref_indices_and_var_formats: Vec<(usize, VarFormat)>,
+    time: wellen::Time,
+) -> Result<Vec<Option<String>>, CommandError> {
+    let waveform = loaded_waveform_mut(waveform)?;
+    let signal_refs = signal_ref_indices_and_var_formats
+        .iter()
+        .map(|(signal_ref_index, _)| signal_ref(waveform, *signal_ref_index))
+        .collect::<Result<Vec<_>, _>>()?;
+    waveform.load_signals_multi_threaded(&signal_refs);
+    let time_table = waveform.time_table();
+    signal_refs
+        .into_iter()
+        .zip(signal_ref_indices_and_var_formats)
+        .map(|(signal_ref, (signal_ref_index, var_format))| {
+            let signal = waveform
+                .get_signal(signal_ref)
+                .ok_or(CommandError::SignalNotFound { signal_ref_index })?;
+            Ok(crate::value_at_time(signal, time_table, time, var_format))
+        })
+        .collect()
+}
+
+pub fn unload_signal(
+    waveform: Option<&mut Waveform>,
+    signal_ref_index: usize,
+) -> Result<(), CommandError> {
+    let waveform = loaded_waveform_mut(waveform)?;
+    let signal_ref = signal_ref(waveform, signal_ref_index)?;
+    waveform.unload_signals(&[signal_ref]);
+    Ok(())
+}
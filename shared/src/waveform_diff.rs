@@ -0,0 +1,187 @@
+use crate::*;
+use std::collections::HashMap;
+use wellen::simple::Waveform;
+
+/// Intervals kept for one var, the rest is only counted in `VarDiff::mismatch_count`
+const MAX_MISMATCHES_PER_VAR: usize = 10_000;
+
+/// Var with the same full name in both diffed waveforms but with different values
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "serde")]
+pub struct VarDiff {
+    pub full_var_name: String,
+    pub first_mismatch_time: wellen::Time,
+    pub mismatch_count: usize,
+    pub mismatches: Vec<TimeRange>,
+}
+
+/// Times on the shared time axis, `end` is exclusive
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(crate = "serde")]
+pub struct TimeRange {
+    pub start: wellen::Time,
+    pub end: wellen::Time,
+}
+
+/// Compares values of vars matched by their full names,
+/// vars found only in one of the waveforms are skipped.
+///
+/// Times are converted to `time_axis`, values are compared by their bits,
+/// so vars with different bit widths differ at all times.
+/// Signals are loaded one pair at a time, the ones not loaded before the diff are unloaded again.
+/// Returned diffs are sorted by their first mismatch time.
+pub fn diff_waveforms(
+    waveform_a: &mut Waveform,
+    waveform_b: &mut Waveform,
+    time_axis: TimeAxis,
+) -> Vec<VarDiff> {
+    let hierarchy_b = waveform_b.hierarchy();
+    let signal_refs_b = hierarchy_b
+        .iter_vars()
+        .map(|var| (var.full_name(hierarchy_b), var.signal_ref()))
+        .collect::<HashMap<_, _>>();
+    let hierarchy_a = waveform_a.hierarchy();
+    let var_names_and_signal_refs = hierarchy_a
+        .iter_vars()
+        .filter_map(|var| {
+            let full_var_name = var.full_name(hierarchy_a);
+            let signal_ref_b = *signal_refs_b.get(&full_var_name)?;
+            Some((full_var_name, var.signal_ref(), signal_ref_b))
+        })
+        .collect::<Vec<_>>();
+
+    // Vars sharing the same signals are compared only once
+    let mut signal_mismatches = HashMap::new();
+    let mut var_diffs = Vec::new();
+    for (full_var_name, signal_ref_a, signal_ref_b) in var_names_and_signal_refs {
+        let mismatches = signal_mismatches
+            .entry((signal_ref_a, signal_ref_b))
+            .or_insert_with(|| {
+                let is_loaded_a = load_signal(waveform_a, signal_ref_a);
+                let is_loaded_b = load_signal(waveform_b, signal_ref_b);
+                let mismatches = mismatches(
+                    changes(waveform_a, signal_ref_a, time_axis),
+                    changes(waveform_b, signal_ref_b, time_axis),
+                    time_axis.last_time,
+                );
+                if is_loaded_a {
+                    waveform_a.unload_signals(&[signal_ref_a]);
+                }
+                if is_loaded_b {
+                    waveform_b.unload_signals(&[signal_ref_b]);
+                }
+                mismatches
+            });
+        let Some(first_mismatch) = mismatches.first() else {
+            continue;
+        };
+        var_diffs.push(VarDiff {
+            full_var_name,
+            first_mismatch_time: first_mismatch.start,
+            mismatch_count: mismatches.len(),
+            mismatches: mismatches
+                .iter()
+                .take(MAX_MISMATCHES_PER_VAR)
+                .copied()
+                .collect(),
+        });
+    }
+    var_diffs.sort_by(|var_diff_a, var_diff_b| {
+        (var_diff_a.first_mismatch_time, &var_diff_a.full_var_name)
+            .cmp(&(var_diff_b.first_mismatch_time, &var_diff_b.full_var_name))
+    });
+    var_diffs
+}
+
+/// Returns `true` when the signal has been loaded by this call
+fn load_signal(waveform: &mut Waveform, signal_ref: wellen::SignalRef) -> bool {
+    if waveform.get_signal(signal_ref).is_some() {
+        return false;
+    }
+    waveform.load_signals(&[signal_ref]);
+    true
+}
+
+/// Times on `time_axis` and values of all signal value changes
+fn changes(
+    waveform: &Waveform,
+    signal_ref: wellen::SignalRef,
+    time_axis: TimeAxis,
+) -> impl Iterator<Item = (wellen::Time, wellen::SignalValue<'_>)> {
+    let waveform_axis = TimeAxis::new(waveform);
+    let time_table = waveform.time_table();
+    let signal = waveform.get_signal(signal_ref);
+    signal
+        .into_iter()
+        .flat_map(|signal| signal.time_indices().iter().map(move |idx| (signal, *idx)))
+        .filter_map(move |(signal, time_table_idx)| {
+            let offset = signal.get_offset(time_table_idx)?;
+            let value = signal.get_value_at(&offset, 0);
+            let time = time_table[time_table_idx as usize];
+            Some((time_axis.time_from(time, waveform_axis), value))
+        })
+}
+
+/// Ranges where the values set by `changes_a` and `changes_b` differ,
+/// a missing value differs from any value
+fn mismatches<'a>(
+    changes_a: impl Iterator<Item = (wellen::Time, wellen::SignalValue<'a>)>,
+    changes_b: impl Iterator<Item = (wellen::Time, wellen::SignalValue<'a>)>,
+    last_time: wellen::Time,
+) -> Vec<TimeRange> {
+    let mut changes_a = changes_a.peekable();
+    let mut changes_b = changes_b.peekable();
+    let mut value_a = None;
+    let mut value_b = None;
+    let mut mismatch_start = None;
+    let mut mismatches = Vec::new();
+    loop {
+        let time = match (changes_a.peek(), changes_b.peek()) {
+            (Some((time_a, _)), Some((time_b, _))) => *time_a.min(time_b),
+            (Some((time, _)), None) | (None, Some((time, _))) => *time,
+            (None, None) => break,
+        };
+        // Only the last change at the same time sets the value
+        while let Some((_, value)) = changes_a.next_if(|(change_time, _)| *change_time == time) {
+            value_a = Some(value);
+        }
+        while let Some((_, value)) = changes_b.next_if(|(change_time, _)| *change_time == time) {
+            value_b = Some(value);
+        }
+        let is_mismatch = match (value_a, value_b) {
+            (Some(value_a), Some(value_b)) => !values_equal(value_a, value_b),
+            (value_a, value_b) => value_a.is_some() != value_b.is_some(),
+        };
+        match (mismatch_start, is_mismatch) {
+            (None, true) => mismatch_start = Some(time),
+            (Some(start), false) => {
+                mismatches.push(TimeRange { start, end: time });
+                mismatch_start = None;
+            }
+            _ => (),
+        }
+    }
+    if let Some(start) = mismatch_start {
+        mismatches.push(TimeRange {
+            start,
+            end: last_time.max(start),
+        });
+    }
+    mismatches
+}
+
+/// Raw bits are compared when both values have the same number of states per bit,
+/// other values, e.g. a two-state value and a four-state one, are compared as strings
+fn values_equal(value_a: wellen::SignalValue, value_b: wellen::SignalValue) -> bool {
+    use wellen::SignalValue::*;
+    match (value_a, value_b) {
+        (Binary(data_a, bits_a), Binary(data_b, bits_b))
+        | (FourValue(data_a, bits_a), FourValue(data_b, bits_b))
+        | (NineValue(data_a, bits_a), NineValue(data_b, bits_b)) => {
+            bits_a == bits_b && data_a == data_b
+        }
+        (String(value_a), String(value_b)) => value_a == value_b,
+        (Real(value_a), Real(value_b)) => value_a == value_b,
+        _ => value_a.to_string() == value_b.to_string(),
+    }
+}
@@ -1,5 +1,67 @@
+use crate::CommandError;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
 use wellen::{simple::Waveform, *};
 
+/// Reads the waveform file, the error is meant to be shown to the user
+pub fn read_from_path(path: &str) -> std::result::Result<Waveform, CommandError> {
+    let header = read_header_from_path(path)?;
+    read_body(header, path, None, None)
+}
+
+/// The hierarchy is available before the value changes are parsed by `read_body`
+pub fn read_header_from_path(
+    path: &str,
+) -> std::result::Result<viewers::HeaderResult, CommandError> {
+    // `viewers::read_header` panics when the file doesn't exist
+    if !std::path::Path::new(path).is_file() {
+        return Err(CommandError::WaveformLoadingFailed {
+            path: path.to_owned(),
+            reason: "File not found".to_owned(),
+        });
+    }
+    viewers::read_header(path, &LoadOptions::default()).map_err(|error| {
+        CommandError::WaveformLoadingFailed {
+            path: path.to_owned(),
+            reason: error.to_string(),
+        }
+    })
+}
+
+/// `progress` is increased by the number of parsed body bytes, see `HeaderResult::body_len`.
+///
+/// wellen only counts the progress, it can't stop a started body parsing.
+/// `is_cancelled` is checked before the parsing starts and before the waveform is created.
+pub fn read_body(
+    header: viewers::HeaderResult,
+    path: &str,
+    progress: Option<Arc<AtomicU64>>,
+    is_cancelled: Option<Arc<AtomicBool>>,
+) -> std::result::Result<Waveform, CommandError> {
+    let check_cancelled = || match &is_cancelled {
+        Some(is_cancelled) if is_cancelled.load(Ordering::Relaxed) => {
+            Err(CommandError::WaveformLoadingFailed {
+                path: path.to_owned(),
+                reason: "Loading cancelled".to_owned(),
+            })
+        }
+        _ => Ok(()),
+    };
+    check_cancelled()?;
+    let body = viewers::read_body(header.body, &header.hierarchy, progress).map_err(|error| {
+        CommandError::WaveformLoadingFailed {
+            path: path.to_owned(),
+            reason: error.to_string(),
+        }
+    })?;
+    check_cancelled()?;
+    Ok(Waveform::new(
+        header.hierarchy,
+        body.source,
+        body.time_table,
+    ))
+}
+
 pub fn read_from_bytes(bytes: Vec<u8>) -> Result<Waveform> {
     read_from_bytes_with_options(bytes, &LoadOptions::default())
 }
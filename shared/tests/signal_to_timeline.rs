@@ -0,0 +1,78 @@
+use futures_util::FutureExt;
+use shared::{signal_to_timeline, BitState, RenderMode, Timeline, VarFormat};
+use std::fmt::Write;
+use wellen::GetItem;
+
+const TIMELINE_VIEWPORT_WIDTH: u32 = 100;
+const ROW_HEIGHT: u32 = 40;
+
+/// Burst of changes every 1 ns followed by a value stable until 1000 ns
+fn burst_waveform() -> wellen::simple::Waveform {
+    let mut vcd = String::from(
+        "$timescale 1ns $end\n\
+        $scope module top $end\n\
+        $var wire 4 ! bus [3:0] $end\n\
+        $var wire 1 \" bit $end\n\
+        $var wire 1 # done $end\n\
+        $upscope $end\n\
+        $enddefinitions $end\n\
+        #0\n0#\n",
+    );
+    for time in 0..=20 {
+        writeln!(vcd, "#{time}\nb{:b} !\n{}\"", time % 16, time % 2).unwrap();
+    }
+    writeln!(vcd, "#1000\n1#").unwrap();
+    shared::wellen_helpers::read_from_bytes(vcd.into_bytes()).unwrap()
+}
+
+fn timeline(waveform: &mut wellen::simple::Waveform, var_name: &str) -> Timeline {
+    let hierarchy = waveform.hierarchy();
+    let var_ref = hierarchy.lookup_var(&["top"], &var_name).unwrap();
+    let signal_ref = hierarchy.get(var_ref).signal_ref();
+    waveform.load_signals(&[signal_ref]);
+    let signal = waveform.get_signal(signal_ref).unwrap();
+    // 10 ns per pixel, the burst is 2 pixels wide
+    signal_to_timeline(
+        signal,
+        waveform.time_table(),
+        1.,
+        TIMELINE_VIEWPORT_WIDTH,
+        0,
+        ROW_HEIGHT,
+        VarFormat::Binary,
+        RenderMode::DigitalBus,
+        |input| Box::pin(async { input.formatted_value.into() }),
+    )
+    .now_or_never()
+    .unwrap()
+}
+
+#[test]
+fn busy_block_ends_before_stable_value() {
+    let mut waveform = burst_waveform();
+    let timeline = timeline(&mut waveform, "bus");
+    let blocks = timeline
+        .blocks
+        .iter()
+        .map(|block| (block.x, block.width, block.is_busy))
+        .collect::<Vec<_>>();
+    assert_eq!(blocks, [(0, 2, true), (2, 98, false)]);
+    let label = timeline.blocks[1].label.as_ref().unwrap();
+    assert_eq!(label.text, "0100");
+}
+
+#[test]
+fn busy_bit_block_ends_before_stable_value() {
+    let mut waveform = burst_waveform();
+    let timeline = timeline(&mut waveform, "bit");
+    let bit_blocks = timeline
+        .bit_blocks
+        .iter()
+        .map(|block| (block.x, block.width, block.state, block.is_busy))
+        .collect::<Vec<_>>();
+    // The busy block has the state of the last merged change
+    assert_eq!(
+        bit_blocks,
+        [(0, 2, BitState::High, true), (2, 98, BitState::Low, false)]
+    );
+}
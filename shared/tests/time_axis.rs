@@ -0,0 +1,39 @@
+use shared::TimeAxis;
+use wellen::{Timescale, TimescaleUnit};
+
+fn axis(factor: u32, unit: TimescaleUnit, last_time: wellen::Time) -> TimeAxis {
+    TimeAxis {
+        timescale: Some(Timescale::new(factor, unit)),
+        last_time,
+    }
+}
+
+#[test]
+fn shared_axis_uses_units_of_first_waveform() {
+    let ns_axis = axis(1, TimescaleUnit::NanoSeconds, 100);
+    let ps_axis = axis(10, TimescaleUnit::PicoSeconds, 50_000);
+    let shared_axis = TimeAxis::shared([ns_axis, ps_axis]).unwrap();
+    assert_eq!(shared_axis.timescale, ns_axis.timescale);
+    assert_eq!(shared_axis.last_time, 500);
+    assert_eq!(shared_axis.time_from(1_000, ps_axis), 10);
+    assert_eq!(shared_axis.time_to(10, ps_axis), 1_000);
+}
+
+#[test]
+fn shorter_waveforms_are_zoomed_out() {
+    let shared_axis = axis(1, TimescaleUnit::NanoSeconds, 200);
+    let waveform_axis = axis(1, TimescaleUnit::NanoSeconds, 50);
+    assert_eq!(shared_axis.waveform_zoom(2., waveform_axis), 0.5);
+    assert_eq!(shared_axis.waveform_zoom(2., shared_axis), 2.);
+}
+
+#[test]
+fn waveforms_without_timescale_share_time_units() {
+    let shared_axis = TimeAxis {
+        timescale: None,
+        last_time: 10,
+    };
+    let waveform_axis = axis(1, TimescaleUnit::PicoSeconds, 20);
+    assert_eq!(shared_axis.time_from(7, waveform_axis), 7);
+    assert_eq!(TimeAxis::shared([]), None);
+}
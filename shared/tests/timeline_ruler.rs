@@ -0,0 +1,57 @@
+use shared::{format_time, timeline_ruler, TimelineRuler};
+use wellen::{Timescale, TimescaleUnit};
+
+fn major_ticks(ruler: &TimelineRuler) -> Vec<(i32, &str)> {
+    ruler
+        .ticks
+        .iter()
+        .filter(|tick| tick.is_major)
+        .map(|tick| (tick.x, tick.label.as_deref().unwrap_or_default()))
+        .collect()
+}
+
+#[test]
+fn major_ticks_are_spaced_by_nice_steps() {
+    let timescale = Some(Timescale::new(1, TimescaleUnit::NanoSeconds));
+    let ruler = timeline_ruler(&[0, 1_000], timescale, 1., 1_000, 0);
+    assert_eq!(ruler.last_time, 1_000);
+    // 5 minor ticks per major tick
+    assert_eq!(ruler.ticks.len(), 51);
+    let major_ticks = major_ticks(&ruler);
+    assert_eq!(major_ticks.len(), 11);
+    assert_eq!(major_ticks[0], (0, "0.0 us"));
+    assert_eq!(major_ticks[1], (100, "0.1 us"));
+    assert_eq!(major_ticks[10], (1_000, "1.0 us"));
+}
+
+#[test]
+fn zoomed_in_ticks_start_at_viewport() {
+    let timescale = Some(Timescale::new(1, TimescaleUnit::NanoSeconds));
+    let ruler = timeline_ruler(&[0, 1_000], timescale, 10., 1_000, 5_000);
+    let major_ticks = major_ticks(&ruler);
+    assert_eq!(major_ticks.first(), Some(&(0, "500 ns")));
+    assert_eq!(major_ticks.get(1), Some(&(100, "510 ns")));
+    assert_eq!(major_ticks.last(), Some(&(1_000, "600 ns")));
+}
+
+#[test]
+fn ticks_without_timescale_have_no_unit() {
+    let ruler = timeline_ruler(&[0, 1_000], None, 1., 1_000, 0);
+    assert_eq!(major_ticks(&ruler)[1], (100, "100"));
+    let empty_ruler = timeline_ruler(&[], None, 1., 1_000, 0);
+    assert!(empty_ruler.ticks.is_empty());
+}
+
+#[test]
+fn formatted_time_uses_most_suitable_unit() {
+    let ps = Some(Timescale::new(1, TimescaleUnit::PicoSeconds));
+    assert_eq!(format_time(1_500, ps), "1.5 ns");
+    assert_eq!(format_time(999, ps), "999 ps");
+    assert_eq!(format_time(2_000_000, ps), "2 us");
+    assert_eq!(format_time(0, ps), "0 ps");
+    let ten_ns = Some(Timescale::new(10, TimescaleUnit::NanoSeconds));
+    assert_eq!(format_time(25, ten_ns), "250 ns");
+    let s = Some(Timescale::new(1, TimescaleUnit::Seconds));
+    assert_eq!(format_time(5_000, s), "5000 s");
+    assert_eq!(format_time(42, None), "42");
+}
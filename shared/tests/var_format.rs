@@ -0,0 +1,134 @@
+use shared::VarFormat;
+use wellen::SignalValue;
+
+const ALL_FORMATS: [VarFormat; 7] = [
+    VarFormat::ASCII,
+    VarFormat::Binary,
+    VarFormat::BinaryWithGroups,
+    VarFormat::Hexadecimal,
+    VarFormat::Octal,
+    VarFormat::Signed,
+    VarFormat::Unsigned,
+];
+
+fn format(var_format: VarFormat, bits: &str) -> String {
+    var_format.format(SignalValue::String(bits))
+}
+
+#[test]
+fn empty_value() {
+    for var_format in ALL_FORMATS {
+        assert_eq!(format(var_format, ""), "", "{var_format:?}");
+    }
+}
+
+#[test]
+fn binary_keeps_all_states() {
+    assert_eq!(format(VarFormat::Binary, "10xz"), "10xz");
+    assert_eq!(format(VarFormat::BinaryWithGroups, "10xz1uw-"), "10xz 1uw-");
+    assert_eq!(format(VarFormat::BinaryWithGroups, "10xz1"), "10xz 1");
+}
+
+#[test]
+fn ascii() {
+    assert_eq!(format(VarFormat::ASCII, "0100011001010111"), "FW");
+    // Groups with unknown bits are skipped
+    assert_eq!(format(VarFormat::ASCII, "0100011001x10111"), "F");
+}
+
+#[test]
+fn hexadecimal_two_state() {
+    assert_eq!(format(VarFormat::Hexadecimal, "10101111"), "af");
+    assert_eq!(format(VarFormat::Hexadecimal, "00001010"), "a");
+    assert_eq!(format(VarFormat::Hexadecimal, "0000"), "0");
+    assert_eq!(format(VarFormat::Hexadecimal, "110000"), "30");
+}
+
+#[test]
+fn hexadecimal_four_state() {
+    assert_eq!(format(VarFormat::Hexadecimal, "xxxx0001"), "x1");
+    assert_eq!(format(VarFormat::Hexadecimal, "zzzz0001"), "z1");
+    assert_eq!(format(VarFormat::Hexadecimal, "xzzz0001"), "x1");
+    assert_eq!(format(VarFormat::Hexadecimal, "1x010000"), "X0");
+    assert_eq!(format(VarFormat::Hexadecimal, "1z010000"), "Z0");
+    assert_eq!(format(VarFormat::Hexadecimal, "xz100000"), "X0");
+    // The most significant group may be shorter than 4 bits
+    assert_eq!(format(VarFormat::Hexadecimal, "x1010"), "xa");
+    assert_eq!(format(VarFormat::Hexadecimal, "z"), "z");
+    assert_eq!(format(VarFormat::Hexadecimal, "x"), "x");
+    // Only zeros followed by another digit are stripped
+    assert_eq!(format(VarFormat::Hexadecimal, "0000xxxx"), "0x");
+    assert_eq!(format(VarFormat::Hexadecimal, "00000000zzzz"), "0z");
+    assert_eq!(format(VarFormat::Hexadecimal, "000000001x00"), "0X");
+}
+
+#[test]
+fn hexadecimal_nine_state() {
+    assert_eq!(format(VarFormat::Hexadecimal, "hlhl"), "a");
+    assert_eq!(format(VarFormat::Hexadecimal, "uuuu"), "x");
+    assert_eq!(format(VarFormat::Hexadecimal, "w---"), "x");
+    assert_eq!(format(VarFormat::Hexadecimal, "u-w1"), "X");
+    assert_eq!(format(VarFormat::Hexadecimal, "hzzz"), "Z");
+}
+
+#[test]
+fn octal() {
+    assert_eq!(format(VarFormat::Octal, "111000"), "70");
+    assert_eq!(format(VarFormat::Octal, "000001"), "1");
+    assert_eq!(format(VarFormat::Octal, "x11000"), "X0");
+    assert_eq!(format(VarFormat::Octal, "xxx000"), "x0");
+    assert_eq!(format(VarFormat::Octal, "zzz101"), "z5");
+    assert_eq!(format(VarFormat::Octal, "1z1101"), "Z5");
+}
+
+#[test]
+fn unsigned() {
+    assert_eq!(format(VarFormat::Unsigned, "1010"), "10");
+    assert_eq!(format(VarFormat::Unsigned, "0000"), "0");
+    assert_eq!(format(VarFormat::Unsigned, "00001010"), "10");
+    assert_eq!(format(VarFormat::Unsigned, "hl"), "2");
+    assert_eq!(format(VarFormat::Unsigned, "10x0"), "x");
+    assert_eq!(format(VarFormat::Unsigned, "10z0"), "x");
+    assert_eq!(format(VarFormat::Unsigned, "zzzz"), "x");
+    assert_eq!(format(VarFormat::Unsigned, "-000"), "x");
+}
+
+#[test]
+fn signed() {
+    assert_eq!(format(VarFormat::Signed, "0111"), "7");
+    assert_eq!(format(VarFormat::Signed, "1111"), "-1");
+    assert_eq!(format(VarFormat::Signed, "1000"), "-8");
+    assert_eq!(format(VarFormat::Signed, "0000"), "0");
+    assert_eq!(format(VarFormat::Signed, "1"), "-1");
+    assert_eq!(format(VarFormat::Signed, "1z00"), "x");
+    assert_eq!(format(VarFormat::Signed, "zz"), "x");
+}
+
+#[test]
+fn wide_two_state_bus() {
+    let all_ones = [0xff; 17];
+    let value = || SignalValue::Binary(&all_ones, 136);
+    assert_eq!(VarFormat::Binary.format(value()), "1".repeat(136));
+    assert_eq!(VarFormat::Hexadecimal.format(value()), "f".repeat(34));
+    assert_eq!(
+        VarFormat::Unsigned.format(value()),
+        "87112285931760246646623899502532662132735"
+    );
+    assert_eq!(VarFormat::Signed.format(value()), "-1");
+}
+
+#[test]
+fn wide_mixed_state_bus() {
+    let bits = format!("x{}", "1".repeat(129));
+    assert_eq!(
+        format(VarFormat::Hexadecimal, &bits),
+        format!("X{}", "f".repeat(32))
+    );
+    assert_eq!(format(VarFormat::Unsigned, &bits), "x");
+
+    let bits = format!("{}{}", "z".repeat(128), "0101".repeat(8));
+    assert_eq!(
+        format(VarFormat::Hexadecimal, &bits),
+        format!("{}{}", "z".repeat(32), "5".repeat(8))
+    );
+}
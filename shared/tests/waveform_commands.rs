@@ -0,0 +1,83 @@
+use shared::{waveform_commands, wellen_helpers, CommandError, VarFormat};
+
+const SIMPLE_VCD: &str = "../test_files/simple.vcd";
+const CORRUPT_VCD: &str = "../test_files/corrupt.vcd";
+
+#[test]
+fn corrupt_waveform_loading_fails() {
+    let error = wellen_helpers::read_from_path(CORRUPT_VCD).unwrap_err();
+    let CommandError::WaveformLoadingFailed { path, reason } = error else {
+        panic!("unexpected error: {error:?}")
+    };
+    assert_eq!(path, CORRUPT_VCD);
+    assert!(!reason.is_empty());
+}
+
+#[test]
+fn missing_waveform_loading_fails() {
+    let error = wellen_helpers::read_from_path("../test_files/missing.vcd").unwrap_err();
+    assert!(matches!(error, CommandError::WaveformLoadingFailed { .. }));
+}
+
+#[test]
+fn commands_without_waveform_fail() {
+    assert!(matches!(
+        waveform_commands::hierarchy(None),
+        Err(CommandError::NoWaveformLoaded)
+    ));
+    assert_eq!(
+        waveform_commands::timeline_ruler(None, 1., 800, 0).unwrap_err(),
+        CommandError::NoWaveformLoaded
+    );
+    assert_eq!(
+        waveform_commands::value_at_time(None, 0, 0, VarFormat::Binary).unwrap_err(),
+        CommandError::NoWaveformLoaded
+    );
+    assert_eq!(
+        waveform_commands::values_at_time(None, vec![(0, VarFormat::Binary)], 0).unwrap_err(),
+        CommandError::NoWaveformLoaded
+    );
+    assert_eq!(
+        waveform_commands::unload_signal(None, 0).unwrap_err(),
+        CommandError::NoWaveformLoaded
+    );
+}
+
+#[test]
+fn commands_with_unknown_signal_fail() {
+    let mut waveform = wellen_helpers::read_from_path(SIMPLE_VCD).unwrap();
+    let signal_ref_index = waveform.hierarchy().num_unique_signals();
+    let signal_not_found = CommandError::SignalNotFound { signal_ref_index };
+    assert_eq!(
+        waveform_commands::value_at_time(
+            Some(&mut waveform),
+            signal_ref_index,
+            0,
+            VarFormat::Binary
+        )
+        .unwrap_err(),
+        signal_not_found
+    );
+    assert_eq!(
+        waveform_commands::values_at_time(
+            Some(&mut waveform),
+            vec![(0, VarFormat::Binary), (signal_ref_index, VarFormat::Binary)],
+            0
+        )
+        .unwrap_err(),
+        signal_not_found
+    );
+    assert_eq!(
+        waveform_commands::unload_signal(Some(&mut waveform), signal_ref_index).unwrap_err(),
+        signal_not_found
+    );
+}
+
+#[test]
+fn commands_with_loaded_waveform_succeed() {
+    let mut waveform = wellen_helpers::read_from_path(SIMPLE_VCD).unwrap();
+    assert!(waveform_commands::hierarchy(Some(&waveform)).is_ok());
+    let value = waveform_commands::value_at_time(Some(&mut waveform), 0, 0, VarFormat::Binary);
+    assert!(value.unwrap().is_some());
+    assert!(waveform_commands::unload_signal(Some(&mut waveform), 0).is_ok());
+}
@@ -0,0 +1,102 @@
+use shared::{diff_waveforms, wellen_helpers, TimeAxis, TimeRange};
+
+const SIMPLE_VCD: &str = "../test_files/simple.vcd";
+const SIMPLE_CHANGED_VCD: &str = "../test_files/simple_changed.vcd";
+
+/// Waveform with the var `top.bus` of `width` bits and `changes` as `(time, bits)`
+fn bus_waveform(timescale: &str, width: u32, changes: &[(u64, &str)]) -> wellen::simple::Waveform {
+    let mut vcd = format!(
+        "$timescale {timescale} $end\n\
+        $scope module top $end\n\
+        $var wire {width} ! bus $end\n\
+        $upscope $end\n\
+        $enddefinitions $end\n"
+    );
+    for (time, bits) in changes {
+        vcd.push_str(&format!("#{time}\nb{bits} !\n"));
+    }
+    wellen_helpers::read_from_bytes(vcd.into_bytes()).unwrap()
+}
+
+fn mismatches(
+    waveform_a: &mut wellen::simple::Waveform,
+    waveform_b: &mut wellen::simple::Waveform,
+) -> Vec<TimeRange> {
+    let time_axis = TimeAxis::shared([TimeAxis::new(waveform_a), TimeAxis::new(waveform_b)]);
+    diff_waveforms(waveform_a, waveform_b, time_axis.unwrap())
+        .into_iter()
+        .flat_map(|var_diff| var_diff.mismatches)
+        .collect()
+}
+
+#[test]
+fn same_waveforms_have_no_diffs() {
+    let mut waveform_a = wellen_helpers::read_from_path(SIMPLE_VCD).unwrap();
+    let mut waveform_b = wellen_helpers::read_from_path(SIMPLE_VCD).unwrap();
+    let time_axis = TimeAxis::new(&waveform_a);
+    assert!(diff_waveforms(&mut waveform_a, &mut waveform_b, time_axis).is_empty());
+}
+
+#[test]
+fn vars_with_same_names_are_diffed() {
+    let mut waveform_a = wellen_helpers::read_from_path(SIMPLE_VCD).unwrap();
+    let mut waveform_b = wellen_helpers::read_from_path(SIMPLE_CHANGED_VCD).unwrap();
+    let time_axis = TimeAxis::new(&waveform_a);
+    let var_diffs = diff_waveforms(&mut waveform_a, &mut waveform_b, time_axis);
+    // `simple_tb.s.C` exists only in the changed waveform
+    assert_eq!(var_diffs.len(), 1);
+    let var_diff = &var_diffs[0];
+    assert_eq!(var_diff.full_var_name, "simple_tb.s.B");
+    assert_eq!(var_diff.first_mismatch_time, 50);
+    assert_eq!(var_diff.mismatch_count, 1);
+    assert_eq!(
+        var_diff.mismatches,
+        [TimeRange {
+            start: 50,
+            end: 150
+        }]
+    );
+}
+
+#[test]
+fn diffed_signals_are_unloaded() {
+    let mut waveform_a = wellen_helpers::read_from_path(SIMPLE_VCD).unwrap();
+    let mut waveform_b = wellen_helpers::read_from_path(SIMPLE_CHANGED_VCD).unwrap();
+    let time_axis = TimeAxis::new(&waveform_a);
+    diff_waveforms(&mut waveform_a, &mut waveform_b, time_axis);
+    let signal_ref = waveform_a.hierarchy().iter_vars().next().unwrap().signal_ref();
+    assert!(waveform_a.get_signal(signal_ref).is_none());
+}
+
+#[test]
+fn times_are_rounded_to_shared_time_axis() {
+    let mut waveform_ns = bus_waveform("1ns", 1, &[(0, "0"), (2, "1"), (10, "0")]);
+    // 1500 ps is rounded to 2 ns, 1400 ps to 1 ns
+    let mut waveform_ps = bus_waveform("1ps", 1, &[(0, "0"), (1500, "1"), (10_000, "0")]);
+    assert!(mismatches(&mut waveform_ns, &mut waveform_ps).is_empty());
+    let mut waveform_ps = bus_waveform("1ps", 1, &[(0, "0"), (1400, "1"), (10_000, "0")]);
+    assert_eq!(
+        mismatches(&mut waveform_ns, &mut waveform_ps),
+        [TimeRange { start: 1, end: 2 }]
+    );
+}
+
+#[test]
+fn vars_with_different_bit_widths_differ() {
+    let mut waveform_4_bits = bus_waveform("1ns", 4, &[(0, "0101"), (10, "0110")]);
+    let mut waveform_8_bits = bus_waveform("1ns", 8, &[(0, "00000101"), (10, "00000110")]);
+    assert_eq!(
+        mismatches(&mut waveform_4_bits, &mut waveform_8_bits),
+        [TimeRange { start: 0, end: 10 }]
+    );
+}
+
+#[test]
+fn two_state_and_four_state_values_are_compared_by_bits() {
+    let mut waveform_a = bus_waveform("1ns", 4, &[(0, "0101"), (10, "0110"), (20, "0111")]);
+    let mut waveform_b = bus_waveform("1ns", 4, &[(0, "0101"), (10, "xxxx"), (20, "0111")]);
+    assert_eq!(
+        mismatches(&mut waveform_a, &mut waveform_b),
+        [TimeRange { start: 10, end: 20 }]
+    );
+}
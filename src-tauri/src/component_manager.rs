@@ -1,2 +1,221 @@
+use crate::{logs, APP_HANDLE};
+use once_cell::sync::Lazy;
+use shared::{ComponentInfo, ComponentKind, ComponentStatus, DisabledComponent};
+use std::collections::HashSet;
+use std::sync::Mutex as StdMutex;
+use tauri::Emitter;
+use wasmtime::{Config, Engine, Store, StoreLimits};
+use wasmtime_wasi::WasiCtx;
+
 pub mod decoders;
 pub mod diagram_connectors;
+pub mod protocol_decoders;
+
+mod file_watcher;
+use file_watcher::{unwatch_component_files_except, watch_component_file};
+
+mod capabilities;
+use capabilities::Capabilities;
+
+// Fuel available to a single call into a component,
+// roughly a few seconds of work before the component is considered stuck
+const FUEL_PER_CALL: u64 = 10_000_000_000;
+
+static ENGINE: Lazy<Engine> = Lazy::new(|| {
+    let mut config = Config::new();
+    config.consume_fuel(true);
+    Engine::new(&config).unwrap()
+});
+
+/// `Store` data of components
+trait ComponentState: Sized + Send + 'static {
+    fn new(ctx: WasiCtx, limits: StoreLimits) -> Self;
+
+    fn limits(&mut self) -> &mut StoreLimits;
+}
+
+/// Every component instance has its own `Store`,
+/// so a trapped instance doesn't prevent calls to other components.
+/// The store is sandboxed according to the component's `Capabilities`.
+fn new_store<T: ComponentState>(capabilities: &Capabilities) -> wasmtime::Result<Store<T>> {
+    let state = T::new(capabilities.wasi_ctx()?, capabilities.store_limits());
+    let mut store = Store::new(&ENGINE, state);
+    store.limiter(|state| state.limits());
+    refuel(&mut store);
+    Ok(store)
+}
+
+/// Call before every call into the component
+fn refuel<T>(store: &mut Store<T>) {
+    store.set_fuel(FUEL_PER_CALL).unwrap();
+}
+
+/// `ComponentStatus` readable from sync code, e.g. inside `Iterator::find` closures
+#[derive(Default)]
+struct ComponentStatusCell(StdMutex<ComponentStatus>);
+
+impl ComponentStatusCell {
+    fn get(&self) -> ComponentStatus {
+        self.0.lock().unwrap().clone()
+    }
+
+    fn is_enabled(&self) -> bool {
+        matches!(*self.0.lock().unwrap(), ComponentStatus::Enabled)
+    }
+
+    /// Failed components can't be enabled again, they have to be removed and added again.
+    fn set_enabled(&self, is_enabled: bool) -> bool {
+        let mut status = self.0.lock().unwrap();
+        if let ComponentStatus::Failed { .. } = *status {
+            return false;
+        }
+        *status = if is_enabled {
+            ComponentStatus::Enabled
+        } else {
+            ComponentStatus::Disabled
+        };
+        true
+    }
+
+    /// Status of the reinstantiated component.
+    /// Components disabled by the user stay disabled, failed ones are enabled again.
+    fn reloaded(&self) -> Self {
+        let status = match self.get() {
+            ComponentStatus::Disabled => ComponentStatus::Disabled,
+            ComponentStatus::Enabled | ComponentStatus::Failed { .. } => ComponentStatus::Enabled,
+        };
+        Self(StdMutex::new(status))
+    }
+
+    /// A component can't be entered again after a trap,
+    /// so it's disabled and reported to the user.
+    fn fail(&self, kind: ComponentKind, name: &str, error: &wasmtime::Error) {
+        logs::error(
+            kind.as_static_str(),
+            format!("'{name}' disabled: {error:?}"),
+        );
+        let reason = error.root_cause().to_string();
+        *self.0.lock().unwrap() = ComponentStatus::Failed {
+            reason: reason.clone(),
+        };
+        let disabled_component = DisabledComponent {
+            kind,
+            name: name.to_owned(),
+            reason,
+        };
+        if let Some(app_handle) = APP_HANDLE.read().unwrap().as_ref() {
+            if let Err(error) = app_handle.emit("component_disabled", disabled_component) {
+                eprintln!("Failed to report disabled component: {error:?}");
+            }
+        }
+    }
+}
+
+fn move_item<T>(items: &mut Vec<T>, old_index: usize, new_index: usize) -> bool {
+    if old_index >= items.len() || new_index >= items.len() {
+        return false;
+    }
+    let item = items.remove(old_index);
+    items.insert(new_index, item);
+    true
+}
+
+/// Instantiates `count` new components from `path` for `reload_*` functions.
+/// They are compiled before the components are locked for the swap,
+/// so the compilation doesn't block calls into other components.
+fn instantiate_reloaded_components<T>(
+    kind: ComponentKind,
+    path: &str,
+    count: usize,
+    instantiate: fn(&str) -> wasmtime::Result<T>,
+) -> Vec<T> {
+    let mut components = Vec::with_capacity(count);
+    for _ in 0..count {
+        match instantiate(path) {
+            Ok(component) => components.push(component),
+            Err(error) => {
+                logs::error(
+                    kind.as_static_str(),
+                    format!("Failed to reload '{path}': {error:?}"),
+                );
+                break;
+            }
+        }
+    }
+    components
+}
+
+/// Stops watching files of removed components
+async fn unwatch_removed_component_files() {
+    let component_paths = components()
+        .await
+        .into_iter()
+        .map(|component| component.path)
+        .collect::<HashSet<_>>();
+    unwatch_component_files_except(&component_paths);
+}
+
+/// Reinstantiates all components added from `path`, they keep their positions.
+async fn reload_components(path: &str) {
+    let mut reloaded_components = decoders::reload_decoders(path).await;
+    reloaded_components.extend(diagram_connectors::reload_diagram_connectors(path).await);
+    reloaded_components.extend(protocol_decoders::reload_protocol_decoders(path).await);
+
+    let Some(app_handle) = APP_HANDLE.read().unwrap().clone() else {
+        return;
+    };
+    for reloaded_component in reloaded_components {
+        logs::info(
+            reloaded_component.kind.as_static_str(),
+            format!("'{}' reloaded", reloaded_component.name),
+        );
+        if let Err(error) = app_handle.emit("component_reloaded", reloaded_component) {
+            eprintln!("Failed to report reloaded component: {error:?}");
+        }
+    }
+}
+
+pub async fn components() -> Vec<ComponentInfo> {
+    let mut components = decoders::decoder_infos().await;
+    components.extend(diagram_connectors::diagram_connector_infos().await);
+    components.extend(protocol_decoders::protocol_decoder_infos().await);
+    components
+}
+
+pub async fn set_component_enabled(kind: ComponentKind, index: usize, is_enabled: bool) -> bool {
+    match kind {
+        ComponentKind::Decoder => decoders::set_decoder_enabled(index, is_enabled).await,
+        ComponentKind::DiagramConnector => {
+            diagram_connectors::set_diagram_connector_enabled(index, is_enabled).await
+        }
+        ComponentKind::ProtocolDecoder => {
+            protocol_decoders::set_protocol_decoder_enabled(index, is_enabled).await
+        }
+    }
+}
+
+pub async fn remove_component(kind: ComponentKind, index: usize) -> bool {
+    let is_removed = match kind {
+        ComponentKind::Decoder => decoders::remove_decoder(index).await,
+        ComponentKind::DiagramConnector => {
+            diagram_connectors::remove_diagram_connector(index).await
+        }
+        ComponentKind::ProtocolDecoder => protocol_decoders::remove_protocol_decoder(index).await,
+    };
+    if is_removed {
+        unwatch_removed_component_files().await;
+    }
+    is_removed
+}
+
+pub async fn move_component(kind: ComponentKind, old_index: usize, new_index: usize) -> bool {
+    match kind {
+        ComponentKind::Decoder => decoders::move_decoder(old_index, new_index).await,
+        ComponentKind::DiagramConnector => {
+            diagram_connectors::move_diagram_connector(old_index, new_index).await
+        }
+        ComponentKind::ProtocolDecoder => {
+            protocol_decoders::move_protocol_decoder(old_index, new_index).await
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use wasmtime::{StoreLimits, StoreLimitsBuilder};
+use wasmtime_wasi::{DirPerms, FilePerms, WasiCtx};
+
+const MANIFEST_FILE_NAME: &str = "component.toml";
+
+/// Sandbox capabilities declared in the manifest next to the component `.wasm` file.
+/// The manifest `<component file stem>.component.toml` is used only by its component,
+/// `component.toml` by all components in the folder without their own manifest.
+/// Components without a manifest can't access files or env vars and their memory isn't limited.
+///
+/// ```toml
+/// # Folders relative to the manifest, preopened read-only under the same paths
+/// preopened_dirs = ["lookup_tables"]
+/// # Host env vars visible to the component
+/// env_vars = ["DECODER_LANGUAGE"]
+/// # Max size of the component linear memory
+/// max_memory_bytes = 16_777_216
+/// ```
+#[derive(Deserialize, Default, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct Capabilities {
+    preopened_dirs: Vec<String>,
+    env_vars: Vec<String>,
+    max_memory_bytes: Option<usize>,
+    #[serde(skip)]
+    component_folder: PathBuf,
+}
+
+/// Manifests that may apply to the component, ordered by priority
+pub fn manifest_paths(component_path: &Path) -> [PathBuf; 2] {
+    let component_folder = component_path.parent().unwrap_or(Path::new(""));
+    let mut component_manifest_name = component_path.file_stem().unwrap_or_default().to_owned();
+    component_manifest_name.push(format!(".{MANIFEST_FILE_NAME}"));
+    [
+        component_folder.join(component_manifest_name),
+        component_folder.join(MANIFEST_FILE_NAME),
+    ]
+}
+
+impl Capabilities {
+    pub fn load(component_path: &str) -> wasmtime::Result<Self> {
+        let component_path = Path::new(component_path);
+        let component_folder = component_path
+            .parent()
+            .filter(|folder| !folder.as_os_str().is_empty())
+            .unwrap_or(Path::new("."))
+            .to_path_buf();
+        let manifest_path = manifest_paths(component_path)
+            .into_iter()
+            .find(|manifest_path| manifest_path.is_file());
+        let mut capabilities = match manifest_path {
+            Some(manifest_path) => {
+                let manifest = std::fs::read_to_string(&manifest_path)?;
+                Self::parse(&manifest).map_err(|error| {
+                    wasmtime::Error::msg(format!("Invalid '{}': {error}", manifest_path.display()))
+                })?
+            }
+            None => Self::default(),
+        };
+        capabilities.component_folder = component_folder;
+        Ok(capabilities)
+    }
+
+    fn parse(manifest: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(manifest)
+    }
+
+    /// Canonical host paths of `preopened_dirs` with their guest paths.
+    /// The manifest must not give access to anything outside the component folder,
+    /// not even through `..` or symlinks.
+    fn preopened_dir_paths(&self) -> wasmtime::Result<Vec<(PathBuf, &str)>> {
+        if self.preopened_dirs.is_empty() {
+            return Ok(Vec::new());
+        }
+        let component_folder = std::fs::canonicalize(&self.component_folder)?;
+        let mut dir_paths = Vec::with_capacity(self.preopened_dirs.len());
+        for dir in &self.preopened_dirs {
+            let dir_path = std::fs::canonicalize(component_folder.join(dir)).map_err(|error| {
+                wasmtime::Error::msg(format!("Preopened dir '{dir}' not found: {error}"))
+            })?;
+            if !dir_path.starts_with(&component_folder) {
+                wasmtime::bail!("Preopened dir '{dir}' is not inside the component folder");
+            }
+            dir_paths.push((dir_path, dir.as_str()));
+        }
+        Ok(dir_paths)
+    }
+
+    pub fn wasi_ctx(&self) -> wasmtime::Result<WasiCtx> {
+        let mut builder = WasiCtx::builder();
+        for (dir_path, guest_path) in self.preopened_dir_paths()? {
+            builder.preopened_dir(dir_path, guest_path, DirPerms::READ, FilePerms::READ)?;
+        }
+        for env_var in &self.env_vars {
+            if let Ok(value) = std::env::var(env_var) {
+                builder.env(env_var, value);
+            }
+        }
+        Ok(builder.build())
+    }
+
+    pub fn store_limits(&self) -> StoreLimits {
+        let mut builder = StoreLimitsBuilder::new();
+        if let Some(max_memory_bytes) = self.max_memory_bytes {
+            builder = builder.memory_size(max_memory_bytes);
+        }
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Empty folder in the system temp dir, unique for every test
+    fn test_folder(name: &str) -> PathBuf {
+        let folder = std::env::temp_dir().join(format!(
+            "fastwave_capabilities_{name}_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&folder);
+        std::fs::create_dir_all(&folder).unwrap();
+        folder
+    }
+
+    fn component_path(folder: &Path, file_name: &str) -> String {
+        folder.join(file_name).to_str().unwrap().to_owned()
+    }
+
+    #[test]
+    fn parses_manifest() {
+        let capabilities = Capabilities::parse(
+            r#"
+            preopened_dirs = ["lookup_tables"]
+            env_vars = ["DECODER_LANGUAGE"]
+            max_memory_bytes = 16_777_216
+            "#,
+        )
+        .unwrap();
+        assert_eq!(capabilities.preopened_dirs, ["lookup_tables"]);
+        assert_eq!(capabilities.env_vars, ["DECODER_LANGUAGE"]);
+        assert_eq!(capabilities.max_memory_bytes, Some(16_777_216));
+
+        let capabilities = Capabilities::parse("").unwrap();
+        assert!(capabilities.preopened_dirs.is_empty());
+        assert_eq!(capabilities.max_memory_bytes, None);
+
+        assert!(Capabilities::parse("preopened_dir = [\"typo\"]").is_err());
+        assert!(Capabilities::parse("max_memory_bytes = \"16 MB\"").is_err());
+    }
+
+    #[test]
+    fn component_manifest_overrides_folder_manifest() {
+        let folder = test_folder("manifests");
+        std::fs::write(folder.join("component.toml"), "env_vars = [\"SHARED\"]").unwrap();
+        std::fs::write(folder.join("uart.component.toml"), "env_vars = [\"UART\"]").unwrap();
+
+        let capabilities = Capabilities::load(&component_path(&folder, "uart.wasm")).unwrap();
+        assert_eq!(capabilities.env_vars, ["UART"]);
+        let capabilities = Capabilities::load(&component_path(&folder, "spi.wasm")).unwrap();
+        assert_eq!(capabilities.env_vars, ["SHARED"]);
+
+        std::fs::write(folder.join("i2c.component.toml"), "unknown = 1").unwrap();
+        let error = Capabilities::load(&component_path(&folder, "i2c.wasm")).unwrap_err();
+        assert!(error.to_string().contains("i2c.component.toml"));
+    }
+
+    #[test]
+    fn preopened_dirs_stay_inside_component_folder() {
+        let folder = test_folder("preopened_dirs");
+        let component_folder = folder.join("component");
+        std::fs::create_dir_all(component_folder.join("lookup_tables/uart")).unwrap();
+        std::fs::create_dir_all(folder.join("secrets")).unwrap();
+        let component_path = component_path(&component_folder, "decoder.wasm");
+        let manifest_path = component_folder.join("component.toml");
+
+        std::fs::write(&manifest_path, "preopened_dirs = [\"lookup_tables/uart\"]").unwrap();
+        let capabilities = Capabilities::load(&component_path).unwrap();
+        let dir_paths = capabilities.preopened_dir_paths().unwrap();
+        assert_eq!(dir_paths.len(), 1);
+        assert!(dir_paths[0].0.ends_with("lookup_tables/uart"));
+        assert_eq!(dir_paths[0].1, "lookup_tables/uart");
+
+        for dir in ["../secrets", "lookup_tables/../../secrets", "missing"] {
+            std::fs::write(&manifest_path, format!("preopened_dirs = [{dir:?}]")).unwrap();
+            let capabilities = Capabilities::load(&component_path).unwrap();
+            assert!(capabilities.preopened_dir_paths().is_err(), "{dir}");
+        }
+        let absolute_dir = folder.join("secrets");
+        std::fs::write(
+            &manifest_path,
+            format!("preopened_dirs = [{:?}]", absolute_dir.to_str().unwrap()),
+        )
+        .unwrap();
+        let capabilities = Capabilities::load(&component_path).unwrap();
+        assert!(capabilities.preopened_dir_paths().is_err());
+
+        // Symlinks pointing outside of the component folder are rejected as well
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(folder.join("secrets"), component_folder.join("link"))
+                .unwrap();
+            std::fs::write(&manifest_path, "preopened_dirs = [\"link\"]").unwrap();
+            let capabilities = Capabilities::load(&component_path).unwrap();
+            assert!(capabilities.preopened_dir_paths().is_err());
+        }
+    }
+}
@@ -1,35 +1,85 @@
-use crate::{AddedDecodersCount, DecoderPath, RemovedDecodersCount};
+use super::{
+    instantiate_reloaded_components, move_item, new_store, refuel, unwatch_removed_component_files,
+    watch_component_file, Capabilities, ComponentState, ComponentStatusCell, ENGINE,
+};
+use crate::{logs, AddedDecodersCount, DecoderName, DecoderPath, RemovedDecodersCount};
+use lru::LruCache;
 use once_cell::sync::Lazy;
+use shared::{ComponentInfo, ComponentKind, DecodedValue, DecodersCacheStats, VarFormat};
+use std::num::NonZeroUsize;
 use std::sync::Arc;
 use tauri::async_runtime::{Mutex, RwLock};
 use wasmtime::component::{Component as WasmtimeComponent, *};
-use wasmtime::{AsContextMut, Engine, Store};
+use wasmtime::{Store, StoreLimits};
 use wasmtime_wasi::{WasiCtx, WasiView};
 
 bindgen!(in "wit/decoder");
 
-pub static DECODERS: Lazy<Arc<RwLock<Vec<Component>>>> = Lazy::new(<_>::default);
-static ENGINE: Lazy<Engine> = Lazy::new(<_>::default);
+// Decoders implementing `wit/decoder_v2`, loaded side by side with the original ones
+mod v2 {
+    wasmtime::component::bindgen!(in "wit/decoder_v2");
+}
+use v2::exports::component::decoder_v2::decoder as decoder_v2;
+
+static DECODERS: Lazy<Arc<RwLock<Vec<Decoder>>>> = Lazy::new(<_>::default);
 static LINKER: Lazy<Linker<State>> = Lazy::new(|| {
     let mut linker = Linker::new(&ENGINE);
     wasmtime_wasi::add_to_linker_sync(&mut linker).unwrap();
     Component::add_to_linker(&mut linker, |state: &mut State| state).unwrap();
     linker
 });
-pub static STORE: Lazy<Arc<Mutex<Store<State>>>> = Lazy::new(|| {
-    let store = Store::new(
-        &ENGINE,
-        State {
-            ctx: WasiCtx::builder().build(),
-            table: ResourceTable::new(),
-        },
-    );
-    Arc::new(Mutex::new(store))
+static LINKER_V2: Lazy<Linker<State>> = Lazy::new(|| {
+    let mut linker = Linker::new(&ENGINE);
+    wasmtime_wasi::add_to_linker_sync(&mut linker).unwrap();
+    v2::Component::add_to_linker(&mut linker, |state: &mut State| state).unwrap();
+    linker
 });
+static DECODED_VALUES_CACHE: Lazy<Mutex<DecodedValuesCache>> = Lazy::new(<_>::default);
+
+struct Decoder {
+    name: DecoderName,
+    path: DecoderPath,
+    component: DecoderComponent,
+    store: Mutex<Store<State>>,
+    // Disabled decoders pass values through unchanged
+    status: ComponentStatusCell,
+}
+
+impl Decoder {
+    fn info(&self, index: usize) -> ComponentInfo {
+        ComponentInfo {
+            kind: ComponentKind::Decoder,
+            index,
+            name: self.name.clone(),
+            path: self.path.clone(),
+            status: self.status.get(),
+        }
+    }
+}
+
+enum DecoderComponent {
+    V1(Component),
+    V2(v2::Component),
+}
 
 pub struct State {
     ctx: WasiCtx,
     table: ResourceTable,
+    limits: StoreLimits,
+}
+
+impl ComponentState for State {
+    fn new(ctx: WasiCtx, limits: StoreLimits) -> Self {
+        Self {
+            ctx,
+            table: ResourceTable::new(),
+            limits,
+        }
+    }
+
+    fn limits(&mut self) -> &mut StoreLimits {
+        &mut self.limits
+    }
 }
 
 impl WasiView for State {
@@ -43,14 +93,233 @@ impl WasiView for State {
 
 impl component::decoder::host::Host for State {
     fn log(&mut self, message: String) {
-        println!("Decoder: {message}");
+        logs::info("Decoder", message);
+    }
+}
+
+impl v2::component::decoder_v2::host::Host for State {
+    fn log(&mut self, message: String) {
+        logs::info("Decoder", message);
+    }
+}
+
+impl From<VarFormat> for decoder_v2::VarFormat {
+    fn from(var_format: VarFormat) -> Self {
+        match var_format {
+            VarFormat::ASCII => decoder_v2::VarFormat::Ascii,
+            VarFormat::Binary => decoder_v2::VarFormat::Binary,
+            VarFormat::BinaryWithGroups => decoder_v2::VarFormat::BinaryWithGroups,
+            VarFormat::Hexadecimal => decoder_v2::VarFormat::Hexadecimal,
+            VarFormat::Octal => decoder_v2::VarFormat::Octal,
+            VarFormat::Signed => decoder_v2::VarFormat::Signed,
+            VarFormat::Unsigned => decoder_v2::VarFormat::Unsigned,
+        }
+    }
+}
+
+type DecodersVersion = u64;
+type VarName = String;
+type RawBits = String;
+
+/// Block value passed to the decoder assigned to the var
+pub struct ValueToDecode {
+    pub var_name: VarName,
+    pub var_format: VarFormat,
+    pub raw_bits: RawBits,
+    /// `raw_bits` formatted by `var_format`
+    pub formatted_value: String,
+    pub time: wellen::Time,
+}
+
+// Enough for values of all blocks visible on a few screens
+const DECODED_VALUES_CACHE_CAPACITY: usize = 10_000;
+
+#[derive(PartialEq, Eq, Hash)]
+struct DecodedValuesCacheKey {
+    decoders_version: DecodersVersion,
+    decoder_name: DecoderName,
+    var_format: VarFormat,
+    formatted_value: String,
+    // Only v2 decoders receive raw bits, var names and times
+    v2_input: Option<(RawBits, VarName, wellen::Time)>,
+}
+
+struct DecodedValuesCache {
+    values: LruCache<DecodedValuesCacheKey, DecodedValue>,
+    // Incremented on every change of `DECODERS`
+    decoders_version: DecodersVersion,
+    hits: u64,
+    misses: u64,
+}
+
+impl Default for DecodedValuesCache {
+    fn default() -> Self {
+        Self {
+            values: LruCache::new(NonZeroUsize::new(DECODED_VALUES_CACHE_CAPACITY).unwrap()),
+            decoders_version: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+}
+
+async fn invalidate_decoded_values_cache() {
+    let mut cache = DECODED_VALUES_CACHE.lock().await;
+    cache.decoders_version += 1;
+    cache.values.clear();
+}
+
+pub async fn decoders_cache_stats() -> DecodersCacheStats {
+    let cache = DECODED_VALUES_CACHE.lock().await;
+    DecodersCacheStats {
+        hits: cache.hits,
+        misses: cache.misses,
+        len: cache.values.len(),
+        capacity: cache.values.cap().get(),
     }
 }
 
+/// Names of enabled decoders
+pub async fn decoder_names() -> Vec<DecoderName> {
+    DECODERS
+        .read()
+        .await
+        .iter()
+        .filter(|decoder| decoder.status.is_enabled())
+        .map(|decoder| decoder.name.clone())
+        .collect()
+}
+
+pub async fn decoder_infos() -> Vec<ComponentInfo> {
+    DECODERS
+        .read()
+        .await
+        .iter()
+        .enumerate()
+        .map(|(index, decoder)| decoder.info(index))
+        .collect()
+}
+
+pub async fn set_decoder_enabled(index: usize, is_enabled: bool) -> bool {
+    let decoders = DECODERS.read().await;
+    let Some(decoder) = decoders.get(index) else {
+        return false;
+    };
+    let is_changed = decoder.status.set_enabled(is_enabled);
+    drop(decoders);
+    invalidate_decoded_values_cache().await;
+    is_changed
+}
+
+pub async fn remove_decoder(index: usize) -> bool {
+    let mut decoders = DECODERS.write().await;
+    if index >= decoders.len() {
+        return false;
+    }
+    decoders.remove(index);
+    drop(decoders);
+    invalidate_decoded_values_cache().await;
+    true
+}
+
+/// The order affects the order of decoders offered for vars
+pub async fn move_decoder(old_index: usize, new_index: usize) -> bool {
+    move_item(&mut *DECODERS.write().await, old_index, new_index)
+}
+
+/// Passes `value` through the decoder named `decoder_name`.
+/// The formatted value is returned unchanged when no such decoder has been added.
+pub async fn decode_signal_value(
+    decoder_name: Option<DecoderName>,
+    value: ValueToDecode,
+) -> DecodedValue {
+    let Some(decoder_name) = decoder_name else {
+        return value.formatted_value.into();
+    };
+    let decoders = DECODERS.read().await;
+    let Some(decoder) = decoders
+        .iter()
+        .find(|decoder| decoder.name == decoder_name && decoder.status.is_enabled())
+    else {
+        return value.formatted_value.into();
+    };
+
+    let mut cache = DECODED_VALUES_CACHE.lock().await;
+    let cache_key = DecodedValuesCacheKey {
+        decoders_version: cache.decoders_version,
+        decoder_name,
+        var_format: value.var_format,
+        formatted_value: value.formatted_value.clone(),
+        v2_input: match decoder.component {
+            DecoderComponent::V1(_) => None,
+            DecoderComponent::V2(_) => {
+                Some((value.raw_bits.clone(), value.var_name.clone(), value.time))
+            }
+        },
+    };
+    if let Some(decoded_value) = cache.values.get(&cache_key).cloned() {
+        cache.hits += 1;
+        return decoded_value;
+    }
+    cache.misses += 1;
+    drop(cache);
+
+    let mut store = decoder.store.lock().await;
+    refuel(&mut store);
+
+    let decoded_value = match &decoder.component {
+        DecoderComponent::V1(component) => component
+            .component_decoder_decoder()
+            .call_format_signal_value(&mut *store, &value.formatted_value)
+            .map(DecodedValue::from),
+        DecoderComponent::V2(component) => {
+            let signal_value = decoder_v2::SignalValue {
+                var_name: value.var_name,
+                bit_width: value.raw_bits.len() as u32,
+                raw_bits: value.raw_bits,
+                formatted_value: value.formatted_value,
+                format: value.var_format.into(),
+                time: value.time,
+            };
+            component
+                .component_decoder_v2_decoder()
+                .call_decode_signal_value(&mut *store, &signal_value)
+                .map(|decoded_value| DecodedValue {
+                    text: decoded_value.label,
+                    color: decoded_value.color,
+                    tooltip: decoded_value.tooltip,
+                    is_unknown: decoded_value.unknown,
+                })
+        }
+    };
+    drop(store);
+
+    let decoded_value = match decoded_value {
+        Ok(decoded_value) => decoded_value,
+        Err(error) => {
+            decoder
+                .status
+                .fail(ComponentKind::Decoder, &decoder.name, &error);
+            invalidate_decoded_values_cache().await;
+            return cache_key.formatted_value.into();
+        }
+    };
+
+    DECODED_VALUES_CACHE
+        .lock()
+        .await
+        .values
+        .put(cache_key, decoded_value.clone());
+    decoded_value
+}
+
 pub async fn remove_all_decoders() -> RemovedDecodersCount {
     let mut decoders = DECODERS.write().await;
     let decoders_count = decoders.len();
     decoders.clear();
+    drop(decoders);
+    invalidate_decoded_values_cache().await;
+    unwatch_removed_component_files().await;
     decoders_count
 }
 
@@ -90,7 +359,10 @@ pub async fn add_decoders(decoder_paths: Vec<DecoderPath>) -> AddedDecodersCount
     // futures::executor::block_on(async move {
     for decoder_path in decoder_paths {
         if let Err(error) = add_decoder(&decoder_path).await {
-            eprintln!("add_decoders error: {error:?}");
+            logs::error(
+                "Decoder",
+                format!("Failed to add '{decoder_path}': {error:?}"),
+            );
         } else {
             added_decoders_count += 1;
         }
@@ -102,24 +374,82 @@ pub async fn add_decoders(decoder_paths: Vec<DecoderPath>) -> AddedDecodersCount
 }
 
 async fn add_decoder(path: &str) -> wasmtime::Result<()> {
-    let wasmtime_component = WasmtimeComponent::from_file(&ENGINE, path)?;
+    let decoder = instantiate_decoder(path)?;
+    logs::info("Decoder", format!("'{}' added", decoder.name));
+    DECODERS.write().await.push(decoder);
+    invalidate_decoded_values_cache().await;
+    watch_component_file(path);
+    Ok(())
+}
 
-    let mut store_lock = STORE.lock().await;
-    let mut store = store_lock.as_context_mut();
+/// Reinstantiates decoders added from `path` and reruns their `init`
+pub async fn reload_decoders(path: &str) -> Vec<ComponentInfo> {
+    let count = DECODERS
+        .read()
+        .await
+        .iter()
+        .filter(|decoder| decoder.path == path)
+        .count();
+    let mut new_decoders =
+        instantiate_reloaded_components(ComponentKind::Decoder, path, count, instantiate_decoder)
+            .into_iter();
 
-    let component = Component::instantiate(&mut store, &wasmtime_component, &LINKER)?;
+    let mut decoders = DECODERS.write().await;
+    let mut reloaded_decoders = Vec::new();
+    for (index, decoder) in decoders.iter_mut().enumerate() {
+        if decoder.path != path {
+            continue;
+        }
+        let Some(reloaded_decoder) = new_decoders.next() else {
+            break;
+        };
+        let status = decoder.status.reloaded();
+        *decoder = reloaded_decoder;
+        decoder.status = status;
+        reloaded_decoders.push(decoder.info(index));
+    }
+    drop(decoders);
+    if !reloaded_decoders.is_empty() {
+        invalidate_decoded_values_cache().await;
+    }
+    reloaded_decoders
+}
 
-    println!(
-        "Decoder name: {}",
-        component
-            .component_decoder_decoder()
-            .call_name(&mut store)?
-    );
-    component
-        .component_decoder_decoder()
-        .call_init(&mut store)?;
+fn instantiate_decoder(path: &str) -> wasmtime::Result<Decoder> {
+    let wasmtime_component = WasmtimeComponent::from_file(&ENGINE, path)?;
 
-    DECODERS.write().await.push(component);
+    let capabilities = Capabilities::load(path)?;
 
-    Ok(())
+    // Instantiation fails when the component doesn't implement the world
+    let mut store = new_store::<State>(&capabilities)?;
+    let (name, component) =
+        match v2::Component::instantiate(&mut store, &wasmtime_component, &LINKER_V2) {
+            Ok(component) => {
+                let decoder = component.component_decoder_v2_decoder();
+                let name = decoder.call_name(&mut store)?;
+                decoder.call_init(&mut store)?;
+                (name, DecoderComponent::V2(component))
+            }
+            Err(v2_error) => {
+                logs::info(
+                    "Decoder",
+                    format!(
+                        "Loading '{path}' as a v1 decoder, v2 instantiation failed: {v2_error:?}"
+                    ),
+                );
+                store = new_store(&capabilities)?;
+                let component = Component::instantiate(&mut store, &wasmtime_component, &LINKER)?;
+                let decoder = component.component_decoder_decoder();
+                let name = decoder.call_name(&mut store)?;
+                decoder.call_init(&mut store)?;
+                (name, DecoderComponent::V1(component))
+            }
+        };
+    Ok(Decoder {
+        name,
+        path: path.to_owned(),
+        component,
+        store: Mutex::new(store),
+        status: ComponentStatusCell::default(),
+    })
 }
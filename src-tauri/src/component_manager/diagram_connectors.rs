@@ -1,41 +1,71 @@
+use super::{
+    instantiate_reloaded_components, move_item, new_store, refuel, unwatch_removed_component_files,
+    watch_component_file, Capabilities, ComponentState, ComponentStatusCell, ENGINE,
+};
 use crate::{
-    AddedDiagramConnectorsCount, ComponentId, DiagramConnectorName, DiagramConnectorPath,
+    logs, AddedDiagramConnectorsCount, ComponentId, DiagramConnectorName, DiagramConnectorPath,
     RemovedDiagramConnectorsCount, APP_HANDLE, WAVEFORM,
 };
 use once_cell::sync::Lazy;
-use shared::{DiagramConnectorMessage, VarFormat};
+use shared::{ComponentInfo, ComponentKind, DiagramConnectorMessage, VarFormat};
 use std::sync::Arc;
 use tauri::async_runtime::{Mutex, RwLock};
 use tauri::Emitter;
 use wasmtime::component::{Component as WasmtimeComponent, *};
-use wasmtime::{AsContextMut, Engine, Store};
+use wasmtime::{Store, StoreLimits};
 use wasmtime_wasi::{WasiCtx, WasiView};
 use wellen::GetItem;
 
 bindgen!(in "wit/diagram_connector");
 
-pub static DIAGRAM_CONNECTORS: Lazy<Arc<RwLock<Vec<Component>>>> = Lazy::new(<_>::default);
-static ENGINE: Lazy<Engine> = Lazy::new(<_>::default);
+use component::diagram_connector::host;
+
+static DIAGRAM_CONNECTORS: Lazy<Arc<RwLock<Vec<DiagramConnector>>>> = Lazy::new(<_>::default);
 static LINKER: Lazy<Linker<State>> = Lazy::new(|| {
     let mut linker = Linker::new(&ENGINE);
     wasmtime_wasi::add_to_linker_sync(&mut linker).unwrap();
     Component::add_to_linker(&mut linker, |state: &mut State| state).unwrap();
     linker
 });
-pub static STORE: Lazy<Arc<Mutex<Store<State>>>> = Lazy::new(|| {
-    let store = Store::new(
-        &ENGINE,
-        State {
-            ctx: WasiCtx::builder().build(),
-            table: ResourceTable::new(),
-        },
-    );
-    Arc::new(Mutex::new(store))
-});
+struct DiagramConnector {
+    name: DiagramConnectorName,
+    path: DiagramConnectorPath,
+    component: Component,
+    store: Mutex<Store<State>>,
+    // Disabled diagram connectors aren't notified about diagram changes
+    status: ComponentStatusCell,
+}
+
+impl DiagramConnector {
+    fn info(&self, index: usize) -> ComponentInfo {
+        ComponentInfo {
+            kind: ComponentKind::DiagramConnector,
+            index,
+            name: self.name.clone(),
+            path: self.path.clone(),
+            status: self.status.get(),
+        }
+    }
+}
 
 pub struct State {
     ctx: WasiCtx,
     table: ResourceTable,
+    limits: StoreLimits,
+}
+
+impl ComponentState for State {
+    fn new(ctx: WasiCtx, limits: StoreLimits) -> Self {
+        Self {
+            ctx,
+            table: ResourceTable::new(),
+            limits,
+        }
+    }
+
+    fn limits(&mut self) -> &mut StoreLimits {
+        &mut self.limits
+    }
 }
 
 impl WasiView for State {
@@ -47,9 +77,9 @@ impl WasiView for State {
     }
 }
 
-impl component::diagram_connector::host::Host for State {
+impl host::Host for State {
     fn log(&mut self, message: String) {
-        println!("Diagram Connector: {message}");
+        logs::info("Diagram Connector", message);
     }
 
     fn listen_for_component_text_changes(
@@ -82,91 +112,91 @@ impl component::diagram_connector::host::Host for State {
     }
 
     // @TODO `resource` in WIT or async in the future
-    // @TODO move business logic to the diagram connector
-    fn address_and_way(&mut self, time_text: String) -> Result<(String, Option<u32>), ()> {
-        let input_time = time_text.parse::<u64>().map_err(|error| {
-            eprintln!("Failed to parse time_text '{time_text}', error: {error:#}");
-        })?;
-
+    fn lookup_var(&mut self, path: String) -> Option<host::VarHandle> {
         let waveform_wrapper = WAVEFORM.read().unwrap();
-        let mut maybe_waveform = waveform_wrapper.try_write().unwrap();
-        let waveform = maybe_waveform.as_mut().unwrap();
+        let mut maybe_waveform = waveform_wrapper
+            .try_write()
+            .map_err(|_| waveform_busy_warning())
+            .ok()?;
+        let waveform = maybe_waveform.as_mut()?;
+
+        let (scope_path, var_name) = match path.rsplit_once('.') {
+            Some((scope_path, var_name)) => (scope_path.split('.').collect(), var_name),
+            None => (Vec::new(), path.as_str()),
+        };
 
         let hierarchy = waveform.hierarchy();
+        let var_ref = hierarchy.lookup_var(&scope_path, &var_name)?;
+        let signal_ref = hierarchy.get(var_ref).signal_ref();
+        waveform.load_signals_multi_threaded(&[signal_ref]);
 
-        // @TODO remove
-        // let timescale = hierarchy.timescale().unwrap();
-        // println!("Timescale: {timescale:#?}");
+        Some(signal_ref.index() as host::VarHandle)
+    }
 
-        let refill_valid_ref = hierarchy
-            .lookup_var(
-                &["TOP", "VexiiRiscv"],
-                &"FetchL1Plugin_logic_refill_start_valid",
-            )
-            .unwrap();
-        let refill_address_ref = hierarchy
-            .lookup_var(
-                &["TOP", "VexiiRiscv"],
-                &"FetchL1Plugin_logic_refill_start_address",
-            )
-            .unwrap();
-        let refill_way_ref = hierarchy
-            .lookup_var(
-                &["TOP", "VexiiRiscv"],
-                &"FetchL1Plugin_logic_refill_start_wayToAllocate",
-            )
-            .unwrap();
+    fn value_at(
+        &mut self,
+        var: host::VarHandle,
+        time: host::Time,
+        format: host::VarFormat,
+    ) -> Option<String> {
+        let waveform_wrapper = WAVEFORM.read().unwrap();
+        let maybe_waveform = waveform_wrapper
+            .try_read()
+            .map_err(|_| waveform_busy_warning())
+            .ok()?;
+        let waveform = maybe_waveform.as_ref()?;
+
+        let signal = waveform.get_signal(wellen::SignalRef::from_index(var as usize)?)?;
+        shared::value_at_time(signal, waveform.time_table(), time, format.into())
+    }
 
-        let refill_valid_var = hierarchy.get(refill_valid_ref);
-        let refill_address_var = hierarchy.get(refill_address_ref);
-        let refill_way_var = hierarchy.get(refill_way_ref);
+    fn next_change(&mut self, var: host::VarHandle, time: host::Time) -> Option<host::Time> {
+        let waveform_wrapper = WAVEFORM.read().unwrap();
+        let maybe_waveform = waveform_wrapper
+            .try_read()
+            .map_err(|_| waveform_busy_warning())
+            .ok()?;
+        let waveform = maybe_waveform.as_ref()?;
+
+        let signal = waveform.get_signal(wellen::SignalRef::from_index(var as usize)?)?;
+        shared::next_change_time(signal, waveform.time_table(), time)
+    }
 
-        let refill_valid_signal_ref = refill_valid_var.signal_ref();
-        let refill_address_signal_ref = refill_address_var.signal_ref();
-        let refill_way_signal_ref = refill_way_var.signal_ref();
+    fn timescale(&mut self) -> Option<host::WaveformTimescale> {
+        let waveform_wrapper = WAVEFORM.read().unwrap();
+        let maybe_waveform = waveform_wrapper
+            .try_read()
+            .map_err(|_| waveform_busy_warning())
+            .ok()?;
+        let timescale = maybe_waveform.as_ref()?.hierarchy().timescale()?;
+
+        Some(host::WaveformTimescale {
+            factor: timescale.factor,
+            exponent: timescale.unit.to_exponent()?,
+        })
+    }
+}
 
-        let mut time_table_idx = None;
-        for (idx, time) in waveform.time_table().iter().enumerate() {
-            if *time >= input_time {
-                time_table_idx = Some(idx as u32);
-                break;
-            }
-        }
-        let Some(time_table_idx) = time_table_idx else {
-            eprintln!("time_table_idx is None");
-            Err(())?
-        };
+// Host functions are called from async commands so they can't block on the waveform lock
+// held by a running waveform load
+fn waveform_busy_warning() {
+    logs::warning(
+        "Diagram Connector",
+        "The waveform is being loaded, try again once the loading is finished",
+    );
+}
 
-        waveform.load_signals_multi_threaded(&[
-            refill_valid_signal_ref,
-            refill_address_signal_ref,
-            refill_way_signal_ref,
-        ]);
-
-        let refill_valid_signal = waveform.get_signal(refill_valid_signal_ref).unwrap();
-        let refill_valid_offset = refill_valid_signal.get_offset(time_table_idx).unwrap();
-        let refill_valid_value = refill_valid_signal
-            .get_value_at(&refill_valid_offset, 0)
-            .to_string();
-
-        let refill_address_signal = waveform.get_signal(refill_address_signal_ref).unwrap();
-        let refill_address_offset = refill_address_signal.get_offset(time_table_idx).unwrap();
-        let refill_address_value = refill_address_signal.get_value_at(&refill_address_offset, 0);
-        let refill_address_value = VarFormat::Hexadecimal.format(refill_address_value);
-
-        if refill_valid_value == "0" {
-            return Ok((refill_address_value, None));
+impl From<host::VarFormat> for VarFormat {
+    fn from(var_format: host::VarFormat) -> Self {
+        match var_format {
+            host::VarFormat::Ascii => VarFormat::ASCII,
+            host::VarFormat::Binary => VarFormat::Binary,
+            host::VarFormat::BinaryWithGroups => VarFormat::BinaryWithGroups,
+            host::VarFormat::Hexadecimal => VarFormat::Hexadecimal,
+            host::VarFormat::Octal => VarFormat::Octal,
+            host::VarFormat::Signed => VarFormat::Signed,
+            host::VarFormat::Unsigned => VarFormat::Unsigned,
         }
-
-        let refill_way_signal = waveform.get_signal(refill_way_signal_ref).unwrap();
-        let refill_way_offset = refill_way_signal.get_offset(time_table_idx).unwrap();
-        let refill_way_value = refill_way_signal.get_value_at(&refill_way_offset, 0);
-        let refill_way_value = VarFormat::Unsigned.format(refill_way_value);
-
-        Ok((
-            refill_address_value,
-            Some(refill_way_value.parse().unwrap()),
-        ))
     }
 }
 
@@ -174,9 +204,42 @@ pub async fn remove_all_diagram_connectors() -> RemovedDiagramConnectorsCount {
     let mut diagram_connectors = DIAGRAM_CONNECTORS.write().await;
     let diagram_connectors_count = diagram_connectors.len();
     diagram_connectors.clear();
+    drop(diagram_connectors);
+    unwatch_removed_component_files().await;
     diagram_connectors_count
 }
 
+pub async fn diagram_connector_infos() -> Vec<ComponentInfo> {
+    DIAGRAM_CONNECTORS
+        .read()
+        .await
+        .iter()
+        .enumerate()
+        .map(|(index, diagram_connector)| diagram_connector.info(index))
+        .collect()
+}
+
+pub async fn set_diagram_connector_enabled(index: usize, is_enabled: bool) -> bool {
+    DIAGRAM_CONNECTORS
+        .read()
+        .await
+        .get(index)
+        .is_some_and(|diagram_connector| diagram_connector.status.set_enabled(is_enabled))
+}
+
+pub async fn remove_diagram_connector(index: usize) -> bool {
+    let mut diagram_connectors = DIAGRAM_CONNECTORS.write().await;
+    if index >= diagram_connectors.len() {
+        return false;
+    }
+    diagram_connectors.remove(index);
+    true
+}
+
+pub async fn move_diagram_connector(old_index: usize, new_index: usize) -> bool {
+    move_item(&mut *DIAGRAM_CONNECTORS.write().await, old_index, new_index)
+}
+
 // @TODO Make println work on Windows in release mode?
 // https://github.com/tauri-apps/tauri/discussions/8626
 
@@ -208,7 +271,10 @@ pub async fn add_diagram_connectors(
     // futures::executor::block_on(async move {
     for diagram_connector_path in diagram_connector_paths {
         if let Err(error) = add_diagram_connector(&diagram_connector_path).await {
-            eprintln!("add_diagram_connectors error: {error:?}");
+            logs::error(
+                "Diagram Connector",
+                format!("Failed to add '{diagram_connector_path}': {error:?}"),
+            );
         } else {
             added_diagram_connectors_count += 1;
         }
@@ -220,54 +286,100 @@ pub async fn add_diagram_connectors(
 }
 
 async fn add_diagram_connector(path: &str) -> wasmtime::Result<()> {
+    let diagram_connector = instantiate_diagram_connector(path)?;
+    logs::info(
+        "Diagram Connector",
+        format!("'{}' added", diagram_connector.name),
+    );
+    DIAGRAM_CONNECTORS.write().await.push(diagram_connector);
+    watch_component_file(path);
+    Ok(())
+}
+
+/// Reinstantiates diagram connectors added from `path` and reruns their `init`
+pub async fn reload_diagram_connectors(path: &str) -> Vec<ComponentInfo> {
+    let count = DIAGRAM_CONNECTORS
+        .read()
+        .await
+        .iter()
+        .filter(|diagram_connector| diagram_connector.path == path)
+        .count();
+    let mut new_diagram_connectors = instantiate_reloaded_components(
+        ComponentKind::DiagramConnector,
+        path,
+        count,
+        instantiate_diagram_connector,
+    )
+    .into_iter();
+
+    let mut diagram_connectors = DIAGRAM_CONNECTORS.write().await;
+    let mut reloaded_diagram_connectors = Vec::new();
+    for (index, diagram_connector) in diagram_connectors.iter_mut().enumerate() {
+        if diagram_connector.path != path {
+            continue;
+        }
+        let Some(reloaded_diagram_connector) = new_diagram_connectors.next() else {
+            break;
+        };
+        let status = diagram_connector.status.reloaded();
+        *diagram_connector = reloaded_diagram_connector;
+        diagram_connector.status = status;
+        reloaded_diagram_connectors.push(diagram_connector.info(index));
+    }
+    reloaded_diagram_connectors
+}
+
+fn instantiate_diagram_connector(path: &str) -> wasmtime::Result<DiagramConnector> {
     let wasmtime_component = WasmtimeComponent::from_file(&ENGINE, path)?;
 
-    let mut store_lock = STORE.lock().await;
-    let mut store = store_lock.as_context_mut();
+    let capabilities = Capabilities::load(path)?;
+    let mut store = new_store::<State>(&capabilities)?;
 
     let component = Component::instantiate(&mut store, &wasmtime_component, &LINKER)?;
 
-    println!(
-        "Diagram Connector name: {}",
-        component
-            .component_diagram_connector_diagram_connector()
-            .call_name(&mut store)?
-    );
+    let name = component
+        .component_diagram_connector_diagram_connector()
+        .call_name(&mut store)?;
     component
         .component_diagram_connector_diagram_connector()
         .call_init(&mut store)?;
 
-    DIAGRAM_CONNECTORS.write().await.push(component);
-
-    Ok(())
+    Ok(DiagramConnector {
+        name,
+        path: path.to_owned(),
+        component,
+        store: Mutex::new(store),
+        status: ComponentStatusCell::default(),
+    })
 }
 
 // @TODO rename `ComponentId` everywhere to something like `DiagramElementId`?
-// @TODO get rid of unwraps
 pub async fn notify_diagram_connector_text_change(
     diagram_connector: DiagramConnectorName,
     component_id: ComponentId,
     text: String,
 ) {
-    let mut store_lock = STORE.lock().await;
-    let mut store = store_lock.as_context_mut();
-
     let diagram_connectors = DIAGRAM_CONNECTORS.read().await;
 
     // @TODO store diagram_collectors in a hashmap/btreemap?
-    let diagram_connector = diagram_connectors
-        .iter()
-        .find(|diagram_collector| {
-            let name = diagram_collector
-                .component_diagram_connector_diagram_connector()
-                .call_name(&mut store)
-                .unwrap();
-            name == diagram_connector
-        })
-        .unwrap();
+    let Some(diagram_connector) = diagram_connectors.iter().find(|diagram_collector| {
+        diagram_collector.name == diagram_connector && diagram_collector.status.is_enabled()
+    }) else {
+        return;
+    };
 
-    diagram_connector
+    let mut store = diagram_connector.store.lock().await;
+    refuel(&mut store);
+
+    let result = diagram_connector
+        .component
         .component_diagram_connector_diagram_connector()
-        .call_on_component_text_changed(&mut store, &component_id, &text)
-        .unwrap();
+        .call_on_component_text_changed(&mut *store, &component_id, &text);
+    if let Err(error) = result {
+        diagram_connector.status.fail(
+            ComponentKind::DiagramConnector,
+            &diagram_connector.name,
+            &error,
+        );
+    }
 }
@@ -0,0 +1,157 @@
+use super::capabilities::manifest_paths;
+use crate::logs;
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use once_cell::sync::Lazy;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Mutex as StdMutex;
+use std::time::Duration;
+use tokio::time::sleep;
+
+type ComponentPath = String;
+
+// Compilers write `.wasm` files in several steps,
+// so the reload is delayed until the file is most likely complete
+const RELOAD_DELAY: Duration = Duration::from_millis(300);
+
+static WATCHER: Lazy<StdMutex<Option<RecommendedWatcher>>> = Lazy::new(<_>::default);
+// Canonical file paths mapped to the paths used when the components were added,
+// a shared manifest belongs to several components
+static WATCHED_FILES: Lazy<StdMutex<HashMap<PathBuf, HashSet<ComponentPath>>>> =
+    Lazy::new(<_>::default);
+static PENDING_RELOADS: Lazy<StdMutex<HashSet<ComponentPath>>> = Lazy::new(<_>::default);
+
+/// Reloads components added from `path` on every change of the file or its manifests.
+///
+/// The parent directory is watched instead of the file itself
+/// because many tools replace the file instead of modifying it.
+pub fn watch_component_file(path: &str) {
+    let canonical_path = match std::fs::canonicalize(path) {
+        Ok(canonical_path) => canonical_path,
+        Err(error) => {
+            logs::error(
+                "Component file watcher",
+                format!("Failed to watch '{path}': {error:?}"),
+            );
+            return;
+        }
+    };
+    let Some(folder) = canonical_path.parent().map(PathBuf::from) else {
+        return;
+    };
+
+    // Manifests don't have to exist yet, their paths are derived from the canonical component path
+    let [component_manifest_path, folder_manifest_path] = manifest_paths(&canonical_path);
+
+    let mut watched_files = WATCHED_FILES.lock().unwrap();
+    let is_folder_watched = watched_files
+        .keys()
+        .any(|watched_file| watched_file.parent() == Some(folder.as_path()));
+    for watched_file in [
+        canonical_path,
+        component_manifest_path,
+        folder_manifest_path,
+    ] {
+        watched_files
+            .entry(watched_file)
+            .or_default()
+            .insert(path.to_owned());
+    }
+    drop(watched_files);
+    if is_folder_watched {
+        return;
+    }
+
+    let mut watcher = WATCHER.lock().unwrap();
+    if watcher.is_none() {
+        match notify::recommended_watcher(on_file_event) {
+            Ok(new_watcher) => *watcher = Some(new_watcher),
+            Err(error) => {
+                logs::error(
+                    "Component file watcher",
+                    format!("Failed to create the watcher: {error:?}"),
+                );
+                return;
+            }
+        }
+    }
+    if let Err(error) = watcher
+        .as_mut()
+        .unwrap()
+        .watch(&folder, RecursiveMode::NonRecursive)
+    {
+        logs::error(
+            "Component file watcher",
+            format!("Failed to watch folder '{}': {error:?}", folder.display()),
+        );
+    }
+}
+
+/// Stops watching files of components not added from `component_paths`
+/// and folders without watched files.
+pub fn unwatch_component_files_except(component_paths: &HashSet<ComponentPath>) {
+    let mut watched_files = WATCHED_FILES.lock().unwrap();
+    let mut unwatched_folders = HashSet::new();
+    watched_files.retain(|watched_file, watched_component_paths| {
+        watched_component_paths.retain(|component_path| component_paths.contains(component_path));
+        let is_used = !watched_component_paths.is_empty();
+        if !is_used {
+            unwatched_folders.extend(watched_file.parent().map(PathBuf::from));
+        }
+        is_used
+    });
+    unwatched_folders.retain(|folder| {
+        !watched_files
+            .keys()
+            .any(|watched_file| watched_file.parent() == Some(folder.as_path()))
+    });
+    drop(watched_files);
+
+    let mut watcher = WATCHER.lock().unwrap();
+    let Some(watcher) = watcher.as_mut() else {
+        return;
+    };
+    for folder in unwatched_folders {
+        if let Err(error) = watcher.unwatch(&folder) {
+            logs::error(
+                "Component file watcher",
+                format!("Failed to unwatch folder '{}': {error:?}", folder.display()),
+            );
+        }
+    }
+}
+
+fn on_file_event(event: notify::Result<Event>) {
+    let event = match event {
+        Ok(event) => event,
+        Err(error) => {
+            logs::error("Component file watcher", format!("{error:?}"));
+            return;
+        }
+    };
+    // Removed manifests change capabilities of their components
+    if !event.kind.is_create() && !event.kind.is_modify() && !event.kind.is_remove() {
+        return;
+    }
+    let watched_files = WATCHED_FILES.lock().unwrap();
+    let component_paths = event
+        .paths
+        .iter()
+        .filter_map(|path| watched_files.get(path))
+        .flatten();
+    for component_path in component_paths {
+        if !PENDING_RELOADS
+            .lock()
+            .unwrap()
+            .insert(component_path.clone())
+        {
+            continue;
+        }
+        let component_path = component_path.clone();
+        tauri::async_runtime::spawn(async move {
+            sleep(RELOAD_DELAY).await;
+            PENDING_RELOADS.lock().unwrap().remove(&component_path);
+            super::reload_components(&component_path).await;
+        });
+    }
+}
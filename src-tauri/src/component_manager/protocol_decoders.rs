@@ -0,0 +1,369 @@
+use super::{
+    instantiate_reloaded_components, move_item, new_store, refuel, unwatch_removed_component_files,
+    watch_component_file, Capabilities, ComponentState, ComponentStatusCell, ENGINE,
+};
+use crate::{
+    logs, AddedProtocolDecodersCount, FullVarName, ProtocolDecoderName, ProtocolDecoderPath,
+    RemovedProtocolDecodersCount,
+};
+use once_cell::sync::Lazy;
+use shared::{ComponentInfo, ComponentKind};
+use std::sync::Arc;
+use tauri::async_runtime::{Mutex, RwLock};
+use wasmtime::component::{Component as WasmtimeComponent, *};
+use wasmtime::{Store, StoreLimits};
+use wasmtime_wasi::{WasiCtx, WasiView};
+use wellen::simple::Waveform;
+use wellen::GetItem;
+
+bindgen!(in "wit/protocol_decoder");
+
+use exports::component::protocol_decoder::protocol_decoder::{AnnotationRow, Guest, SignalChange};
+
+static PROTOCOL_DECODERS: Lazy<Arc<RwLock<Vec<ProtocolDecoder>>>> = Lazy::new(<_>::default);
+static LINKER: Lazy<Linker<State>> = Lazy::new(|| {
+    let mut linker = Linker::new(&ENGINE);
+    wasmtime_wasi::add_to_linker_sync(&mut linker).unwrap();
+    Component::add_to_linker(&mut linker, |state: &mut State| state).unwrap();
+    linker
+});
+
+// Changes are sent to the component in batches to limit the size of the copied lists
+const CHANGES_BATCH_SIZE: usize = 10_000;
+
+struct ProtocolDecoder {
+    name: ProtocolDecoderName,
+    path: ProtocolDecoderPath,
+    inputs: Vec<String>,
+    component: Component,
+    store: Mutex<Store<State>>,
+    // Decoding with a disabled protocol decoder fails
+    status: ComponentStatusCell,
+}
+
+impl ProtocolDecoder {
+    fn info(&self, index: usize) -> ComponentInfo {
+        ComponentInfo {
+            kind: ComponentKind::ProtocolDecoder,
+            index,
+            name: self.name.clone(),
+            path: self.path.clone(),
+            status: self.status.get(),
+        }
+    }
+}
+
+pub struct State {
+    ctx: WasiCtx,
+    table: ResourceTable,
+    limits: StoreLimits,
+}
+
+impl ComponentState for State {
+    fn new(ctx: WasiCtx, limits: StoreLimits) -> Self {
+        Self {
+            ctx,
+            table: ResourceTable::new(),
+            limits,
+        }
+    }
+
+    fn limits(&mut self) -> &mut StoreLimits {
+        &mut self.limits
+    }
+}
+
+impl WasiView for State {
+    fn ctx(&mut self) -> &mut WasiCtx {
+        &mut self.ctx
+    }
+    fn table(&mut self) -> &mut ResourceTable {
+        &mut self.table
+    }
+}
+
+impl component::protocol_decoder::host::Host for State {
+    fn log(&mut self, message: String) {
+        logs::info("Protocol Decoder", message);
+    }
+}
+
+pub async fn remove_all_protocol_decoders() -> RemovedProtocolDecodersCount {
+    let mut protocol_decoders = PROTOCOL_DECODERS.write().await;
+    let protocol_decoders_count = protocol_decoders.len();
+    protocol_decoders.clear();
+    drop(protocol_decoders);
+    unwatch_removed_component_files().await;
+    protocol_decoders_count
+}
+
+pub async fn protocol_decoder_infos() -> Vec<ComponentInfo> {
+    PROTOCOL_DECODERS
+        .read()
+        .await
+        .iter()
+        .enumerate()
+        .map(|(index, protocol_decoder)| protocol_decoder.info(index))
+        .collect()
+}
+
+pub async fn set_protocol_decoder_enabled(index: usize, is_enabled: bool) -> bool {
+    PROTOCOL_DECODERS
+        .read()
+        .await
+        .get(index)
+        .is_some_and(|protocol_decoder| protocol_decoder.status.set_enabled(is_enabled))
+}
+
+pub async fn remove_protocol_decoder(index: usize) -> bool {
+    let mut protocol_decoders = PROTOCOL_DECODERS.write().await;
+    if index >= protocol_decoders.len() {
+        return false;
+    }
+    protocol_decoders.remove(index);
+    true
+}
+
+pub async fn move_protocol_decoder(old_index: usize, new_index: usize) -> bool {
+    move_item(&mut *PROTOCOL_DECODERS.write().await, old_index, new_index)
+}
+
+// @TODO Remove / improve comments below
+// Testing (load `test_files/uart.vcd` first)
+//
+// FW.add_protocol_decoders(["../test_files/components/rust_uart_decoder/rust_uart_decoder.wasm"])
+// FW.decode_protocol("UART", ["uart_tb.rx"])
+//
+// Remove all
+// FW.remove_all_protocol_decoders()
+pub async fn add_protocol_decoders(
+    protocol_decoder_paths: Vec<ProtocolDecoderPath>,
+) -> AddedProtocolDecodersCount {
+    let mut added_protocol_decoders_count = 0;
+    for protocol_decoder_path in protocol_decoder_paths {
+        if let Err(error) = add_protocol_decoder(&protocol_decoder_path).await {
+            logs::error(
+                "Protocol Decoder",
+                format!("Failed to add '{protocol_decoder_path}': {error:?}"),
+            );
+        } else {
+            added_protocol_decoders_count += 1;
+        }
+    }
+    added_protocol_decoders_count
+}
+
+async fn add_protocol_decoder(path: &str) -> wasmtime::Result<()> {
+    let protocol_decoder = instantiate_protocol_decoder(path)?;
+    logs::info(
+        "Protocol Decoder",
+        format!(
+            "'{}' added, inputs: {:?}",
+            protocol_decoder.name, protocol_decoder.inputs
+        ),
+    );
+    PROTOCOL_DECODERS.write().await.push(protocol_decoder);
+    watch_component_file(path);
+    Ok(())
+}
+
+/// Reinstantiates protocol decoders added from `path` and reruns their `init`
+pub async fn reload_protocol_decoders(path: &str) -> Vec<ComponentInfo> {
+    let count = PROTOCOL_DECODERS
+        .read()
+        .await
+        .iter()
+        .filter(|protocol_decoder| protocol_decoder.path == path)
+        .count();
+    let mut new_protocol_decoders = instantiate_reloaded_components(
+        ComponentKind::ProtocolDecoder,
+        path,
+        count,
+        instantiate_protocol_decoder,
+    )
+    .into_iter();
+
+    let mut protocol_decoders = PROTOCOL_DECODERS.write().await;
+    let mut reloaded_protocol_decoders = Vec::new();
+    for (index, protocol_decoder) in protocol_decoders.iter_mut().enumerate() {
+        if protocol_decoder.path != path {
+            continue;
+        }
+        let Some(reloaded_protocol_decoder) = new_protocol_decoders.next() else {
+            break;
+        };
+        let status = protocol_decoder.status.reloaded();
+        *protocol_decoder = reloaded_protocol_decoder;
+        protocol_decoder.status = status;
+        reloaded_protocol_decoders.push(protocol_decoder.info(index));
+    }
+    reloaded_protocol_decoders
+}
+
+fn instantiate_protocol_decoder(path: &str) -> wasmtime::Result<ProtocolDecoder> {
+    let wasmtime_component = WasmtimeComponent::from_file(&ENGINE, path)?;
+
+    let capabilities = Capabilities::load(path)?;
+    let mut store = new_store::<State>(&capabilities)?;
+
+    let component = Component::instantiate(&mut store, &wasmtime_component, &LINKER)?;
+
+    let protocol_decoder = component.component_protocol_decoder_protocol_decoder();
+    let name = protocol_decoder.call_name(&mut store)?;
+    let inputs = protocol_decoder.call_inputs(&mut store)?;
+    protocol_decoder.call_init(&mut store)?;
+
+    Ok(ProtocolDecoder {
+        name,
+        path: path.to_owned(),
+        inputs,
+        component,
+        store: Mutex::new(store),
+        status: ComponentStatusCell::default(),
+    })
+}
+
+/// Streams changes of vars `input_var_names` (bound to the decoder inputs in the same order)
+/// to the protocol decoder and returns the annotation rows it emits.
+pub async fn decode_protocol(
+    waveform: &mut Waveform,
+    protocol_decoder_name: ProtocolDecoderName,
+    input_var_names: Vec<FullVarName>,
+) -> wasmtime::Result<Vec<shared::AnnotationRow>> {
+    let protocol_decoders = PROTOCOL_DECODERS.read().await;
+    let Some(protocol_decoder) = protocol_decoders
+        .iter()
+        .find(|protocol_decoder| protocol_decoder.name == protocol_decoder_name)
+    else {
+        wasmtime::bail!("Protocol decoder '{protocol_decoder_name}' not found");
+    };
+    if !protocol_decoder.status.is_enabled() {
+        wasmtime::bail!("Protocol decoder '{protocol_decoder_name}' has been disabled");
+    }
+    if input_var_names.len() != protocol_decoder.inputs.len() {
+        wasmtime::bail!(
+            "Protocol decoder '{protocol_decoder_name}' expects inputs {:?}, got vars {input_var_names:?}",
+            protocol_decoder.inputs
+        );
+    }
+
+    let hierarchy = waveform.hierarchy();
+    let mut signal_refs = Vec::new();
+    for input_var_name in &input_var_names {
+        let (scope_path, var_name) = match input_var_name.rsplit_once('.') {
+            Some((scope_path, var_name)) => (scope_path.split('.').collect(), var_name),
+            None => (Vec::new(), input_var_name.as_str()),
+        };
+        let Some(var_ref) = hierarchy.lookup_var(&scope_path, &var_name) else {
+            wasmtime::bail!("Var '{input_var_name}' not found");
+        };
+        signal_refs.push(hierarchy.get(var_ref).signal_ref());
+    }
+    waveform.load_signals_multi_threaded(&signal_refs);
+
+    let time_table = waveform.time_table();
+    let signals = signal_refs
+        .iter()
+        .zip(&input_var_names)
+        .map(|(signal_ref, input_var_name)| {
+            waveform
+                .get_signal(*signal_ref)
+                .ok_or_else(|| wasmtime::format_err!("Signal of var '{input_var_name}' not loaded"))
+        })
+        .collect::<wasmtime::Result<Vec<_>>>()?;
+
+    let mut store = protocol_decoder.store.lock().await;
+    let guest = protocol_decoder
+        .component
+        .component_protocol_decoder_protocol_decoder();
+
+    let result = stream_changes(&mut store, guest, &signals, time_table);
+    drop(store);
+    let annotation_rows = match result {
+        Ok(annotation_rows) => annotation_rows,
+        Err(error) => {
+            protocol_decoder.status.fail(
+                ComponentKind::ProtocolDecoder,
+                &protocol_decoder.name,
+                &error,
+            );
+            return Err(error);
+        }
+    };
+
+    let annotation_rows = annotation_rows
+        .into_iter()
+        .map(|annotation_row| shared::AnnotationRow {
+            name: format!("{protocol_decoder_name}: {}", annotation_row.name),
+            annotations: annotation_row
+                .annotations
+                .into_iter()
+                .map(|annotation| shared::Annotation {
+                    start: annotation.start,
+                    end: annotation.end,
+                    label: annotation.label,
+                    color: annotation.color,
+                })
+                .collect(),
+        })
+        .collect();
+    Ok(annotation_rows)
+}
+
+// Every call into the component gets its own fuel, so long waveforms don't disable the protocol decoder
+fn stream_changes(
+    store: &mut Store<State>,
+    guest: &Guest,
+    signals: &[&wellen::Signal],
+    time_table: &[wellen::Time],
+) -> wasmtime::Result<Vec<AnnotationRow>> {
+    refuel(store);
+    guest.call_start(&mut *store)?;
+
+    // Merges changes of all inputs ordered by time
+    let mut next_change_indices = vec![0; signals.len()];
+    let mut changes = Vec::with_capacity(CHANGES_BATCH_SIZE);
+    loop {
+        let next_input = signals
+            .iter()
+            .zip(&next_change_indices)
+            .enumerate()
+            .filter_map(|(input, (signal, change_idx))| {
+                let time_idx = *signal.time_indices().get(*change_idx)?;
+                Some((time_table[time_idx as usize], input))
+            })
+            .min();
+        let Some((time, input)) = next_input else {
+            break;
+        };
+
+        let signal = signals[input];
+        let time_idx = signal.time_indices()[next_change_indices[input]];
+        next_change_indices[input] += 1;
+        let Some(offset) = signal.get_offset(time_idx) else {
+            continue;
+        };
+        let raw_bits = signal
+            .get_value_at(&offset, 0)
+            .to_bit_string()
+            .unwrap_or_default();
+        changes.push(SignalChange {
+            input: input as u32,
+            time,
+            raw_bits,
+        });
+
+        if changes.len() == CHANGES_BATCH_SIZE {
+            refuel(store);
+            guest.call_process_changes(&mut *store, &changes)?;
+            changes.clear();
+        }
+    }
+    if !changes.is_empty() {
+        refuel(store);
+        guest.call_process_changes(&mut *store, &changes)?;
+    }
+
+    refuel(store);
+    guest.call_finish(&mut *store)
+}
@@ -1,4 +1,6 @@
 use once_cell::sync::Lazy;
+use shared::{waveform_commands, CommandError, WaveformId, WaveformInfo};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
 use std::process::Command;
@@ -9,42 +11,100 @@ use tauri::{async_runtime::RwLock, AppHandle};
 use tauri_plugin_dialog::DialogExt;
 use tokio::fs::read_to_string;
 use tokio::time::sleep;
-use wasmtime::AsContextMut;
 use wellen::simple::Waveform;
 
 type Filename = String;
+type FilePath = String;
 type FolderPath = String;
 type JavascriptCode = String;
 
 type AddedDecodersCount = usize;
 type RemovedDecodersCount = usize;
 type DecoderPath = String;
+type DecoderName = String;
 
 type AddedDiagramConnectorsCount = usize;
 type RemovedDiagramConnectorsCount = usize;
 type DiagramConnectorPath = String;
 type DiagramConnectorName = String;
 type ComponentId = String;
+
+type AddedProtocolDecodersCount = usize;
+type RemovedProtocolDecodersCount = usize;
+type ProtocolDecoderPath = String;
+type ProtocolDecoderName = String;
+type FullVarName = String;
+
+type ComponentIndex = usize;
 use alacritty_terminal::event::Notify;
 use shared::term::{TerminalDownMsg, TerminalScreen};
 
 mod aterm;
 mod component_manager;
+mod logs;
+mod recent_files;
 mod terminal_size;
+mod waveform_loader;
+mod waveform_watcher;
 use std::sync::Mutex;
 
 pub static APP_HANDLE: Lazy<Arc<StdRwLock<Option<AppHandle>>>> = Lazy::new(<_>::default);
+// The first loaded waveform, used by diagram connectors
 pub static WAVEFORM: Lazy<StdRwLock<Arc<RwLock<Option<Waveform>>>>> = Lazy::new(<_>::default);
 
 static TERM: Lazy<Mutex<aterm::ATerm>> =
     Lazy::new(|| Mutex::new(aterm::ATerm::new().expect("Failed to initialize ATerm")));
 
+type Waveforms = Arc<StdRwLock<BTreeMap<WaveformId, LoadedWaveform>>>;
+
 #[derive(Default)]
 struct Store {
-    waveform: Arc<RwLock<Option<Waveform>>>,
+    waveforms: Waveforms,
     val: Arc<RwLock<bool>>,
 }
 
+impl Store {
+    fn loaded_waveform(&self, waveform_id: WaveformId) -> Result<LoadedWaveform, CommandError> {
+        self.waveforms
+            .read()
+            .unwrap()
+            .get(&waveform_id)
+            .cloned()
+            .ok_or(CommandError::WaveformNotFound { waveform_id })
+    }
+
+    /// Times sent to and received from the frontend are on this axis
+    fn time_axis(&self) -> Option<shared::TimeAxis> {
+        let waveforms = self.waveforms.read().unwrap();
+        shared::TimeAxis::shared(
+            waveforms
+                .values()
+                .map(|loaded_waveform| loaded_waveform.time_axis),
+        )
+    }
+
+    /// Converts `time` on the shared time axis to the time of `waveform`
+    fn waveform_time(&self, time: wellen::Time, waveform: Option<&Waveform>) -> wellen::Time {
+        match (self.time_axis(), waveform) {
+            (Some(time_axis), Some(waveform)) => {
+                time_axis.time_to(time, shared::TimeAxis::new(waveform))
+            }
+            _ => time,
+        }
+    }
+}
+
+/// One of the waveforms loaded at once
+#[derive(Clone)]
+struct LoadedWaveform {
+    path: FilePath,
+    waveform: Arc<RwLock<Option<Waveform>>>,
+    // Serialized hierarchy, available before value changes are loaded
+    hierarchy: Arc<serde_json::Value>,
+    // `last_time` is `0` until value changes are loaded
+    time_axis: shared::TimeAxis,
+}
+
 #[tauri::command(rename_all = "snake_case")]
 async fn show_window(window: tauri::Window) {
     window.show().unwrap();
@@ -54,60 +114,140 @@ async fn show_window(window: tauri::Window) {
 async fn pick_and_load_waveform(
     store: tauri::State<'_, Store>,
     app: tauri::AppHandle,
-) -> Result<Option<Filename>, ()> {
+) -> Result<Option<WaveformInfo>, CommandError> {
     let Some(file_path) = app.dialog().file().blocking_pick_file() else {
         return Ok(None);
     };
-    let file_buf = file_path.into_path().unwrap();
-    let file_str = file_buf.as_os_str().to_str().unwrap();
-    // @TODO `read` should accept `Path` instead of `&str`
-    let waveform = wellen::simple::read(file_str);
-    let Ok(waveform) = waveform else {
-        panic!("Waveform file reading failed")
-    };
-    *store.waveform.write().await = Some(waveform);
-    *WAVEFORM.write().unwrap() = Arc::clone(&store.waveform);
-    Ok(Some(
-        file_buf.file_name().unwrap().to_string_lossy().to_string(),
-    ))
+    let path = file_path.to_string();
+    let file_buf = file_path
+        .into_path()
+        .map_err(|error| CommandError::WaveformLoadingFailed {
+            path,
+            reason: error.to_string(),
+        })?;
+    let file_str = file_buf
+        .to_str()
+        .ok_or_else(|| CommandError::WaveformLoadingFailed {
+            path: file_buf.to_string_lossy().into_owned(),
+            reason: "The path is not valid UTF-8".to_owned(),
+        })?;
+    waveform_loader::load_waveform(&store, file_str)
+        .await
+        .map(Some)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+async fn load_waveform_from_path(
+    path: FilePath,
+    store: tauri::State<'_, Store>,
+) -> Result<WaveformInfo, CommandError> {
+    waveform_loader::load_waveform(&store, &path).await
+}
+
+#[tauri::command(rename_all = "snake_case")]
+async fn reload_waveform(
+    waveform_id: WaveformId,
+    store: tauri::State<'_, Store>,
+) -> Result<Filename, CommandError> {
+    waveform_loader::reload_waveform(&store, waveform_id).await
+}
+
+#[tauri::command(rename_all = "snake_case")]
+async fn unload_waveform(
+    waveform_id: WaveformId,
+    store: tauri::State<'_, Store>,
+) -> Result<(), CommandError> {
+    waveform_loader::unload_waveform(&store, waveform_id);
+    Ok(())
+}
+
+#[tauri::command(rename_all = "snake_case")]
+async fn cancel_waveform_loading(waveform_id: WaveformId) -> Result<(), CommandError> {
+    waveform_loader::cancel_loading(waveform_id);
+    Ok(())
+}
+
+fn absolute_path(path: &str) -> FilePath {
+    fs::canonicalize(path).map_or_else(
+        |_| path.to_owned(),
+        |path| path.to_string_lossy().to_string(),
+    )
+}
+
+/// Arguments not starting with `-`, e.g. `fastwave golden.vcd failing.vcd`
+#[tauri::command(rename_all = "snake_case")]
+async fn waveform_paths_from_args() -> Result<Vec<FilePath>, ()> {
+    let paths = std::env::args()
+        .skip(1)
+        .filter(|arg| !arg.starts_with('-'))
+        .map(|path| absolute_path(&path))
+        .collect();
+    Ok(paths)
 }
 
 #[tauri::command(rename_all = "snake_case")]
-async fn load_file_with_selected_vars(app: tauri::AppHandle) -> Result<Option<JavascriptCode>, ()> {
+async fn recent_files() -> Result<Vec<FilePath>, ()> {
+    Ok(recent_files::recent_files())
+}
+
+#[tauri::command(rename_all = "snake_case")]
+async fn load_file_with_selected_vars(
+    app: tauri::AppHandle,
+) -> Result<Option<JavascriptCode>, CommandError> {
     let Some(file_path) = app.dialog().file().blocking_pick_file() else {
         return Ok(None);
     };
+    let path = file_path.to_string();
+    let file_buf = file_path
+        .into_path()
+        .map_err(|error| CommandError::FileReadingFailed {
+            path,
+            reason: error.to_string(),
+        })?;
     // @TODO Tokio's `fs` or a Tauri `fs`?
-    let Ok(javascript_code) = fs::read_to_string(file_path.into_path().unwrap()) else {
-        panic!("Selected vars file reading failed")
-    };
+    let javascript_code =
+        fs::read_to_string(&file_buf).map_err(|error| CommandError::FileReadingFailed {
+            path: file_buf.display().to_string(),
+            reason: error.to_string(),
+        })?;
     Ok(Some(javascript_code))
 }
 
 #[tauri::command(rename_all = "snake_case")]
-async fn get_hierarchy(store: tauri::State<'_, Store>) -> Result<serde_json::Value, ()> {
-    let waveform_lock = store.waveform.read().await;
-    let waveform = waveform_lock.as_ref().unwrap();
-    let hierarchy = waveform.hierarchy();
-    Ok(serde_json::to_value(hierarchy).unwrap())
+async fn get_hierarchy(
+    waveform_id: WaveformId,
+    store: tauri::State<'_, Store>,
+) -> Result<serde_json::Value, CommandError> {
+    let hierarchy = store.loaded_waveform(waveform_id)?.hierarchy;
+    Ok((*hierarchy).clone())
 }
 
 #[tauri::command(rename_all = "snake_case")]
 async fn load_signal_and_get_timeline(
+    waveform_id: WaveformId,
     signal_ref_index: usize,
+    var_name: FullVarName,
     timeline_zoom: f64,
     timeline_viewport_width: u32,
     timeline_viewport_x: i32,
     block_height: u32,
     var_format: shared::VarFormat,
+    render_mode: shared::RenderMode,
+    decoder_name: Option<DecoderName>,
     store: tauri::State<'_, Store>,
-) -> Result<serde_json::Value, ()> {
+) -> Result<serde_json::Value, CommandError> {
     // @TODO run (all?) in a blocking thread?
-    let signal_ref = wellen::SignalRef::from_index(signal_ref_index).unwrap();
-    let mut waveform_lock = store.waveform.write().await;
-    let waveform = waveform_lock.as_mut().unwrap();
-    waveform.load_signals_multi_threaded(&[signal_ref]);
-    let signal = waveform.get_signal(signal_ref).unwrap();
+    let loaded_waveform = store.loaded_waveform(waveform_id)?;
+    let mut waveform_lock = loaded_waveform.waveform.write().await;
+    let waveform = waveform_commands::loaded_waveform_mut(waveform_lock.as_mut())?;
+    // The time axis is complete once the waveform lock is released by the loading
+    let timeline_zoom = store.time_axis().map_or(timeline_zoom, |time_axis| {
+        time_axis.waveform_zoom(timeline_zoom, shared::TimeAxis::new(waveform))
+    });
+    let signal_ref = waveform_commands::load_signal(waveform, signal_ref_index)?;
+    let signal = waveform
+        .get_signal(signal_ref)
+        .ok_or(CommandError::SignalNotFound { signal_ref_index })?;
     let time_table = waveform.time_table();
     let timeline = shared::signal_to_timeline(
         signal,
@@ -117,44 +257,121 @@ async fn load_signal_and_get_timeline(
         timeline_viewport_x,
         block_height,
         var_format,
-        |mut value: String| {
-            Box::pin(async {
-                // We need to spawn a (non-runtime-specific?) blocking task before calling component methods to prevent this error:
-                // "Cannot start a runtime from within a runtime. This happens because a function (like `block_on`) attempted to block the current thread while the thread is being used to drive asynchronous tasks."
-                // @TODO Workaround? Is it a problem only for non-Rust components? Is it needed only when there is a problem in the component (e.g. "`Err` value: wasm trap: cannot enter component instance"?)
-                // let value = std::thread::spawn(move || {
-                // futures::executor::block_on(async move {
-                let decoders = component_manager::decoders::DECODERS.read().await;
-                let mut store_lock = component_manager::decoders::STORE.lock().await;
-                let mut store = store_lock.as_context_mut();
-
-                for decoder in decoders.iter() {
-                    value = decoder
-                        .component_decoder_decoder()
-                        .call_format_signal_value(&mut store, &value)
-                        // @TODO Resolve panic when running non-Rust components:
-                        // `Err` value: wasm trap: cannot enter component instance
-                        // https://github.com/bytecodealliance/wasmtime/issues/8670 ?
-                        .unwrap()
-                }
-                // value
-                // })
-                // }).join().unwrap();
-                value
-            })
+        render_mode,
+        |input: shared::DecoderInput| {
+            Box::pin(component_manager::decoders::decode_signal_value(
+                decoder_name.clone(),
+                component_manager::decoders::ValueToDecode {
+                    var_name: var_name.clone(),
+                    var_format,
+                    raw_bits: input.value.to_bit_string().unwrap_or_default(),
+                    formatted_value: input.formatted_value,
+                    time: input.time,
+                },
+            ))
         },
     )
     .await;
-    Ok(serde_json::to_value(timeline).unwrap())
+    serde_json::to_value(timeline).map_err(|error| CommandError::Unexpected {
+        reason: error.to_string(),
+    })
 }
 
 #[tauri::command(rename_all = "snake_case")]
-async fn unload_signal(signal_ref_index: usize, store: tauri::State<'_, Store>) -> Result<(), ()> {
-    let signal_ref = wellen::SignalRef::from_index(signal_ref_index).unwrap();
-    let mut waveform_lock = store.waveform.write().await;
-    let waveform = waveform_lock.as_mut().unwrap();
-    waveform.unload_signals(&[signal_ref]);
-    Ok(())
+async fn get_timeline_ruler(
+    timeline_zoom: f64,
+    timeline_viewport_width: u32,
+    timeline_viewport_x: i32,
+    store: tauri::State<'_, Store>,
+) -> Result<serde_json::Value, CommandError> {
+    // There is no ruler until a waveform is loaded
+    let ruler = store.time_axis().map(|time_axis| {
+        time_axis.timeline_ruler(timeline_zoom, timeline_viewport_width, timeline_viewport_x)
+    });
+    serde_json::to_value(ruler).map_err(|error| CommandError::Unexpected {
+        reason: error.to_string(),
+    })
+}
+
+#[tauri::command(rename_all = "snake_case")]
+async fn get_value_at_time(
+    waveform_id: WaveformId,
+    signal_ref_index: usize,
+    time: wellen::Time,
+    var_format: shared::VarFormat,
+    store: tauri::State<'_, Store>,
+) -> Result<Option<String>, CommandError> {
+    let loaded_waveform = store.loaded_waveform(waveform_id)?;
+    let mut waveform_lock = loaded_waveform.waveform.write().await;
+    let time = store.waveform_time(time, waveform_lock.as_ref());
+    waveform_commands::value_at_time(waveform_lock.as_mut(), signal_ref_index, time, var_format)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+async fn get_values_at_time(
+    waveform_id: WaveformId,
+    signal_ref_indices_and_var_formats: Vec<(usize, shared::VarFormat)>,
+    time: wellen::Time,
+    store: tauri::State<'_, Store>,
+) -> Result<Vec<Option<String>>, CommandError> {
+    let loaded_waveform = store.loaded_waveform(waveform_id)?;
+    let mut waveform_lock = loaded_waveform.waveform.write().await;
+    let time = store.waveform_time(time, waveform_lock.as_ref());
+    waveform_commands::values_at_time(
+        waveform_lock.as_mut(),
+        signal_ref_indices_and_var_formats,
+        time,
+    )
+}
+
+#[tauri::command(rename_all = "snake_case")]
+async fn unload_signal(
+    waveform_id: WaveformId,
+    signal_ref_index: usize,
+    store: tauri::State<'_, Store>,
+) -> Result<(), CommandError> {
+    let loaded_waveform = store.loaded_waveform(waveform_id)?;
+    let mut waveform_lock = loaded_waveform.waveform.write().await;
+    waveform_commands::unload_signal(waveform_lock.as_mut(), signal_ref_index)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+async fn diff_waveforms(
+    waveform_id_a: WaveformId,
+    waveform_id_b: WaveformId,
+    store: tauri::State<'_, Store>,
+) -> Result<Vec<shared::VarDiff>, CommandError> {
+    if waveform_id_a == waveform_id_b {
+        return Ok(Vec::new());
+    }
+    let loaded_waveform_a = store.loaded_waveform(waveform_id_a)?;
+    let loaded_waveform_b = store.loaded_waveform(waveform_id_b)?;
+    // Locked in the order of ids to not deadlock with a diff of the swapped waveforms
+    let (mut waveform_lock_a, mut waveform_lock_b) = if waveform_id_a < waveform_id_b {
+        let waveform_lock_a = loaded_waveform_a.waveform.write_owned().await;
+        (
+            waveform_lock_a,
+            loaded_waveform_b.waveform.write_owned().await,
+        )
+    } else {
+        let waveform_lock_b = loaded_waveform_b.waveform.write_owned().await;
+        (
+            loaded_waveform_a.waveform.write_owned().await,
+            waveform_lock_b,
+        )
+    };
+    // The time axis is complete once the waveform locks are released by the loadings
+    let time_axis = store.time_axis().ok_or(CommandError::NoWaveformLoaded)?;
+    // All signals of both waveforms may be loaded and compared
+    tauri::async_runtime::spawn_blocking(move || {
+        let waveform_a = waveform_commands::loaded_waveform_mut(waveform_lock_a.as_mut())?;
+        let waveform_b = waveform_commands::loaded_waveform_mut(waveform_lock_b.as_mut())?;
+        Ok(shared::diff_waveforms(waveform_a, waveform_b, time_axis))
+    })
+    .await
+    .map_err(|error| CommandError::Unexpected {
+        reason: error.to_string(),
+    })?
 }
 
 #[tauri::command(rename_all = "snake_case")]
@@ -178,6 +395,16 @@ async fn remove_all_decoders() -> Result<RemovedDecodersCount, ()> {
     Ok(component_manager::decoders::remove_all_decoders().await)
 }
 
+#[tauri::command(rename_all = "snake_case")]
+async fn decoder_names() -> Result<Vec<DecoderName>, ()> {
+    Ok(component_manager::decoders::decoder_names().await)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+async fn decoders_cache_stats() -> Result<shared::DecodersCacheStats, ()> {
+    Ok(component_manager::decoders::decoders_cache_stats().await)
+}
+
 #[tauri::command(rename_all = "snake_case")]
 async fn add_diagram_connectors(
     diagram_connector_paths: Vec<DiagramConnectorPath>,
@@ -210,23 +437,112 @@ async fn notify_diagram_connector_text_change(
 }
 
 #[tauri::command(rename_all = "snake_case")]
-async fn open_konata_file(app: tauri::AppHandle) {
+async fn add_protocol_decoders(
+    protocol_decoder_paths: Vec<ProtocolDecoderPath>,
+) -> Result<AddedProtocolDecodersCount, ()> {
+    Ok(component_manager::protocol_decoders::add_protocol_decoders(protocol_decoder_paths).await)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+async fn remove_all_protocol_decoders() -> Result<RemovedProtocolDecodersCount, ()> {
+    Ok(component_manager::protocol_decoders::remove_all_protocol_decoders().await)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+async fn decode_protocol(
+    waveform_id: WaveformId,
+    protocol_decoder_name: ProtocolDecoderName,
+    input_var_names: Vec<FullVarName>,
+    store: tauri::State<'_, Store>,
+) -> Result<Vec<shared::AnnotationRow>, CommandError> {
+    let loaded_waveform = store.loaded_waveform(waveform_id)?;
+    let mut waveform_lock = loaded_waveform.waveform.write().await;
+    let waveform = waveform_commands::loaded_waveform_mut(waveform_lock.as_mut())?;
+    let waveform_axis = shared::TimeAxis::new(waveform);
+    let mut annotation_rows = component_manager::protocol_decoders::decode_protocol(
+        waveform,
+        protocol_decoder_name.clone(),
+        input_var_names,
+    )
+    .await
+    .map_err(|error| {
+        logs::error(
+            "Protocol Decoder",
+            format!("Failed to decode '{protocol_decoder_name}': {error:?}"),
+        );
+        CommandError::ProtocolDecodingFailed {
+            protocol_decoder_name,
+            reason: format!("{error:?}"),
+        }
+    })?;
+    // Annotations are drawn on the shared time axis
+    let time_axis = store.time_axis().unwrap_or(waveform_axis);
+    for annotation in annotation_rows
+        .iter_mut()
+        .flat_map(|annotation_row| &mut annotation_row.annotations)
+    {
+        annotation.start = time_axis.time_from(annotation.start, waveform_axis);
+        annotation.end = time_axis.time_from(annotation.end, waveform_axis);
+    }
+    Ok(annotation_rows)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+async fn components() -> Result<Vec<shared::ComponentInfo>, ()> {
+    Ok(component_manager::components().await)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+async fn set_component_enabled(
+    kind: shared::ComponentKind,
+    index: ComponentIndex,
+    is_enabled: bool,
+) -> Result<bool, ()> {
+    Ok(component_manager::set_component_enabled(kind, index, is_enabled).await)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+async fn remove_component(kind: shared::ComponentKind, index: ComponentIndex) -> Result<bool, ()> {
+    Ok(component_manager::remove_component(kind, index).await)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+async fn move_component(
+    kind: shared::ComponentKind,
+    old_index: ComponentIndex,
+    new_index: ComponentIndex,
+) -> Result<bool, ()> {
+    Ok(component_manager::move_component(kind, old_index, new_index).await)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+async fn logs() -> Result<Vec<shared::LogEntry>, ()> {
+    Ok(logs::log_entries())
+}
+
+#[tauri::command(rename_all = "snake_case")]
+async fn open_konata_file(app: tauri::AppHandle) -> Result<(), CommandError> {
     let Some(file_path) = app.dialog().file().blocking_pick_file() else {
-        return;
+        return Ok(());
+    };
+    let path = file_path.to_string();
+    let konata_error = |reason: String| CommandError::KonataFileOpeningFailed {
+        path: path.clone(),
+        reason,
     };
     let file_str = file_path
         .into_path()
-        .unwrap()
+        .map_err(|error| konata_error(error.to_string()))?
         .into_os_string()
         .into_string()
-        .unwrap();
+        .map_err(|_| konata_error("The path is not valid UTF-8".to_owned()))?;
 
     let port = 30000;
     let base_url = format!("http://localhost:{port}");
     let client = reqwest::Client::builder()
         .connect_timeout(Duration::from_secs(1))
         .build()
-        .unwrap();
+        .map_err(|error| konata_error(error.to_string()))?;
 
     let mut konata_server_ready = false;
 
@@ -241,15 +557,17 @@ async fn open_konata_file(app: tauri::AppHandle) {
     if is_konata_server_ready().await {
         konata_server_ready = true;
     } else {
-        spawn_konata_app();
+        spawn_konata_app().map_err(|error| konata_error(error.to_string()))?;
     }
 
     let mut attempts = 1;
     while !konata_server_ready {
         attempts += 1;
         if attempts > 5 {
-            eprintln!("Failed to get Konata server status (5 attempts)");
-            return;
+            logs::error("Konata", "Failed to get Konata server status (5 attempts)");
+            return Err(konata_error(
+                "Failed to get Konata server status (5 attempts)".to_owned(),
+            ));
         }
         konata_server_ready = is_konata_server_ready().await;
         sleep(Duration::from_secs(1)).await;
@@ -262,27 +580,27 @@ async fn open_konata_file(app: tauri::AppHandle) {
         }))
         .send()
         .await
-        .unwrap()
-        .error_for_status()
-        .unwrap();
+        .and_then(|response| response.error_for_status())
+        .map_err(|error| konata_error(error.to_string()))?;
+    Ok(())
 }
 
 #[cfg(target_family = "windows")]
-fn spawn_konata_app() {
+fn spawn_konata_app() -> std::io::Result<()> {
     Command::new("cscript")
         .current_dir("../../Konata")
         .arg("konata.vbs")
         .spawn()
-        .unwrap();
+        .map(drop)
 }
 
 #[cfg(target_family = "unix")]
-fn spawn_konata_app() {
+fn spawn_konata_app() -> std::io::Result<()> {
     Command::new("sh")
         .current_dir("../../Konata")
         .arg("konata.sh")
         .spawn()
-        .unwrap();
+        .map(drop)
 }
 
 #[tauri::command(rename_all = "snake_case")]
@@ -293,31 +611,45 @@ async fn read_file(path: String) -> Result<String, String> {
 }
 
 #[tauri::command(rename_all = "snake_case")]
-async fn select_folder_to_open(app: tauri::AppHandle) -> Result<Option<FolderPath>, ()> {
+async fn select_folder_to_open(app: tauri::AppHandle) -> Result<Option<FolderPath>, CommandError> {
     let Some(file_path) = app.dialog().file().blocking_pick_folder() else {
         return Ok(None);
     };
-    let path_buf = file_path.into_path().unwrap();
-    let folder_path = path_buf.as_os_str().to_str().unwrap();
+    let path = file_path.to_string();
+    let path_buf = file_path
+        .into_path()
+        .map_err(|error| CommandError::FileReadingFailed {
+            path: path.clone(),
+            reason: error.to_string(),
+        })?;
+    let folder_path = path_buf
+        .to_str()
+        .ok_or_else(|| CommandError::FileReadingFailed {
+            path,
+            reason: "The path is not valid UTF-8".to_owned(),
+        })?;
     Ok(Some(folder_path.to_owned()))
 }
 
 #[tauri::command(rename_all = "snake_case")]
-async fn file_tree(path: FolderPath) -> Result<shared::FileTreeItem, ()> {
-    let root = file_tree_item(path.into());
-    Ok(root)
+async fn file_tree(path: FolderPath) -> Result<shared::FileTreeItem, CommandError> {
+    file_tree_item(path.into())
 }
 
-fn file_tree_item(path: PathBuf) -> shared::FileTreeItem {
-    let metadata = fs::metadata(path.clone()).unwrap();
+fn file_tree_item(path: PathBuf) -> Result<shared::FileTreeItem, CommandError> {
+    let file_reading_failed = |error: std::io::Error| CommandError::FileReadingFailed {
+        path: path.to_string_lossy().into_owned(),
+        reason: error.to_string(),
+    };
+    let metadata = fs::metadata(&path).map_err(file_reading_failed)?;
     if metadata.is_file() {
-        shared::FileTreeItem::new_file(path)
-    } else {
-        let children = fs::read_dir(&path)
-            .unwrap()
-            .map(|entry| file_tree_item(entry.unwrap().path()));
-        shared::FileTreeItem::new_folder(path, children.collect())
+        return Ok(shared::FileTreeItem::new_file(path));
     }
+    let children = fs::read_dir(&path)
+        .map_err(file_reading_failed)?
+        .map(|entry| file_tree_item(entry.map_err(file_reading_failed)?.path()))
+        .collect::<Result<_, _>>()?;
+    Ok(shared::FileTreeItem::new_folder(path, children))
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -330,20 +662,53 @@ pub fn run() {
         .manage(Store::default())
         .plugin(tauri_plugin_window_state::Builder::default().build())
         .plugin(tauri_plugin_dialog::init())
+        .on_window_event(|window, event| {
+            if let tauri::WindowEvent::DragDrop(tauri::DragDropEvent::Drop { paths, .. }) = event {
+                for path in paths {
+                    if let Err(error) = window.emit("waveform_file_dropped", path.to_string_lossy())
+                    {
+                        logs::error(
+                            "Waveform",
+                            format!("Failed to send the dropped file path: {error:?}"),
+                        );
+                    }
+                }
+            }
+        })
         // Note: Add all handlers to `frontend/src/tauri_bridge.rs`
         .invoke_handler(tauri::generate_handler![
             show_window,
             pick_and_load_waveform,
+            load_waveform_from_path,
+            waveform_paths_from_args,
+            recent_files,
+            reload_waveform,
+            unload_waveform,
+            cancel_waveform_loading,
             load_file_with_selected_vars,
             get_hierarchy,
             load_signal_and_get_timeline,
+            get_timeline_ruler,
+            get_value_at_time,
+            get_values_at_time,
             unload_signal,
+            diff_waveforms,
             send_char,
             add_decoders,
             remove_all_decoders,
+            decoder_names,
+            decoders_cache_stats,
             add_diagram_connectors,
             remove_all_diagram_connectors,
             notify_diagram_connector_text_change,
+            add_protocol_decoders,
+            remove_all_protocol_decoders,
+            decode_protocol,
+            components,
+            set_component_enabled,
+            remove_component,
+            move_component,
+            logs,
             open_konata_file,
             read_file,
             select_folder_to_open,
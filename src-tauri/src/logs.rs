@@ -0,0 +1,78 @@
+use crate::APP_HANDLE;
+use once_cell::sync::Lazy;
+use shared::{LogEntry, LogSeverity, MAX_LOG_ENTRIES};
+use std::collections::VecDeque;
+use std::sync::Mutex as StdMutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::Emitter;
+use tokio::time::sleep;
+
+// Entries are sent to the frontend in batches,
+// so components logging in a loop don't flood the event channel and the log panel
+const SEND_DELAY: Duration = Duration::from_millis(100);
+
+static LOG_ENTRIES: Lazy<StdMutex<VecDeque<LogEntry>>> = Lazy::new(<_>::default);
+// `Some` while a batch waits to be sent
+static PENDING_LOG_ENTRIES: Lazy<StdMutex<Option<Vec<LogEntry>>>> = Lazy::new(<_>::default);
+
+pub fn info(source: &str, message: impl Into<String>) {
+    log(LogSeverity::Info, source, message.into())
+}
+
+pub fn warning(source: &str, message: impl Into<String>) {
+    log(LogSeverity::Warning, source, message.into())
+}
+
+pub fn error(source: &str, message: impl Into<String>) {
+    log(LogSeverity::Error, source, message.into())
+}
+
+/// Stores the entry and sends it to the frontend with other entries logged in the next 100 ms.
+/// The message is printed to the terminal as well.
+pub fn log(severity: LogSeverity, source: &str, message: String) {
+    match severity {
+        LogSeverity::Info => println!("{source}: {message}"),
+        LogSeverity::Warning | LogSeverity::Error => eprintln!("{source}: {message}"),
+    }
+    let log_entry = LogEntry {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis() as u64)
+            .unwrap_or_default(),
+        severity,
+        source: source.to_owned(),
+        message,
+    };
+
+    let mut log_entries = LOG_ENTRIES.lock().unwrap();
+    if log_entries.len() == MAX_LOG_ENTRIES {
+        log_entries.pop_front();
+    }
+    log_entries.push_back(log_entry.clone());
+    drop(log_entries);
+
+    let mut pending_log_entries = PENDING_LOG_ENTRIES.lock().unwrap();
+    if let Some(pending_log_entries) = pending_log_entries.as_mut() {
+        pending_log_entries.push(log_entry);
+        return;
+    }
+    *pending_log_entries = Some(vec![log_entry]);
+    drop(pending_log_entries);
+    tauri::async_runtime::spawn(async {
+        sleep(SEND_DELAY).await;
+        let log_entries = PENDING_LOG_ENTRIES
+            .lock()
+            .unwrap()
+            .take()
+            .unwrap_or_default();
+        if let Some(app_handle) = APP_HANDLE.read().unwrap().as_ref() {
+            if let Err(error) = app_handle.emit("log_entries", log_entries) {
+                eprintln!("Failed to send log entries: {error:?}");
+            }
+        }
+    });
+}
+
+pub fn log_entries() -> Vec<LogEntry> {
+    LOG_ENTRIES.lock().unwrap().iter().cloned().collect()
+}
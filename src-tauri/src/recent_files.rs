@@ -0,0 +1,68 @@
+use crate::{logs, APP_HANDLE};
+use std::fs;
+use std::path::PathBuf;
+use tauri::Manager;
+
+type FilePath = String;
+
+const MAX_RECENT_FILES: usize = 10;
+const RECENT_FILES_FILE_NAME: &str = "recent_files.json";
+
+/// Paths of recently loaded waveform files, the most recent first.
+/// The list is persisted in the app config folder.
+pub fn recent_files() -> Vec<FilePath> {
+    let Some(recent_files_path) = recent_files_path() else {
+        return Vec::new();
+    };
+    // The file doesn't exist until the first waveform is loaded
+    let Ok(recent_files) = fs::read_to_string(&recent_files_path) else {
+        return Vec::new();
+    };
+    serde_json::from_str(&recent_files).unwrap_or_else(|error| {
+        logs::warning(
+            "Recent files",
+            format!("Invalid '{}': {error}", recent_files_path.display()),
+        );
+        Vec::new()
+    })
+}
+
+pub fn add_recent_file(file_path: FilePath) {
+    let mut recent_files = recent_files();
+    recent_files.retain(|recent_file| *recent_file != file_path);
+    recent_files.insert(0, file_path);
+    recent_files.truncate(MAX_RECENT_FILES);
+
+    let Some(recent_files_path) = recent_files_path() else {
+        return;
+    };
+    let result = recent_files_path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| {
+            fs::write(
+                &recent_files_path,
+                serde_json::json!(recent_files).to_string(),
+            )
+        });
+    if let Err(error) = result {
+        logs::error(
+            "Recent files",
+            format!("Failed to write '{}': {error}", recent_files_path.display()),
+        );
+    }
+}
+
+fn recent_files_path() -> Option<PathBuf> {
+    let app_handle = APP_HANDLE.read().unwrap().clone()?;
+    match app_handle.path().app_config_dir() {
+        Ok(app_config_dir) => Some(app_config_dir.join(RECENT_FILES_FILE_NAME)),
+        Err(error) => {
+            logs::error(
+                "Recent files",
+                format!("Failed to get the app config folder: {error}"),
+            );
+            None
+        }
+    }
+}
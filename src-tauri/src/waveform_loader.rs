@@ -0,0 +1,269 @@
+use crate::{
+    logs, recent_files, waveform_watcher, Filename, LoadedWaveform, Store, Waveforms, APP_HANDLE,
+    WAVEFORM,
+};
+use once_cell::sync::Lazy;
+use shared::{
+    wellen_helpers, CommandError, TimeAxis, WaveformId, WaveformInfo, WaveformLoadingStatus,
+};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, TryRecvError};
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::Duration;
+use tauri::async_runtime::RwLock;
+use tauri::Emitter;
+use tokio::time::sleep;
+use wellen::simple::Waveform;
+
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+static NEXT_WAVEFORM_ID: AtomicUsize = AtomicUsize::new(0);
+// Cancellation flags of running loadings
+static LOADINGS_CANCELLED: Lazy<StdMutex<HashMap<WaveformId, Arc<AtomicBool>>>> =
+    Lazy::new(<_>::default);
+
+/// Adds the waveform to the loaded ones and adds the file to recent files.
+///
+/// Returns once the header has been read so the hierarchy can be shown immediately.
+/// Value changes are parsed on a separate thread, commands that need them wait
+/// for the waveform lock held until the parsing is done.
+/// The loading is reported to the frontend through `waveform_loading_status` events.
+pub async fn load_waveform(store: &Store, path: &str) -> Result<WaveformInfo, CommandError> {
+    let filename = filename(path);
+
+    let header = tauri::async_runtime::spawn_blocking({
+        let path = path.to_owned();
+        move || wellen_helpers::read_header_from_path(&path)
+    })
+    .await
+    .map_err(|error| CommandError::Unexpected {
+        reason: error.to_string(),
+    })?
+    .map_err(|error| {
+        logs::error("Waveform", error.to_string());
+        error
+    })?;
+    let hierarchy = hierarchy_to_value(path, &header.hierarchy)?;
+
+    let waveform_id = NEXT_WAVEFORM_ID.fetch_add(1, Ordering::Relaxed);
+    let waveform = Arc::new(RwLock::new(None));
+    // Commands working with the new waveform wait until the parsing is done
+    let mut waveform_lock = Arc::clone(&waveform).write_owned().await;
+    store.waveforms.write().unwrap().insert(
+        waveform_id,
+        LoadedWaveform {
+            path: path.to_owned(),
+            waveform,
+            hierarchy: Arc::new(hierarchy),
+            time_axis: TimeAxis {
+                timescale: header.hierarchy.timescale(),
+                last_time: 0,
+            },
+        },
+    );
+    update_global_waveform(&store.waveforms);
+    recent_files::add_recent_file(crate::absolute_path(path));
+    waveform_watcher::watch_waveform_file(waveform_id, path);
+
+    let is_cancelled = Arc::new(AtomicBool::new(false));
+    LOADINGS_CANCELLED
+        .lock()
+        .unwrap()
+        .insert(waveform_id, Arc::clone(&is_cancelled));
+    let total_bytes = header.body_len;
+    let progress = Arc::new(AtomicU64::new(0));
+    let (body_sender, body_receiver) = mpsc::channel();
+    std::thread::spawn({
+        let path = path.to_owned();
+        let progress = Arc::clone(&progress);
+        let is_cancelled = Arc::clone(&is_cancelled);
+        // A body parsing already started by wellen runs to the end,
+        // but the cancelled loading skips it when possible and is never stored
+        move || {
+            let body = wellen_helpers::read_body(header, &path, Some(progress), Some(is_cancelled));
+            let _ = body_sender.send(body);
+        }
+    });
+
+    let waveforms = Arc::clone(&store.waveforms);
+    let path = path.to_owned();
+    let filename_for_loading = filename.clone();
+    tauri::async_runtime::spawn(async move {
+        let filename = filename_for_loading;
+        let status = loop {
+            // Checked first, the parsing thread fails when it notices the cancellation
+            if is_cancelled.load(Ordering::Relaxed) {
+                logs::info("Waveform", format!("Loading of '{filename}' cancelled"));
+                break WaveformLoadingStatus::Cancelled {
+                    waveform_id,
+                    filename,
+                };
+            }
+            match body_receiver.try_recv() {
+                Ok(Ok(waveform)) => {
+                    log_waveform_warnings(&filename, &waveform);
+                    logs::info("Waveform", format!("'{filename}' loaded"));
+                    // Set before the lock is released so commands get the complete time axis
+                    if let Some(loaded_waveform) = waveforms.write().unwrap().get_mut(&waveform_id)
+                    {
+                        loaded_waveform.time_axis = TimeAxis::new(&waveform);
+                    }
+                    *waveform_lock = Some(waveform);
+                    break WaveformLoadingStatus::Loaded {
+                        waveform_id,
+                        filename,
+                    };
+                }
+                Ok(Err(error)) => {
+                    logs::error("Waveform", error.to_string());
+                    break WaveformLoadingStatus::Failed { waveform_id, error };
+                }
+                Err(TryRecvError::Disconnected) => {
+                    let error = CommandError::WaveformLoadingFailed {
+                        path,
+                        reason: "The parsing thread panicked".to_owned(),
+                    };
+                    logs::error("Waveform", error.to_string());
+                    break WaveformLoadingStatus::Failed { waveform_id, error };
+                }
+                Err(TryRecvError::Empty) => (),
+            }
+            emit_status(WaveformLoadingStatus::Loading {
+                waveform_id,
+                filename: filename.clone(),
+                loaded_bytes: progress.load(Ordering::Relaxed),
+                total_bytes,
+            });
+            sleep(PROGRESS_INTERVAL).await;
+        };
+        LOADINGS_CANCELLED.lock().unwrap().remove(&waveform_id);
+        if !matches!(status, WaveformLoadingStatus::Loaded { .. }) {
+            remove_waveform(&waveforms, waveform_id);
+        }
+        emit_status(status);
+        drop(waveform_lock);
+    });
+    Ok(WaveformInfo {
+        id: waveform_id,
+        filename,
+    })
+}
+
+/// Parses the waveform file again and replaces the waveform once it's complete.
+///
+/// The previous waveform stays loaded when the parsing fails,
+/// e.g. when the file is still being written by a simulation.
+/// Selected signals have to be loaded again.
+pub async fn reload_waveform(
+    store: &Store,
+    waveform_id: WaveformId,
+) -> Result<Filename, CommandError> {
+    let path = store.loaded_waveform(waveform_id)?.path;
+    let filename = filename(&path);
+
+    let waveform = tauri::async_runtime::spawn_blocking({
+        let path = path.clone();
+        move || wellen_helpers::read_from_path(&path)
+    })
+    .await
+    .map_err(|error| CommandError::WaveformLoadingFailed {
+        path: path.clone(),
+        reason: error.to_string(),
+    })?
+    .map_err(|error| {
+        logs::warning("Waveform", format!("Reloading failed: {error}"));
+        error
+    })?;
+    let hierarchy = Arc::new(hierarchy_to_value(&path, waveform.hierarchy())?);
+    let time_axis = TimeAxis::new(&waveform);
+
+    // Waits until a running loading is done
+    let loaded_waveform = store.loaded_waveform(waveform_id)?;
+    let mut waveform_lock = loaded_waveform.waveform.write().await;
+    {
+        let mut waveforms = store.waveforms.write().unwrap();
+        // The waveform may have been unloaded during the parsing
+        let loaded_waveform = waveforms
+            .get_mut(&waveform_id)
+            .ok_or(CommandError::WaveformNotFound { waveform_id })?;
+        loaded_waveform.hierarchy = hierarchy;
+        loaded_waveform.time_axis = time_axis;
+    }
+    log_waveform_warnings(&filename, &waveform);
+    *waveform_lock = Some(waveform);
+    logs::info("Waveform", format!("'{filename}' reloaded"));
+    Ok(filename)
+}
+
+/// The hierarchy is serialized once per loading and sent to the frontend as it is
+fn hierarchy_to_value(
+    path: &str,
+    hierarchy: &wellen::Hierarchy,
+) -> Result<serde_json::Value, CommandError> {
+    serde_json::to_value(hierarchy).map_err(|error| {
+        let error = CommandError::WaveformLoadingFailed {
+            path: path.to_owned(),
+            reason: error.to_string(),
+        };
+        logs::error("Waveform", error.to_string());
+        error
+    })
+}
+
+/// Cancels the waveform's loading if it's still running
+pub fn unload_waveform(store: &Store, waveform_id: WaveformId) {
+    cancel_loading(waveform_id);
+    remove_waveform(&store.waveforms, waveform_id);
+}
+
+pub fn cancel_loading(waveform_id: WaveformId) {
+    if let Some(is_cancelled) = LOADINGS_CANCELLED.lock().unwrap().get(&waveform_id) {
+        is_cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+fn remove_waveform(waveforms: &Waveforms, waveform_id: WaveformId) {
+    waveforms.write().unwrap().remove(&waveform_id);
+    waveform_watcher::unwatch_waveform_file(waveform_id);
+    update_global_waveform(waveforms);
+}
+
+// Diagram connectors work with the first loaded waveform
+fn update_global_waveform(waveforms: &Waveforms) {
+    *WAVEFORM.write().unwrap() = waveforms
+        .read()
+        .unwrap()
+        .values()
+        .next()
+        .map(|loaded_waveform| Arc::clone(&loaded_waveform.waveform))
+        .unwrap_or_default();
+}
+
+fn filename(path: &str) -> Filename {
+    Path::new(path).file_name().map_or_else(
+        || path.to_owned(),
+        |filename| filename.to_string_lossy().to_string(),
+    )
+}
+
+fn emit_status(status: WaveformLoadingStatus) {
+    if let Some(app_handle) = APP_HANDLE.read().unwrap().as_ref() {
+        if let Err(error) = app_handle.emit("waveform_loading_status", status) {
+            eprintln!("Failed to send waveform loading status: {error:?}");
+        }
+    }
+}
+
+fn log_waveform_warnings(filename: &str, waveform: &Waveform) {
+    if waveform.time_table().is_empty() {
+        logs::warning("Waveform", format!("'{filename}' has no value changes"));
+    }
+    if waveform.hierarchy().timescale().is_none() {
+        logs::warning(
+            "Waveform",
+            format!("'{filename}' has no timescale, times are shown without units"),
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{logs, APP_HANDLE};
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use once_cell::sync::Lazy;
+use shared::WaveformId;
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex as StdMutex;
+use std::time::Duration;
+use tauri::Emitter;
+use tokio::time::sleep;
+
+// Simulations append to dumps continuously,
+// so changes are reported at most once per delay instead of on every write
+const CHANGE_DELAY: Duration = Duration::from_millis(500);
+
+static WATCHER: Lazy<StdMutex<Option<RecommendedWatcher>>> = Lazy::new(<_>::default);
+// Canonical paths of the files of loaded waveforms
+static WATCHED_FILES: Lazy<StdMutex<BTreeMap<WaveformId, PathBuf>>> = Lazy::new(<_>::default);
+static PENDING_CHANGES: Lazy<StdMutex<HashSet<WaveformId>>> = Lazy::new(<_>::default);
+
+/// Emits `waveform_file_changed` with `waveform_id` when the file at `path` changes.
+///
+/// The parent directory is watched instead of the file itself
+/// because many tools replace the file instead of modifying it.
+pub fn watch_waveform_file(waveform_id: WaveformId, path: &str) {
+    let canonical_path = match std::fs::canonicalize(path) {
+        Ok(canonical_path) => canonical_path,
+        Err(error) => {
+            logs::error(
+                "Waveform file watcher",
+                format!("Failed to watch '{path}': {error:?}"),
+            );
+            return;
+        }
+    };
+    let Some(folder) = canonical_path.parent().map(PathBuf::from) else {
+        return;
+    };
+
+    let mut watched_files = WATCHED_FILES.lock().unwrap();
+    let is_folder_watched = is_folder_watched(&watched_files, &folder);
+    watched_files.insert(waveform_id, canonical_path);
+    drop(watched_files);
+    if is_folder_watched {
+        return;
+    }
+
+    let mut watcher = WATCHER.lock().unwrap();
+    if watcher.is_none() {
+        match notify::recommended_watcher(on_file_event) {
+            Ok(new_watcher) => *watcher = Some(new_watcher),
+            Err(error) => {
+                logs::error(
+                    "Waveform file watcher",
+                    format!("Failed to create the watcher: {error:?}"),
+                );
+                return;
+            }
+        }
+    }
+    if let Err(error) = watcher
+        .as_mut()
+        .unwrap()
+        .watch(&folder, RecursiveMode::NonRecursive)
+    {
+        logs::error(
+            "Waveform file watcher",
+            format!("Failed to watch folder '{}': {error:?}", folder.display()),
+        );
+    }
+}
+
+pub fn unwatch_waveform_file(waveform_id: WaveformId) {
+    let mut watched_files = WATCHED_FILES.lock().unwrap();
+    let Some(canonical_path) = watched_files.remove(&waveform_id) else {
+        return;
+    };
+    let Some(folder) = canonical_path.parent() else {
+        return;
+    };
+    let is_folder_watched = is_folder_watched(&watched_files, folder);
+    // The watcher thread locks the files in `on_file_event`
+    drop(watched_files);
+    if is_folder_watched {
+        return;
+    }
+    if let Some(watcher) = WATCHER.lock().unwrap().as_mut() {
+        // The folder may have been deleted together with the file
+        let _ = watcher.unwatch(folder);
+    }
+}
+
+fn is_folder_watched(watched_files: &BTreeMap<WaveformId, PathBuf>, folder: &Path) -> bool {
+    watched_files
+        .values()
+        .any(|watched_file| watched_file.parent() == Some(folder))
+}
+
+fn on_file_event(event: notify::Result<Event>) {
+    let event = match event {
+        Ok(event) => event,
+        Err(error) => {
+            logs::error("Waveform file watcher", format!("{error:?}"));
+            return;
+        }
+    };
+    if !event.kind.is_create() && !event.kind.is_modify() {
+        return;
+    }
+    let watched_files = WATCHED_FILES.lock().unwrap();
+    for (waveform_id, watched_file) in watched_files.iter() {
+        if !event.paths.contains(watched_file) {
+            continue;
+        }
+        if !PENDING_CHANGES.lock().unwrap().insert(*waveform_id) {
+            continue;
+        }
+        let waveform_id = *waveform_id;
+        tauri::async_runtime::spawn(async move {
+            sleep(CHANGE_DELAY).await;
+            PENDING_CHANGES.lock().unwrap().remove(&waveform_id);
+            if let Some(app_handle) = APP_HANDLE.read().unwrap().as_ref() {
+                if let Err(error) = app_handle.emit("waveform_file_changed", waveform_id) {
+                    eprintln!("Failed to send waveform file change: {error:?}");
+                }
+            }
+        });
+    }
+}
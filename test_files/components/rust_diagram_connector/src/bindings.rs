@@ -12,6 +12,72 @@ pub mod component {
             static __FORCE_SECTION_REF: fn() =
                 super::super::super::__link_custom_section_describing_imports;
             use super::super::super::_rt;
+            /// Loaded signal returned by `lookup-var`
+            pub type VarHandle = u32;
+            /// Time in the waveform's timescale units
+            pub type Time = u64;
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, PartialEq)]
+            pub enum VarFormat {
+                Ascii,
+                Binary,
+                BinaryWithGroups,
+                Hexadecimal,
+                Octal,
+                Signed,
+                Unsigned,
+            }
+            impl ::core::fmt::Debug for VarFormat {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        VarFormat::Ascii => f.debug_tuple("VarFormat::Ascii").finish(),
+                        VarFormat::Binary => f.debug_tuple("VarFormat::Binary").finish(),
+                        VarFormat::BinaryWithGroups => {
+                            f.debug_tuple("VarFormat::BinaryWithGroups").finish()
+                        }
+                        VarFormat::Hexadecimal => f.debug_tuple("VarFormat::Hexadecimal").finish(),
+                        VarFormat::Octal => f.debug_tuple("VarFormat::Octal").finish(),
+                        VarFormat::Signed => f.debug_tuple("VarFormat::Signed").finish(),
+                        VarFormat::Unsigned => f.debug_tuple("VarFormat::Unsigned").finish(),
+                    }
+                }
+            }
+
+            impl VarFormat {
+                pub(crate) unsafe fn _lift(val: u8) -> VarFormat {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+
+                    match val {
+                        0 => VarFormat::Ascii,
+                        1 => VarFormat::Binary,
+                        2 => VarFormat::BinaryWithGroups,
+                        3 => VarFormat::Hexadecimal,
+                        4 => VarFormat::Octal,
+                        5 => VarFormat::Signed,
+                        6 => VarFormat::Unsigned,
+
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+
+            /// `time` * `factor` * 10^`exponent` = seconds
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct WaveformTimescale {
+                pub factor: u32,
+                pub exponent: i8,
+            }
+            impl ::core::fmt::Debug for WaveformTimescale {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct("WaveformTimescale")
+                        .field("factor", &self.factor)
+                        .field("exponent", &self.exponent)
+                        .finish()
+                }
+            }
             #[allow(unused_unsafe, clippy::all)]
             pub fn log(message: &str) {
                 unsafe {
@@ -85,19 +151,20 @@ pub mod component {
                 }
             }
             #[allow(unused_unsafe, clippy::all)]
-            pub fn address_and_way(time_text: &str) -> Result<(_rt::String, Option<u32>), ()> {
+            /// `path` is a full var path with scopes separated by dots, e.g. `TOP.VexiiRiscv.FetchL1Plugin_logic_refill_start_valid`
+            pub fn lookup_var(path: &str) -> Option<VarHandle> {
                 unsafe {
                     #[repr(align(4))]
-                    struct RetArea([::core::mem::MaybeUninit<u8>; 20]);
-                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 20]);
-                    let vec0 = time_text;
+                    struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
+                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+                    let vec0 = path;
                     let ptr0 = vec0.as_ptr().cast::<u8>();
                     let len0 = vec0.len();
                     let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
                     #[cfg(target_arch = "wasm32")]
                     #[link(wasm_import_module = "component:diagram-connector/host")]
                     extern "C" {
-                        #[link_name = "address-and-way"]
+                        #[link_name = "lookup-var"]
                         fn wit_import(_: *mut u8, _: usize, _: *mut u8);
                     }
 
@@ -108,35 +175,130 @@ pub mod component {
                     wit_import(ptr0.cast_mut(), len0, ptr1);
                     let l2 = i32::from(*ptr1.add(0).cast::<u8>());
                     match l2 {
-                        0 => {
+                        0 => None,
+                        1 => {
+                            let e = {
+                                let l3 = *ptr1.add(4).cast::<i32>();
+
+                                l3 as u32
+                            };
+                            Some(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    }
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Value set by the last change at or before `time`
+            pub fn value_at(var: VarHandle, time: Time, format: VarFormat) -> Option<_rt::String> {
+                unsafe {
+                    #[repr(align(4))]
+                    struct RetArea([::core::mem::MaybeUninit<u8>; 12]);
+                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 12]);
+                    let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "component:diagram-connector/host")]
+                    extern "C" {
+                        #[link_name = "value-at"]
+                        fn wit_import(_: i32, _: i64, _: i32, _: *mut u8);
+                    }
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import(_: i32, _: i64, _: i32, _: *mut u8) {
+                        unreachable!()
+                    }
+                    wit_import(
+                        _rt::as_i32(var),
+                        _rt::as_i64(time),
+                        format.clone() as i32,
+                        ptr0,
+                    );
+                    let l1 = i32::from(*ptr0.add(0).cast::<u8>());
+                    match l1 {
+                        0 => None,
+                        1 => {
+                            let e = {
+                                let l2 = *ptr0.add(4).cast::<*mut u8>();
+                                let l3 = *ptr0.add(8).cast::<usize>();
+                                let len4 = l3;
+                                let bytes4 = _rt::Vec::from_raw_parts(l2.cast(), len4, len4);
+
+                                _rt::string_lift(bytes4)
+                            };
+                            Some(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    }
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Time of the first change after `time`
+            pub fn next_change(var: VarHandle, time: Time) -> Option<Time> {
+                unsafe {
+                    #[repr(align(8))]
+                    struct RetArea([::core::mem::MaybeUninit<u8>; 16]);
+                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 16]);
+                    let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "component:diagram-connector/host")]
+                    extern "C" {
+                        #[link_name = "next-change"]
+                        fn wit_import(_: i32, _: i64, _: *mut u8);
+                    }
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import(_: i32, _: i64, _: *mut u8) {
+                        unreachable!()
+                    }
+                    wit_import(_rt::as_i32(var), _rt::as_i64(time), ptr0);
+                    let l1 = i32::from(*ptr0.add(0).cast::<u8>());
+                    match l1 {
+                        0 => None,
+                        1 => {
                             let e = {
-                                let l3 = *ptr1.add(4).cast::<*mut u8>();
-                                let l4 = *ptr1.add(8).cast::<usize>();
-                                let len5 = l4;
-                                let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
-                                let l6 = i32::from(*ptr1.add(12).cast::<u8>());
-
-                                (
-                                    _rt::string_lift(bytes5),
-                                    match l6 {
-                                        0 => None,
-                                        1 => {
-                                            let e = {
-                                                let l7 = *ptr1.add(16).cast::<i32>();
-
-                                                l7 as u32
-                                            };
-                                            Some(e)
-                                        }
-                                        _ => _rt::invalid_enum_discriminant(),
-                                    },
-                                )
+                                let l2 = *ptr0.add(8).cast::<i64>();
+
+                                l2 as u64
                             };
-                            Ok(e)
+                            Some(e)
                         }
+                        _ => _rt::invalid_enum_discriminant(),
+                    }
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            pub fn timescale() -> Option<WaveformTimescale> {
+                unsafe {
+                    #[repr(align(4))]
+                    struct RetArea([::core::mem::MaybeUninit<u8>; 12]);
+                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 12]);
+                    let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "component:diagram-connector/host")]
+                    extern "C" {
+                        #[link_name = "timescale"]
+                        fn wit_import(_: *mut u8);
+                    }
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import(_: *mut u8) {
+                        unreachable!()
+                    }
+                    wit_import(ptr0);
+                    let l1 = i32::from(*ptr0.add(0).cast::<u8>());
+                    match l1 {
+                        0 => None,
                         1 => {
-                            let e = ();
-                            Err(e)
+                            let e = {
+                                let l2 = *ptr0.add(4).cast::<i32>();
+                                let l3 = i32::from(*ptr0.add(8).cast::<i8>());
+
+                                WaveformTimescale {
+                                    factor: l2 as u32,
+                                    exponent: l3 as i8,
+                                }
+                            };
+                            Some(e)
                         }
                         _ => _rt::invalid_enum_discriminant(),
                     }
@@ -262,6 +424,104 @@ mod _rt {
         }
     }
 
+    pub fn as_i32<T: AsI32>(t: T) -> i32 {
+        t.as_i32()
+    }
+
+    pub trait AsI32 {
+        fn as_i32(self) -> i32;
+    }
+
+    impl<'a, T: Copy + AsI32> AsI32 for &'a T {
+        fn as_i32(self) -> i32 {
+            (*self).as_i32()
+        }
+    }
+
+    impl AsI32 for i32 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+
+    impl AsI32 for u32 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+
+    impl AsI32 for i16 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+
+    impl AsI32 for u16 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+
+    impl AsI32 for i8 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+
+    impl AsI32 for u8 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+
+    impl AsI32 for char {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+
+    impl AsI32 for usize {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+
+    pub fn as_i64<T: AsI64>(t: T) -> i64 {
+        t.as_i64()
+    }
+
+    pub trait AsI64 {
+        fn as_i64(self) -> i64;
+    }
+
+    impl<'a, T: Copy + AsI64> AsI64 for &'a T {
+        fn as_i64(self) -> i64 {
+            (*self).as_i64()
+        }
+    }
+
+    impl AsI64 for i64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+
+    impl AsI64 for u64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+
     #[cfg(target_arch = "wasm32")]
     pub fn run_ctors_once() {
         wit_bindgen_rt::run_ctors_once();
@@ -308,18 +568,24 @@ pub(crate) use __export_component_impl as export;
 #[cfg(target_arch = "wasm32")]
 #[link_section = "component-type:wit-bindgen:0.25.0:component:encoded world"]
 #[doc(hidden)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 550] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xa6\x03\x01A\x02\x01\
-A\x04\x01B\x0b\x01@\x01\x07messages\x01\0\x04\0\x03log\x01\0\x01@\x02\x14diagram\
--connect-names\x0ccomponent-ids\x01\0\x04\0!listen-for-component-text-changes\x01\
-\x01\x01@\x02\x0ccomponent-ids\x04texts\x01\0\x04\0\x12set-component-text\x01\x02\
-\x01ky\x01o\x02s\x03\x01j\x01\x04\0\x01@\x01\x09time-texts\0\x05\x04\0\x0faddres\
-s-and-way\x01\x06\x03\x01\x20component:diagram-connector/host\x05\0\x01B\x06\x01\
-@\0\x01\0\x04\0\x04init\x01\0\x01@\0\0s\x04\0\x04name\x01\x01\x01@\x02\x0ccompon\
-ent-ids\x04texts\x01\0\x04\0\x19on-component-text-changed\x01\x02\x04\x01-compon\
-ent:diagram-connector/diagram-connector\x05\x01\x04\x01%component:diagram-connec\
-tor/component\x04\0\x0b\x0f\x01\0\x09component\x03\0\0\0G\x09producers\x01\x0cpr\
-ocessed-by\x02\x0dwit-component\x070.208.1\x10wit-bindgen-rust\x060.25.0";
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 787] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\x93\x05\x01A\x02\x01\
+A\x04\x01B\x1a\x01y\x04\0\x0avar-handle\x03\0\0\x01w\x04\0\x04time\x03\0\x02\x01\
+m\x07\x05ascii\x06binary\x12binary-with-groups\x0bhexadecimal\x05octal\x06signed\
+\x08unsigned\x04\0\x0avar-format\x03\0\x04\x01r\x02\x06factory\x08exponent~\x04\0\
+\x12waveform-timescale\x03\0\x06\x01@\x01\x07messages\x01\0\x04\0\x03log\x01\x08\
+\x01@\x02\x14diagram-connect-names\x0ccomponent-ids\x01\0\x04\0!listen-for-compo\
+nent-text-changes\x01\x09\x01@\x02\x0ccomponent-ids\x04texts\x01\0\x04\0\x12set-\
+component-text\x01\x0a\x01k\x01\x01@\x01\x04paths\0\x0b\x04\0\x0alookup-var\x01\x0c\
+\x01ks\x01@\x03\x03var\x01\x04time\x03\x06format\x05\0\x0d\x04\0\x08value-at\x01\
+\x0e\x01k\x03\x01@\x02\x03var\x01\x04time\x03\0\x0f\x04\0\x0bnext-change\x01\x10\
+\x01k\x07\x01@\0\0\x11\x04\0\x09timescale\x01\x12\x03\x01\x20component:diagram-c\
+onnector/host\x05\0\x01B\x06\x01@\0\x01\0\x04\0\x04init\x01\0\x01@\0\0s\x04\0\x04\
+name\x01\x01\x01@\x02\x0ccomponent-ids\x04texts\x01\0\x04\0\x19on-component-text\
+-changed\x01\x02\x04\x01-component:diagram-connector/diagram-connector\x05\x01\x04\
+\x01%component:diagram-connector/component\x04\0\x0b\x0f\x01\0\x09component\x03\0\
+\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-component\x070.208.1\x10wit-bi\
+ndgen-rust\x060.25.0";
 
 #[inline(never)]
 #[doc(hidden)]
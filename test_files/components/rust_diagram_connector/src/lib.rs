@@ -4,7 +4,7 @@ use std::cell::RefCell;
 #[allow(warnings)]
 mod bindings;
 
-use bindings::component::diagram_connector::host;
+use bindings::component::diagram_connector::host::{self, VarFormat};
 use bindings::exports::component::diagram_connector::diagram_connector;
 
 macro_rules! log {
@@ -18,6 +18,10 @@ const ADDRESS_COMPONENT_ID: &str = "ITxhJ7NtZ74YFd9JQ0_pl";
 const TIME_COMPONENT_ID: &str = "afXu8_6Kqfq-q2IsjtAcP";
 const STATUS_COMPONENT_ID: &str = "0iH5yRbH4IEseV3mnof3A";
 
+const REFILL_VALID_VAR_PATH: &str = "TOP.VexiiRiscv.FetchL1Plugin_logic_refill_start_valid";
+const REFILL_ADDRESS_VAR_PATH: &str = "TOP.VexiiRiscv.FetchL1Plugin_logic_refill_start_address";
+const REFILL_WAY_VAR_PATH: &str = "TOP.VexiiRiscv.FetchL1Plugin_logic_refill_start_wayToAllocate";
+
 thread_local! {
     static TIME_TEXT: RefCell<String> = <_>::default();
 }
@@ -46,8 +50,7 @@ impl diagram_connector::Guest for Component {
 }
 
 fn refresh_fields() {
-    let way = TIME_TEXT.with_borrow(|time_text| host::address_and_way(&time_text));
-    let Ok((address, way)) = way else {
+    let Some((address, way)) = TIME_TEXT.with_borrow(|time_text| address_and_way(time_text)) else {
         return;
     };
     host::set_component_text(ADDRESS_COMPONENT_ID, &address);
@@ -60,4 +63,41 @@ fn refresh_fields() {
     host::set_component_text(STATUS_COMPONENT_ID, &status_text);
 }
 
+fn address_and_way(time_text: &str) -> Option<(String, Option<u32>)> {
+    let time = time_text
+        .parse::<host::Time>()
+        .inspect_err(|error| log!("Failed to parse time_text '{time_text}', error: {error:#}"))
+        .ok()?;
+
+    let refill_valid = lookup_var(REFILL_VALID_VAR_PATH)?;
+    let refill_address = lookup_var(REFILL_ADDRESS_VAR_PATH)?;
+    let refill_way = lookup_var(REFILL_WAY_VAR_PATH)?;
+
+    let refill_valid_value = value_at(refill_valid, time, VarFormat::Binary)?;
+    let refill_address_value = value_at(refill_address, time, VarFormat::Hexadecimal)?;
+
+    if refill_valid_value == "0" {
+        return Some((refill_address_value, None));
+    }
+
+    let refill_way_value = value_at(refill_way, time, VarFormat::Unsigned)?;
+    Some((refill_address_value, refill_way_value.parse().ok()))
+}
+
+fn lookup_var(path: &str) -> Option<host::VarHandle> {
+    let var = host::lookup_var(path);
+    if var.is_none() {
+        log!("Var '{path}' not found");
+    }
+    var
+}
+
+fn value_at(var: host::VarHandle, time: host::Time, format: VarFormat) -> Option<String> {
+    let value = host::value_at(var, time, format);
+    if value.is_none() {
+        log!("No value at time {time} for the var handle {var}");
+    }
+    value
+}
+
 bindings::export!(Component with_types_in bindings);
@@ -0,0 +1,518 @@
+// Generated by `wit-bindgen` 0.25.0. DO NOT EDIT!
+// Options used:
+#[allow(dead_code)]
+pub mod component {
+    #[allow(dead_code)]
+    pub mod protocol_decoder {
+        #[allow(dead_code, clippy::all)]
+        pub mod host {
+            #[used]
+            #[doc(hidden)]
+            #[cfg(target_arch = "wasm32")]
+            static __FORCE_SECTION_REF: fn() =
+                super::super::super::__link_custom_section_describing_imports;
+            #[allow(unused_unsafe, clippy::all)]
+            pub fn log(message: &str) {
+                unsafe {
+                    let vec0 = message;
+                    let ptr0 = vec0.as_ptr().cast::<u8>();
+                    let len0 = vec0.len();
+
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "component:protocol-decoder/host")]
+                    extern "C" {
+                        #[link_name = "log"]
+                        fn wit_import(_: *mut u8, _: usize);
+                    }
+
+                    #[cfg(not(target_arch = "wasm32"))]
+                    fn wit_import(_: *mut u8, _: usize) {
+                        unreachable!()
+                    }
+                    wit_import(ptr0.cast_mut(), len0);
+                }
+            }
+        }
+    }
+}
+#[allow(dead_code)]
+pub mod exports {
+    #[allow(dead_code)]
+    pub mod component {
+        #[allow(dead_code)]
+        pub mod protocol_decoder {
+            #[allow(dead_code, clippy::all)]
+            pub mod protocol_decoder {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() =
+                    super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                /// Time in the waveform's timescale units
+                pub type Time = u64;
+                #[derive(Clone)]
+                pub struct SignalChange {
+                    /// Index of the changed signal in the list returned by `inputs`
+                    pub input: u32,
+                    pub time: Time,
+                    /// Most significant bit first, including four- and nine-state bits, e.g. `1` or `10xz`
+                    pub raw_bits: _rt::String,
+                }
+                impl ::core::fmt::Debug for SignalChange {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("SignalChange")
+                            .field("input", &self.input)
+                            .field("time", &self.time)
+                            .field("raw-bits", &self.raw_bits)
+                            .finish()
+                    }
+                }
+                /// Decoded transaction, e.g. a UART frame
+                #[derive(Clone)]
+                pub struct Annotation {
+                    pub start: Time,
+                    pub end: Time,
+                    pub label: _rt::String,
+                    /// CSS color, e.g. `#ff8c00`
+                    pub color: Option<_rt::String>,
+                }
+                impl ::core::fmt::Debug for Annotation {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("Annotation")
+                            .field("start", &self.start)
+                            .field("end", &self.end)
+                            .field("label", &self.label)
+                            .field("color", &self.color)
+                            .finish()
+                    }
+                }
+                #[derive(Clone)]
+                pub struct AnnotationRow {
+                    pub name: _rt::String,
+                    pub annotations: _rt::Vec<Annotation>,
+                }
+                impl ::core::fmt::Debug for AnnotationRow {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("AnnotationRow")
+                            .field("name", &self.name)
+                            .field("annotations", &self.annotations)
+                            .finish()
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_init_cabi<T: Guest>() {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    T::init();
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_name_cabi<T: Guest>() -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::name();
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec2 = (result0.into_bytes()).into_boxed_slice();
+                    let ptr2 = vec2.as_ptr().cast::<u8>();
+                    let len2 = vec2.len();
+                    ::core::mem::forget(vec2);
+                    *ptr1.add(4).cast::<usize>() = len2;
+                    *ptr1.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_name<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0.add(4).cast::<usize>();
+                    _rt::cabi_dealloc(l0, l1, 1);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_inputs_cabi<T: Guest>() -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::inputs();
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec3 = result0;
+                    let len3 = vec3.len();
+                    let layout3 = _rt::alloc::Layout::from_size_align_unchecked(vec3.len() * 8, 4);
+                    let result3 = if layout3.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout3).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout3);
+                        }
+                        ptr
+                    } else {
+                        {
+                            ::core::ptr::null_mut()
+                        }
+                    };
+                    for (i, e) in vec3.into_iter().enumerate() {
+                        let base = result3.add(i * 8);
+                        {
+                            let vec2 = (e.into_bytes()).into_boxed_slice();
+                            let ptr2 = vec2.as_ptr().cast::<u8>();
+                            let len2 = vec2.len();
+                            ::core::mem::forget(vec2);
+                            *base.add(4).cast::<usize>() = len2;
+                            *base.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+                        }
+                    }
+                    *ptr1.add(4).cast::<usize>() = len3;
+                    *ptr1.add(0).cast::<*mut u8>() = result3;
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_inputs<T: Guest>(arg0: *mut u8) {
+                    let l2 = *arg0.add(0).cast::<*mut u8>();
+                    let l3 = *arg0.add(4).cast::<usize>();
+                    let base4 = l2;
+                    let len4 = l3;
+                    for i in 0..len4 {
+                        let base = base4.add(i * 8);
+                        {
+                            let l0 = *base.add(0).cast::<*mut u8>();
+                            let l1 = *base.add(4).cast::<usize>();
+                            _rt::cabi_dealloc(l0, l1, 1);
+                        }
+                    }
+                    _rt::cabi_dealloc(base4, len4 * 8, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_start_cabi<T: Guest>() {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    T::start();
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_process_changes_cabi<T: Guest>(arg0: *mut u8, arg1: usize) {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let base5 = arg0;
+                    let len5 = arg1;
+                    let mut result5 = _rt::Vec::with_capacity(len5);
+                    for i in 0..len5 {
+                        let base = base5.add(i * 24);
+                        let e5 = {
+                            let l0 = *base.add(0).cast::<i32>();
+                            let l1 = *base.add(8).cast::<i64>();
+                            let l2 = *base.add(16).cast::<*mut u8>();
+                            let l3 = *base.add(20).cast::<usize>();
+                            let len4 = l3;
+                            let bytes4 = _rt::Vec::from_raw_parts(l2.cast(), len4, len4);
+
+                            SignalChange {
+                                input: l0 as u32,
+                                time: l1 as u64,
+                                raw_bits: _rt::string_lift(bytes4),
+                            }
+                        };
+                        result5.push(e5);
+                    }
+                    _rt::cabi_dealloc(base5, len5 * 24, 8);
+                    T::process_changes(result5);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_finish_cabi<T: Guest>() -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::finish();
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec8 = result0;
+                    let len8 = vec8.len();
+                    let layout8 = _rt::alloc::Layout::from_size_align_unchecked(vec8.len() * 16, 4);
+                    let result8 = if layout8.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout8).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout8);
+                        }
+                        ptr
+                    } else {
+                        {
+                            ::core::ptr::null_mut()
+                        }
+                    };
+                    for (i, e) in vec8.into_iter().enumerate() {
+                        let base = result8.add(i * 16);
+                        {
+                            let AnnotationRow {
+                                name: name2,
+                                annotations: annotations2,
+                            } = e;
+                            let vec3 = (name2.into_bytes()).into_boxed_slice();
+                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                            let len3 = vec3.len();
+                            ::core::mem::forget(vec3);
+                            *base.add(4).cast::<usize>() = len3;
+                            *base.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                            let vec7 = annotations2;
+                            let len7 = vec7.len();
+                            let layout7 =
+                                _rt::alloc::Layout::from_size_align_unchecked(vec7.len() * 40, 8);
+                            let result7 = if layout7.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout7);
+                                }
+                                ptr
+                            } else {
+                                {
+                                    ::core::ptr::null_mut()
+                                }
+                            };
+                            for (i, e) in vec7.into_iter().enumerate() {
+                                let base = result7.add(i * 40);
+                                {
+                                    let Annotation {
+                                        start: start4,
+                                        end: end4,
+                                        label: label4,
+                                        color: color4,
+                                    } = e;
+                                    *base.add(0).cast::<i64>() = _rt::as_i64(start4);
+                                    *base.add(8).cast::<i64>() = _rt::as_i64(end4);
+                                    let vec5 = (label4.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *base.add(20).cast::<usize>() = len5;
+                                    *base.add(16).cast::<*mut u8>() = ptr5.cast_mut();
+                                    match color4 {
+                                        Some(e) => {
+                                            *base.add(24).cast::<u8>() = (1i32) as u8;
+                                            let vec6 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr6 = vec6.as_ptr().cast::<u8>();
+                                            let len6 = vec6.len();
+                                            ::core::mem::forget(vec6);
+                                            *base.add(32).cast::<usize>() = len6;
+                                            *base.add(28).cast::<*mut u8>() = ptr6.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                            *base.add(12).cast::<usize>() = len7;
+                            *base.add(8).cast::<*mut u8>() = result7;
+                        }
+                    }
+                    *ptr1.add(4).cast::<usize>() = len8;
+                    *ptr1.add(0).cast::<*mut u8>() = result8;
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_finish<T: Guest>(arg0: *mut u8) {
+                    let l10 = *arg0.add(0).cast::<*mut u8>();
+                    let l11 = *arg0.add(4).cast::<usize>();
+                    let base12 = l10;
+                    let len12 = l11;
+                    for i in 0..len12 {
+                        let base = base12.add(i * 16);
+                        {
+                            let l0 = *base.add(0).cast::<*mut u8>();
+                            let l1 = *base.add(4).cast::<usize>();
+                            _rt::cabi_dealloc(l0, l1, 1);
+                            let l7 = *base.add(8).cast::<*mut u8>();
+                            let l8 = *base.add(12).cast::<usize>();
+                            let base9 = l7;
+                            let len9 = l8;
+                            for i in 0..len9 {
+                                let base = base9.add(i * 40);
+                                {
+                                    let l2 = *base.add(16).cast::<*mut u8>();
+                                    let l3 = *base.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                    let l4 = i32::from(*base.add(24).cast::<u8>());
+                                    match l4 {
+                                        0 => (),
+                                        _ => {
+                                            let l5 = *base.add(28).cast::<*mut u8>();
+                                            let l6 = *base.add(32).cast::<usize>();
+                                            _rt::cabi_dealloc(l5, l6, 1);
+                                        }
+                                    }
+                                }
+                            }
+                            _rt::cabi_dealloc(base9, len9 * 40, 8);
+                        }
+                    }
+                    _rt::cabi_dealloc(base12, len12 * 16, 4);
+                }
+                pub trait Guest {
+                    fn init();
+                    fn name() -> _rt::String;
+                    /// Names of the input signals, e.g. `["rx"]`, the user binds them to vars in the same order
+                    fn inputs() -> _rt::Vec<_rt::String>;
+                    /// Resets the decoder state before the first `process-changes` call
+                    fn start();
+                    /// Batch of changes of all inputs ordered by time, initial values included
+                    fn process_changes(changes: _rt::Vec<SignalChange>);
+                    /// Called after the last batch
+                    fn finish() -> _rt::Vec<AnnotationRow>;
+                }
+                #[doc(hidden)]
+
+                macro_rules! __export_component_protocol_decoder_protocol_decoder_cabi{
+  ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
+
+    #[export_name = "component:protocol-decoder/protocol-decoder#init"]
+    unsafe extern "C" fn export_init() {
+      $($path_to_types)*::_export_init_cabi::<$ty>()
+    }
+    #[export_name = "component:protocol-decoder/protocol-decoder#name"]
+    unsafe extern "C" fn export_name() -> *mut u8 {
+      $($path_to_types)*::_export_name_cabi::<$ty>()
+    }
+    #[export_name = "cabi_post_component:protocol-decoder/protocol-decoder#name"]
+    unsafe extern "C" fn _post_return_name(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_name::<$ty>(arg0)
+    }
+    #[export_name = "component:protocol-decoder/protocol-decoder#inputs"]
+    unsafe extern "C" fn export_inputs() -> *mut u8 {
+      $($path_to_types)*::_export_inputs_cabi::<$ty>()
+    }
+    #[export_name = "cabi_post_component:protocol-decoder/protocol-decoder#inputs"]
+    unsafe extern "C" fn _post_return_inputs(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_inputs::<$ty>(arg0)
+    }
+    #[export_name = "component:protocol-decoder/protocol-decoder#start"]
+    unsafe extern "C" fn export_start() {
+      $($path_to_types)*::_export_start_cabi::<$ty>()
+    }
+    #[export_name = "component:protocol-decoder/protocol-decoder#process-changes"]
+    unsafe extern "C" fn export_process_changes(arg0: *mut u8,arg1: usize,) {
+      $($path_to_types)*::_export_process_changes_cabi::<$ty>(arg0, arg1)
+    }
+    #[export_name = "component:protocol-decoder/protocol-decoder#finish"]
+    unsafe extern "C" fn export_finish() -> *mut u8 {
+      $($path_to_types)*::_export_finish_cabi::<$ty>()
+    }
+    #[export_name = "cabi_post_component:protocol-decoder/protocol-decoder#finish"]
+    unsafe extern "C" fn _post_return_finish(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_finish::<$ty>(arg0)
+    }
+  };);
+}
+                #[doc(hidden)]
+                pub(crate) use __export_component_protocol_decoder_protocol_decoder_cabi;
+                #[repr(align(4))]
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 8]);
+                static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+            }
+        }
+    }
+}
+mod _rt {
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn run_ctors_once() {
+        wit_bindgen_rt::run_ctors_once();
+    }
+    pub use alloc_crate::string::String;
+    pub use alloc_crate::vec::Vec;
+    pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
+        if size == 0 {
+            return;
+        }
+        let layout = alloc::Layout::from_size_align_unchecked(size, align);
+        alloc::dealloc(ptr as *mut u8, layout);
+    }
+    pub use alloc_crate::alloc;
+    pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
+        if cfg!(debug_assertions) {
+            String::from_utf8(bytes).unwrap()
+        } else {
+            String::from_utf8_unchecked(bytes)
+        }
+    }
+
+    pub fn as_i64<T: AsI64>(t: T) -> i64 {
+        t.as_i64()
+    }
+
+    pub trait AsI64 {
+        fn as_i64(self) -> i64;
+    }
+
+    impl<'a, T: Copy + AsI64> AsI64 for &'a T {
+        fn as_i64(self) -> i64 {
+            (*self).as_i64()
+        }
+    }
+
+    impl AsI64 for i64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+
+    impl AsI64 for u64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+    extern crate alloc as alloc_crate;
+}
+
+/// Generates `#[no_mangle]` functions to export the specified type as the
+/// root implementation of all generated traits.
+///
+/// For more information see the documentation of `wit_bindgen::generate!`.
+///
+/// ```rust
+/// # macro_rules! export{ ($($t:tt)*) => (); }
+/// # trait Guest {}
+/// struct MyType;
+///
+/// impl Guest for MyType {
+///     // ...
+/// }
+///
+/// export!(MyType);
+/// ```
+#[allow(unused_macros)]
+#[doc(hidden)]
+
+macro_rules! __export_component_impl {
+  ($ty:ident) => (self::export!($ty with_types_in self););
+  ($ty:ident with_types_in $($path_to_types_root:tt)*) => (
+  $($path_to_types_root)*::exports::component::protocol_decoder::protocol_decoder::__export_component_protocol_decoder_protocol_decoder_cabi!($ty with_types_in $($path_to_types_root)*::exports::component::protocol_decoder::protocol_decoder);
+  )
+}
+#[doc(inline)]
+pub(crate) use __export_component_impl as export;
+
+#[cfg(target_arch = "wasm32")]
+#[link_section = "component-type:wit-bindgen:0.25.0:component:encoded world"]
+#[doc(hidden)]
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 550] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xa6\x03\x01A\x02\x01\
+A\x04\x01B\x02\x01@\x01\x07messages\x01\0\x04\0\x03log\x01\0\x03\x01\x1fcomponen\
+t:protocol-decoder/host\x05\0\x01B\x18\x01w\x04\0\x04time\x03\0\0\x01r\x03\x05in\
+puty\x04time\x01\x08raw-bitss\x04\0\x0dsignal-change\x03\0\x02\x01ks\x01r\x04\x05\
+start\x01\x03end\x01\x05labels\x05color\x04\x04\0\x0aannotation\x03\0\x05\x01p\x06\
+\x01r\x02\x04names\x0bannotations\x07\x04\0\x0eannotation-row\x03\0\x08\x01@\0\x01\
+\0\x04\0\x04init\x01\x0a\x01@\0\0s\x04\0\x04name\x01\x0b\x01ps\x01@\0\0\x0c\x04\0\
+\x06inputs\x01\x0d\x04\0\x05start\x01\x0a\x01p\x03\x01@\x01\x07changes\x0e\x01\0\
+\x04\0\x0fprocess-changes\x01\x0f\x01p\x09\x01@\0\0\x10\x04\0\x06finish\x01\x11\x04\
+\x01+component:protocol-decoder/protocol-decoder\x05\x01\x04\x01$component:proto\
+col-decoder/component\x04\0\x0b\x0f\x01\0\x09component\x03\0\0\0G\x09producers\x01\
+\x0cprocessed-by\x02\x0dwit-component\x070.208.1\x10wit-bindgen-rust\x060.25.0";
+
+#[inline(never)]
+#[doc(hidden)]
+#[cfg(target_arch = "wasm32")]
+pub fn __link_custom_section_describing_imports() {
+    wit_bindgen_rt::maybe_link_cabi_realloc();
+}
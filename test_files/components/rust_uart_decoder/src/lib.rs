@@ -0,0 +1,137 @@
+#[allow(warnings)]
+mod bindings;
+
+use bindings::component::protocol_decoder::host;
+use bindings::exports::component::protocol_decoder::protocol_decoder::{
+    self, Annotation, AnnotationRow, SignalChange, Time,
+};
+use std::sync::Mutex;
+
+macro_rules! log {
+    ($($arg:tt)*) => (host::log(&format!($($arg)*)))
+}
+
+static NAME: &str = "UART";
+
+// 8 data bits, no parity, 1 stop bit
+const DATA_BITS: u64 = 8;
+const FRAME_BITS: u64 = 1 + DATA_BITS + 1;
+
+const COLOR_FRAMING_ERROR: &str = "#ff3333";
+
+// (time, level), `None` level means an unknown or high-impedance value
+static RX_CHANGES: Mutex<Vec<(Time, Option<bool>)>> = Mutex::new(Vec::new());
+
+struct Component;
+
+impl protocol_decoder::Guest for Component {
+    fn init() {
+        log!("'{NAME}' initialized")
+    }
+
+    fn name() -> String {
+        NAME.to_string()
+    }
+
+    fn inputs() -> Vec<String> {
+        vec!["rx".to_string()]
+    }
+
+    fn start() {
+        RX_CHANGES.lock().unwrap().clear();
+    }
+
+    fn process_changes(changes: Vec<SignalChange>) {
+        let mut rx_changes = RX_CHANGES.lock().unwrap();
+        for SignalChange { time, raw_bits, .. } in changes {
+            // Only the least significant bit matters for a single-bit line
+            let level = match raw_bits.chars().last() {
+                Some('1' | 'h') => Some(true),
+                Some('0' | 'l') => Some(false),
+                _ => None,
+            };
+            if rx_changes.last().map(|(_, last_level)| *last_level) != Some(level) {
+                rx_changes.push((time, level));
+            }
+        }
+    }
+
+    fn finish() -> Vec<AnnotationRow> {
+        let rx_changes = std::mem::take(&mut *RX_CHANGES.lock().unwrap());
+        let Some(bit_period) = detect_bit_period(&rx_changes) else {
+            log!("Not enough changes to detect the baud rate");
+            return Vec::new();
+        };
+        log!("Detected bit period: {bit_period}");
+        vec![AnnotationRow {
+            name: "rx".to_string(),
+            annotations: decode_frames(&rx_changes, bit_period),
+        }]
+    }
+}
+
+/// The shortest pulse is the duration of one bit.
+fn detect_bit_period(rx_changes: &[(Time, Option<bool>)]) -> Option<Time> {
+    rx_changes
+        .windows(2)
+        .filter(|pair| pair[0].1.is_some() && pair[1].1.is_some())
+        .map(|pair| pair[1].0 - pair[0].0)
+        .filter(|duration| *duration > 0)
+        .min()
+}
+
+fn decode_frames(rx_changes: &[(Time, Option<bool>)], bit_period: Time) -> Vec<Annotation> {
+    let level_at = |time: Time| {
+        let change_index = rx_changes.partition_point(|(change_time, _)| *change_time <= time);
+        change_index
+            .checked_sub(1)
+            .and_then(|index| rx_changes[index].1)
+    };
+    // Samples in the middle of the bit
+    let bit_level = |frame_start: Time, bit_index: u64| {
+        level_at(frame_start + bit_index * bit_period + bit_period / 2)
+    };
+
+    let mut annotations = Vec::new();
+    let mut search_from = 0;
+    for (index, (time, level)) in rx_changes.iter().enumerate() {
+        let is_falling_edge =
+            *level == Some(false) && index > 0 && rx_changes[index - 1].1 == Some(true);
+        if *time < search_from || !is_falling_edge {
+            continue;
+        }
+        let frame_start = *time;
+        // Glitch shorter than a half of the start bit
+        if bit_level(frame_start, 0) != Some(false) {
+            continue;
+        }
+
+        let mut byte = Some(0u8);
+        for bit_index in 0..DATA_BITS {
+            byte = match bit_level(frame_start, 1 + bit_index) {
+                Some(bit) => byte.map(|byte| byte | (u8::from(bit) << bit_index)),
+                None => None,
+            };
+        }
+        let stop_bit_valid = bit_level(frame_start, FRAME_BITS - 1) == Some(true);
+
+        let label = match byte {
+            Some(byte) if byte.is_ascii_graphic() || byte == b' ' => {
+                format!("0x{byte:02x} '{}'", byte as char)
+            }
+            Some(byte) => format!("0x{byte:02x}"),
+            None => "?".to_string(),
+        };
+        annotations.push(Annotation {
+            start: frame_start,
+            end: frame_start + FRAME_BITS * bit_period,
+            label,
+            color: (!stop_bit_valid || byte.is_none()).then(|| COLOR_FRAMING_ERROR.to_string()),
+        });
+        // The next start bit can't begin before the middle of the stop bit
+        search_from = frame_start + (FRAME_BITS - 1) * bit_period + bit_period / 2;
+    }
+    annotations
+}
+
+bindings::export!(Component with_types_in bindings);